@@ -0,0 +1,39 @@
+//! An example detector plugin, built as a `cdylib` and loaded with `--plugin <path>`.
+
+use std::{collections::HashMap, path::PathBuf};
+use sway_analyzer::{
+    error::Error,
+    export_plugin,
+    project::Project,
+    report::Severity,
+    visitor::{AstVisitor, FnContext, ModuleContext},
+};
+
+#[derive(Default)]
+struct FnCountVisitor {
+    fn_counts: HashMap<PathBuf, usize>,
+}
+
+impl AstVisitor for FnCountVisitor {
+    fn visit_fn(&mut self, context: &FnContext, _project: &mut Project) -> Result<(), Error> {
+        *self.fn_counts.entry(context.path.into()).or_default() += 1;
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, project: &mut Project) -> Result<(), Error> {
+        let fn_count = self.fn_counts.get(context.path).copied().unwrap_or_default();
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            None,
+            Severity::Low,
+            format!("The module contains {fn_count} functions."),
+        );
+
+        Ok(())
+    }
+}
+
+export_plugin!(|registrar| {
+    registrar.register_detector("example_fn_count", || Box::<FnCountVisitor>::default());
+});
@@ -0,0 +1,394 @@
+use crate::{
+    types::Type,
+    utils::{self, span_key, ExprKey},
+};
+use std::{collections::HashMap, fmt::Display};
+use sway_ast::{
+    attribute::AttributeDecl, FnArgs, FnSignature, ItemImplItem, ItemKind, ItemTraitItem, Module,
+};
+use sway_types::{Span, Spanned};
+
+/// An attribute of a function, i.e: `payable` or `storage(read, write)`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FnAttribute {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+impl FnAttribute {
+    /// Collects the `payable` and `storage` attributes in the supplied `attribute_decls`, which have to match between an ABI and its implementation.
+    fn collect(attribute_decls: &[AttributeDecl]) -> Vec<FnAttribute> {
+        let mut result = vec![];
+
+        for attribute_decl in attribute_decls {
+            for attribute in (&attribute_decl.attribute.inner).into_iter() {
+                if !matches!(attribute.name.as_str(), "payable" | "storage") {
+                    continue;
+                }
+
+                let mut args = attribute.args.as_ref()
+                    .map(|args| (&args.inner).into_iter().map(|arg| arg.name.as_str().to_string()).collect::<Vec<_>>())
+                    .unwrap_or_default();
+
+                args.sort();
+
+                result.push(FnAttribute {
+                    name: attribute.name.as_str().to_string(),
+                    args,
+                });
+            }
+        }
+
+        result.sort();
+        result
+    }
+}
+
+impl Display for FnAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.args.is_empty() {
+            write!(f, "#[{}]", self.name)
+        } else {
+            write!(f, "#[{}({})]", self.name, self.args.join(", "))
+        }
+    }
+}
+
+/// The argument and return types of a function, ignoring argument names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FnTypes {
+    has_self: bool,
+    args: Vec<Type>,
+    ret: Type,
+}
+
+impl FnTypes {
+    fn new(fn_signature: &FnSignature) -> Self {
+        let (has_self, args) = match &fn_signature.arguments.inner {
+            FnArgs::Static(args) => (false, args.into_iter().map(|arg| Type::from_ty(&arg.ty)).collect()),
+
+            FnArgs::NonStatic { args_opt, .. } => (
+                true,
+                args_opt.iter().flat_map(|(_, args)| args.into_iter()).map(|arg| Type::from_ty(&arg.ty)).collect(),
+            ),
+        };
+
+        Self {
+            has_self,
+            args,
+            ret: fn_signature.return_type_opt.as_ref().map(|(_, ty)| Type::from_ty(ty)).unwrap_or_else(Type::unit),
+        }
+    }
+}
+
+/// A method declared in an `abi` block, paired with its implementation for `Contract`.
+#[derive(Clone, Debug)]
+pub struct AbiMethod {
+    pub abi_name: String,
+    pub name: String,
+
+    /// The signature of the method in the `abi` block.
+    pub declaration: Span,
+    pub declaration_attributes: Vec<FnAttribute>,
+
+    /// The signature of the method in an `impl ... for Contract` block or the `abi` block's provided methods.
+    pub implementation: Option<Span>,
+    pub implementation_attributes: Vec<FnAttribute>,
+
+    declaration_types: FnTypes,
+}
+
+impl AbiMethod {
+    /// Checks if either side of the method has the attribute called `name` with all of the supplied `args`.
+    pub fn has_attribute(&self, name: &str, args: &[&str]) -> bool {
+        self.declaration_attributes.iter().chain(self.implementation_attributes.iter())
+            .any(|attribute| attribute.name == name && args.iter().all(|arg| attribute.args.iter().any(|x| x == arg)))
+    }
+
+    pub fn is_payable(&self) -> bool {
+        self.has_attribute("payable", &[])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiMismatchKind {
+    /// An ABI method without an implementation in an `impl ... for Contract` block which implements the ABI.
+    MissingImplementation,
+
+    /// A function in an `impl ... for Contract` block which is not declared in its ABI.
+    UndeclaredMethod,
+
+    /// The `payable` and `storage` attributes of the declaration and implementation differ.
+    Attributes {
+        declared: Vec<FnAttribute>,
+        implemented: Vec<FnAttribute>,
+    },
+
+    /// The argument or return types of the declaration and implementation differ.
+    Signature {
+        declared: String,
+    },
+}
+
+/// A difference between an ABI declaration and its implementation for `Contract`.
+#[derive(Clone, Debug)]
+pub struct AbiMismatch {
+    pub kind: AbiMismatchKind,
+    pub abi_name: String,
+    pub method_name: String,
+
+    /// The implementation's signature, or the declaration's signature for missing implementations.
+    pub span: Span,
+}
+
+/// The methods of an `abi` block, indexed by name.
+struct DeclaredAbi {
+    source: usize,
+    method_indices: HashMap<String, usize>,
+}
+
+/// A model of the ABIs declared in a set of modules and their implementations for `Contract`.
+#[derive(Default)]
+pub struct AbiModel {
+    methods: Vec<AbiMethod>,
+    implementations: HashMap<ExprKey, usize>,
+    mismatches: Vec<AbiMismatch>,
+}
+
+impl AbiModel {
+    /// Pairs the ABI methods in the supplied `modules` with their implementations.
+    pub fn new<'a, I: IntoIterator<Item = &'a Module>>(modules: I) -> Self {
+        let modules = modules.into_iter().collect::<Vec<_>>();
+        let mut result = Self::default();
+
+        // ABI names are only unique within a project, so keep every declaration of each name
+        let mut abis: HashMap<String, Vec<DeclaredAbi>> = HashMap::new();
+
+        for module in modules.iter() {
+            let source = span_key(&module.span()).0;
+
+            for item in module.items.iter() {
+                let ItemKind::Abi(item_abi) = &item.value else { continue };
+                let abi_name = item_abi.name.as_str().to_string();
+                let mut method_indices = HashMap::new();
+
+                for item in item_abi.abi_items.inner.iter() {
+                    let ItemTraitItem::Fn(fn_signature, _) = &item.value else { continue };
+
+                    method_indices.insert(fn_signature.name.as_str().to_string(), result.methods.len());
+
+                    result.methods.push(AbiMethod {
+                        abi_name: abi_name.clone(),
+                        name: fn_signature.name.as_str().to_string(),
+                        declaration: fn_signature.span(),
+                        declaration_attributes: FnAttribute::collect(&item.attribute_list),
+                        implementation: None,
+                        implementation_attributes: vec![],
+                        declaration_types: FnTypes::new(fn_signature),
+                    });
+                }
+
+                // Provided methods are implemented by the ABI itself
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    let fn_signature = &item.value.fn_signature;
+                    let attributes = FnAttribute::collect(&item.attribute_list);
+                    let index = result.methods.len();
+
+                    result.methods.push(AbiMethod {
+                        abi_name: abi_name.clone(),
+                        name: fn_signature.name.as_str().to_string(),
+                        declaration: fn_signature.span(),
+                        declaration_attributes: attributes.clone(),
+                        implementation: Some(fn_signature.span()),
+                        implementation_attributes: attributes,
+                        declaration_types: FnTypes::new(fn_signature),
+                    });
+
+                    result.implementations.insert(span_key(&fn_signature.span()), index);
+                }
+
+                abis.entry(abi_name).or_default().push(DeclaredAbi { source, method_indices });
+            }
+        }
+
+        for module in modules.iter() {
+            let source = span_key(&module.span()).0;
+
+            for item in module.items.iter() {
+                let ItemKind::Impl(item_impl) = &item.value else { continue };
+                let Some((path_type, _)) = item_impl.trait_opt.as_ref() else { continue };
+
+                if item_impl.ty.span().as_str() != "Contract" {
+                    continue;
+                }
+
+                let abi_name = path_type.last_segment().name.as_str().to_string();
+
+                // Prefer an ABI declared in the same source as the implementation
+                let Some(candidates) = abis.get(&abi_name) else { continue };
+                let Some(DeclaredAbi { method_indices, .. }) = candidates.iter().find(|x| x.source == source).or(candidates.first()) else { continue };
+
+                let mut implemented = vec![];
+
+                for item in item_impl.contents.inner.iter() {
+                    let ItemImplItem::Fn(item_fn) = &item.value else { continue };
+                    let fn_signature = &item_fn.fn_signature;
+                    let name = fn_signature.name.as_str().to_string();
+
+                    let Some(&index) = method_indices.get(&name) else {
+                        result.mismatches.push(AbiMismatch {
+                            kind: AbiMismatchKind::UndeclaredMethod,
+                            abi_name: abi_name.clone(),
+                            method_name: name,
+                            span: fn_signature.span(),
+                        });
+
+                        continue;
+                    };
+
+                    implemented.push(index);
+
+                    let method = &mut result.methods[index];
+                    method.implementation = Some(fn_signature.span());
+                    method.implementation_attributes = FnAttribute::collect(&item.attribute_list);
+                    result.implementations.insert(span_key(&fn_signature.span()), index);
+
+                    if method.implementation_attributes != method.declaration_attributes {
+                        result.mismatches.push(AbiMismatch {
+                            kind: AbiMismatchKind::Attributes {
+                                declared: method.declaration_attributes.clone(),
+                                implemented: method.implementation_attributes.clone(),
+                            },
+                            abi_name: abi_name.clone(),
+                            method_name: name.clone(),
+                            span: fn_signature.span(),
+                        });
+                    }
+
+                    if FnTypes::new(fn_signature) != method.declaration_types {
+                        result.mismatches.push(AbiMismatch {
+                            kind: AbiMismatchKind::Signature {
+                                declared: method.declaration.as_str().to_string(),
+                            },
+                            abi_name: abi_name.clone(),
+                            method_name: name,
+                            span: fn_signature.span(),
+                        });
+                    }
+                }
+
+                for &index in method_indices.values() {
+                    if implemented.contains(&index) {
+                        continue;
+                    }
+
+                    result.mismatches.push(AbiMismatch {
+                        kind: AbiMismatchKind::MissingImplementation,
+                        abi_name: abi_name.clone(),
+                        method_name: result.methods[index].name.clone(),
+                        span: result.methods[index].declaration.clone(),
+                    });
+                }
+            }
+        }
+
+        result.mismatches.sort_by_key(|mismatch| span_key(&mismatch.span));
+        result
+    }
+
+    /// Gets every declared ABI method, including those without an implementation.
+    pub fn methods(&self) -> &[AbiMethod] {
+        self.methods.as_slice()
+    }
+
+    /// Gets the publicly-callable entry points of the contract, i.e. the implemented ABI methods.
+    pub fn entry_points(&self) -> impl Iterator<Item = &AbiMethod> {
+        self.methods.iter().filter(|method| method.implementation.is_some())
+    }
+
+    /// Gets the ABI method implemented by the function with the supplied signature, if it is an entry point.
+    pub fn entry_point(&self, fn_signature: &FnSignature) -> Option<&AbiMethod> {
+        self.implementations.get(&span_key(&fn_signature.span())).map(|&index| &self.methods[index])
+    }
+
+    /// Gets the mismatches between the ABIs and implementations in the supplied `module`.
+    pub fn module_mismatches<'a>(&'a self, module: &'a Module) -> impl Iterator<Item = &'a AbiMismatch> {
+        let source = span_key(&module.span()).0;
+        self.mismatches.iter().filter(move |mismatch| span_key(&mismatch.span).0 == source)
+    }
+
+    /// Checks if the function with the supplied signature and `fn_attributes` has the attribute called `name` with all of the supplied `args`, including the attributes of its ABI declaration.
+    pub fn fn_has_attribute(&self, fn_signature: &FnSignature, fn_attributes: &[AttributeDecl], name: &str, args: &[&str]) -> bool {
+        utils::check_attribute_decls(fn_attributes, name, args)
+            || self.entry_point(fn_signature).map(|method| method.has_attribute(name, args)).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbiMismatchKind;
+    use crate::project::Project;
+
+    #[test]
+    fn test_abi_model() {
+        let source = r#"contract;
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read)]
+    fn balance(owner: Identity) -> u64;
+
+    fn withdraw(amount: u64);
+} {
+    fn version() -> u64 {
+        1
+    }
+}
+
+abi External {
+    fn call_me();
+}
+
+impl Vault for Contract {
+    #[storage(write, read)]
+    fn deposit() {}
+
+    #[storage(read, write)]
+    fn balance(owner: Address) -> u64 {
+        0
+    }
+
+    fn extra() {}
+}
+"#;
+
+        let mut project = Project::default();
+        project.parse_source("test.sw", source).unwrap();
+        project.visit_modules(&mut crate::visitor::AstVisitorRecursive::default()).unwrap();
+
+        let abi = project.abi();
+
+        let entry_points = abi.entry_points().map(|method| method.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(entry_points, vec!["deposit", "balance", "version"]);
+
+        let deposit = abi.methods().iter().find(|method| method.name == "deposit").unwrap();
+        assert!(deposit.is_payable());
+        assert!(deposit.has_attribute("storage", &["write"]));
+
+        let mut mismatches = abi.mismatches.iter()
+            .map(|mismatch| (mismatch.method_name.as_str(), mismatch.kind.clone()))
+            .collect::<Vec<_>>();
+
+        mismatches.sort_by_key(|(name, _)| *name);
+
+        assert_eq!(mismatches.len(), 5);
+        assert!(matches!(mismatches[0], ("balance", AbiMismatchKind::Attributes { .. })));
+        assert!(matches!(mismatches[1], ("balance", AbiMismatchKind::Signature { .. })));
+        assert!(matches!(mismatches[2], ("deposit", AbiMismatchKind::Attributes { .. })));
+        assert!(matches!(mismatches[3], ("extra", AbiMismatchKind::UndeclaredMethod)));
+        assert!(matches!(mismatches[4], ("withdraw", AbiMismatchKind::MissingImplementation)));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::project::Project;
+use std::fmt::Display;
+
+/// An expected finding declared in a test fixture with a `//~ detector_name: message substring` comment.
+///
+/// The annotation applies to the line containing it, or to a preceding line for each `^` in `//~^`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub line: usize,
+    pub detector: String,
+    pub message: String,
+}
+
+impl Display for Annotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "L{} {}: {}", self.line, self.detector, self.message)
+    }
+}
+
+/// Parses the annotations in the supplied `source`.
+pub fn parse_annotations(source: &str) -> Vec<Annotation> {
+    let mut annotations = vec![];
+
+    for (i, line) in source.lines().enumerate() {
+        for annotation in line.split("//~").skip(1) {
+            let offset = annotation.chars().take_while(|c| *c == '^').count();
+            let Some((detector, message)) = annotation[offset..].split_once(':') else { continue };
+
+            annotations.push(Annotation {
+                line: (i + 1).saturating_sub(offset),
+                detector: detector.trim().to_string(),
+                message: message.trim().to_string(),
+            });
+        }
+    }
+
+    annotations
+}
+
+/// Compares the report of the supplied `project` against the annotations in its source files for the supplied `detectors`.
+///
+/// Returns a diff of the missing (`-`) and unexpected (`+`) findings of each file if they do not match exactly.
+pub fn check_annotations(project: &Project, detectors: &[&str]) -> Result<(), String> {
+    let report = project.report.borrow();
+    let mut paths = project.source_paths().collect::<Vec<_>>();
+    paths.sort();
+
+    let mut diff = String::new();
+
+    for path in paths {
+        let source = project.get_source(path).unwrap_or_default();
+
+        let mut missing = parse_annotations(source).into_iter()
+            .filter(|annotation| detectors.contains(&annotation.detector.as_str()))
+            .collect::<Vec<_>>();
+
+        let mut unexpected = vec![];
+
+        let entries = report.entries.iter()
+            .filter(|(entry_path, _)| entry_path == path)
+            .flat_map(|(_, entries)| entries.iter());
+
+        for entry in entries {
+            let Some(line) = entry.line else {
+                unexpected.push(format!("{}: {}", entry.detector, entry.text));
+                continue;
+            };
+
+            let position = missing.iter().position(|annotation| {
+                annotation.line == line && annotation.detector == entry.detector && entry.text.contains(&annotation.message)
+            });
+
+            match position {
+                Some(position) => { missing.remove(position); }
+                None => unexpected.push(format!("L{line} {}: {}", entry.detector, entry.text)),
+            }
+        }
+
+        if missing.is_empty() && unexpected.is_empty() {
+            continue;
+        }
+
+        diff.push_str(&format!("{}:\n", path.to_string_lossy()));
+
+        for annotation in missing {
+            diff.push_str(&format!("-\t{annotation}\n"));
+        }
+
+        for entry in unexpected {
+            diff.push_str(&format!("+\t{entry}\n"));
+        }
+    }
+
+    if diff.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Findings do not match annotations (- missing, + unexpected):\n{diff}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_annotations() {
+        let source = "fn f() {\n    let a = 1; //~ magic_number: `1`\n    //~^^ explicit_return_statement: The `f` function //~ other: text\n}\n";
+
+        assert_eq!(parse_annotations(source), vec![
+            Annotation { line: 2, detector: "magic_number".into(), message: "`1`".into() },
+            Annotation { line: 1, detector: "explicit_return_statement".into(), message: "The `f` function".into() },
+            Annotation { line: 3, detector: "other".into(), message: "text".into() },
+        ]);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{error::Error, project::Project, Options};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// The name of the file labeling the true findings of a corpus package.
+pub const EXPECTED_FILE_NAME: &str = "expected.json";
+
+/// A true finding in a corpus package, i.e:
+///
+/// ```json
+/// [{ "file": "src/main.sw", "line": 26, "detector": "arbitrary_code_execution" }]
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpectedFinding {
+    /// The path of the file containing the finding, relative to the package directory.
+    pub file: PathBuf,
+    pub line: usize,
+    pub detector: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DetectorScore {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+}
+
+impl DetectorScore {
+    /// The fraction of reported findings that are true findings, or `None` if nothing was reported.
+    pub fn precision(&self) -> Option<f64> {
+        let reported = self.true_positives + self.false_positives;
+        (reported > 0).then(|| self.true_positives as f64 / reported as f64)
+    }
+
+    /// The fraction of true findings that were reported, or `None` if there are no true findings.
+    pub fn recall(&self) -> Option<f64> {
+        let expected = self.true_positives + self.false_negatives;
+        (expected > 0).then(|| self.true_positives as f64 / expected as f64)
+    }
+
+    fn add(&mut self, other: &DetectorScore) {
+        self.true_positives += other.true_positives;
+        self.false_positives += other.false_positives;
+        self.false_negatives += other.false_negatives;
+    }
+}
+
+/// The precision and recall of each detector over a labeled corpus.
+#[derive(Clone, Debug, Default)]
+pub struct Benchmark {
+    pub packages: usize,
+    pub scores: BTreeMap<String, DetectorScore>,
+}
+
+impl Benchmark {
+    /// Attempts to run the detectors selected by the supplied `options` over each package in the `corpus` directory containing an `expected.json` file.
+    pub fn run<P: AsRef<Path>>(corpus: P, options: &Options) -> Result<Self, Error> {
+        let mut package_paths = vec![];
+        find_packages(corpus.as_ref(), &mut package_paths)?;
+        package_paths.sort();
+
+        let mut benchmark = Benchmark::default();
+
+        for package_path in package_paths {
+            benchmark.add_package(&package_path, options)?;
+        }
+
+        Ok(benchmark)
+    }
+
+    fn add_package(&mut self, package_path: &Path, options: &Options) -> Result<(), Error> {
+        let expected_path = package_path.join(EXPECTED_FILE_NAME);
+        let expected_source = std::fs::read_to_string(&expected_path).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        let mut expected: Vec<ExpectedFinding> = serde_json::from_str(&expected_source).map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let package_options = Options {
+            directory: Some(format!("{}/", package_path.to_string_lossy().trim_end_matches('/')).into()),
+            detectors: options.detectors.clone(),
+            rules: options.rules.clone(),
+            plugins: options.plugins.clone(),
+            // Score every entry, since entries triaged as false positives in the package would otherwise be hidden and inflate the precision
+            show_triaged: true,
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&package_options)?;
+        project.analyze_modules()?;
+
+        // Only score the detectors that were run
+        expected.retain(|finding| project.detector_names().contains(&finding.detector));
+
+        for detector_name in project.detector_names() {
+            self.scores.entry(detector_name.clone()).or_default();
+        }
+
+        let report = project.report.borrow();
+
+        for (path, entries) in report.entries.iter() {
+            let file = path.strip_prefix(package_options.directory.as_ref().unwrap()).unwrap_or(path);
+
+            for entry in entries.iter() {
+                let score = self.scores.entry(entry.detector.clone()).or_default();
+
+                let position = expected.iter().position(|finding| {
+                    finding.file == file && Some(finding.line) == entry.line && finding.detector == entry.detector
+                });
+
+                match position {
+                    Some(position) => {
+                        expected.remove(position);
+                        score.true_positives += 1;
+                    }
+
+                    None => score.false_positives += 1,
+                }
+            }
+        }
+
+        for finding in expected {
+            self.scores.entry(finding.detector).or_default().false_negatives += 1;
+        }
+
+        self.packages += 1;
+
+        Ok(())
+    }
+
+    /// The combined score of every detector.
+    pub fn total(&self) -> DetectorScore {
+        let mut total = DetectorScore::default();
+
+        for score in self.scores.values() {
+            total.add(score);
+        }
+
+        total
+    }
+}
+
+fn find_packages(path: &Path, package_paths: &mut Vec<PathBuf>) -> Result<(), Error> {
+    if path.join(EXPECTED_FILE_NAME).is_file() {
+        package_paths.push(path.into());
+        return Ok(());
+    }
+
+    for entry in path.read_dir().map_err(|e| Error::Wrapped(Box::new(e)))? {
+        let Ok(entry) = entry else { continue };
+
+        if entry.path().is_dir() {
+            find_packages(&entry.path(), package_paths)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_ratio = |ratio: Option<f64>| ratio.map(|x| format!("{:.1}%", x * 100.0)).unwrap_or_else(|| "-".to_string());
+
+        let total = self.total();
+
+        let rows = self.scores.iter()
+            .map(|(detector, score)| (detector.as_str(), score))
+            .chain(std::iter::once(("Total", &total)))
+            .map(|(detector, score)| [
+                detector.to_string(),
+                score.true_positives.to_string(),
+                score.false_positives.to_string(),
+                score.false_negatives.to_string(),
+                format_ratio(score.precision()),
+                format_ratio(score.recall()),
+            ])
+            .collect::<Vec<_>>();
+
+        let header = ["Detector", "TP", "FP", "FN", "Precision", "Recall"].map(String::from);
+        let mut widths = header.clone().map(|x| x.len());
+
+        for row in rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: &[String; 6]| -> std::fmt::Result {
+            write!(f, "{:<width$}", row[0], width = widths[0])?;
+
+            for (cell, width) in row.iter().zip(widths.iter()).skip(1) {
+                write!(f, "  {cell:>width$}")?;
+            }
+
+            writeln!(f)
+        };
+
+        writeln!(f, "Benchmarked {} {}:", self.packages, if self.packages == 1 { "package" } else { "packages" })?;
+        writeln!(f)?;
+
+        write_row(f, &header)?;
+
+        for (i, row) in rows.iter().enumerate() {
+            // Separate the total from the detector rows
+            if i == rows.len() - 1 {
+                writeln!(f, "{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)))?;
+            }
+
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark() {
+        let options = Options {
+            detectors: vec!["arbitrary_code_execution".to_string(), "boolean_comparison".to_string(), "magic_number".to_string()],
+            ..Default::default()
+        };
+
+        let benchmark = Benchmark::run("test/", &options).unwrap();
+
+        assert_eq!(benchmark.packages, 2);
+        assert_eq!(benchmark.scores["arbitrary_code_execution"], DetectorScore { true_positives: 1, false_positives: 0, false_negatives: 0 });
+        assert_eq!(benchmark.scores["boolean_comparison"], DetectorScore { true_positives: 10, false_positives: 0, false_negatives: 0 });
+        assert_eq!(benchmark.scores["magic_number"], DetectorScore { true_positives: 0, false_positives: 3, false_negatives: 0 });
+        assert_eq!(benchmark.scores["magic_number"].precision(), Some(0.0));
+        assert_eq!(benchmark.scores["magic_number"].recall(), None);
+        assert_eq!(benchmark.total().precision(), Some(11.0 / 14.0));
+    }
+
+    #[test]
+    fn test_benchmark_ignores_triage() {
+        let corpus = std::env::temp_dir().join(format!("sway-analyzer-benchmark-{}", std::process::id()));
+        let package = corpus.join("boolean_comparison");
+
+        std::fs::create_dir_all(package.join("src")).unwrap();
+
+        for file in ["Forc.toml", "expected.json", "src/main.sw"] {
+            std::fs::copy(format!("test/boolean_comparison/{file}"), package.join(file)).unwrap();
+        }
+
+        let options = Options {
+            detectors: vec!["boolean_comparison".to_string()],
+            ..Default::default()
+        };
+
+        // Triage every entry as a false positive in the package's default triage database
+        let mut project = Project::try_from(&Options {
+            directory: Some(format!("{}/", package.to_string_lossy()).into()),
+            detectors: options.detectors.clone(),
+            ..Default::default()
+        }).unwrap();
+        project.analyze_modules().unwrap();
+
+        let mut database = crate::triage::TriageDatabase::default();
+
+        for entry in project.report.borrow().entries.iter().flat_map(|(_, entries)| entries.iter()) {
+            database.insert(entry.fingerprint.clone(), crate::triage::TriageEntry {
+                status: crate::triage::TriageStatus::FalsePositive,
+                note: String::new(),
+            });
+        }
+
+        database.save(package.join(crate::triage::DEFAULT_TRIAGE_PATH)).unwrap();
+
+        let benchmark = Benchmark::run(&corpus, &options).unwrap();
+        assert_eq!(benchmark.scores["boolean_comparison"], DetectorScore { true_positives: 10, false_positives: 0, false_negatives: 0 });
+
+        std::fs::remove_dir_all(&corpus).unwrap();
+    }
+}
@@ -0,0 +1,679 @@
+use crate::{
+    types::Type,
+    utils::{self, expr_key, source_key, ExprKey},
+};
+use num_bigint::BigUint;
+use num_traits::{CheckedSub, ToPrimitive, Zero};
+use std::{collections::HashMap, fmt::Display};
+use sway_ast::{
+    expr::LoopControlFlow, literal::{LitBoolType, LitIntType}, CodeBlockContents, Expr,
+    ExprArrayDescriptor, ExprTupleDescriptor, FnArgs, IfCondition, IfExpr, ItemFn, ItemImplItem,
+    ItemKind, Literal, MatchBranchKind, Module, Pattern, Statement, Ty,
+};
+use sway_types::Spanned;
+
+/// A value of a constant expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConstValue {
+    Bool(bool),
+
+    /// An unsigned integer with its width in bits, or `None` for an unsuffixed literal.
+    Int(BigUint, Option<u16>),
+
+    B256(BigUint),
+    Str(String),
+}
+
+impl ConstValue {
+    /// Gets the value as an integer if it is one.
+    pub fn as_int(&self) -> Option<&BigUint> {
+        match self {
+            ConstValue::Int(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ConstValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a `u64` if it is an integer that fits.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_int()?.to_u64()
+    }
+
+    /// Checks that an integer result fits in the supplied `bits`, defaulting to `u64` for unsuffixed literals.
+    fn int(value: BigUint, bits: Option<u16>) -> Option<Self> {
+        (value <= max_value(bits.unwrap_or(64))).then_some(ConstValue::Int(value, bits))
+    }
+
+    /// Converts the value to the supplied declared type, or `None` if it does not fit.
+    fn with_type(self, ty: &Ty) -> Option<Self> {
+        let ty = Type::from_ty(ty);
+
+        if ty.name() == Some("b256") {
+            return matches!(self, ConstValue::B256(_)).then_some(self);
+        }
+
+        let Some(bits) = ty.int_bits() else { return Some(self) };
+
+        match self {
+            ConstValue::Int(value, _) => Self::int(value, Some(bits)),
+            _ => None,
+        }
+    }
+}
+
+/// Gets the largest value of an unsigned integer with the supplied `bits`.
+pub fn max_value(bits: u16) -> BigUint {
+    (BigUint::from(1u8) << bits) - 1u8
+}
+
+/// The origin of a constant value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConstOrigin {
+    /// Fixed at compile time by literals and `const` items.
+    Fixed,
+
+    /// Derived from a `configurable` default, which can be overridden when the contract is deployed.
+    Configurable,
+}
+
+/// A folded constant value along with its origin.
+type Folded = (ConstValue, ConstOrigin);
+
+impl Display for ConstValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstValue::Bool(value) => write!(f, "{value}"),
+            ConstValue::Int(value, _) => write!(f, "{value}"),
+            ConstValue::B256(value) => write!(f, "0x{value:064x}"),
+            ConstValue::Str(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+fn eval_literal(literal: &Literal) -> Option<ConstValue> {
+    match literal {
+        Literal::Bool(lit_bool) => Some(ConstValue::Bool(matches!(lit_bool.kind, LitBoolType::True))),
+        Literal::String(lit_string) => Some(ConstValue::Str(lit_string.parsed.clone())),
+        Literal::Char(_) => None,
+
+        Literal::Int(lit_int) => {
+            let text = lit_int.span.as_str().replace('_', "");
+
+            // 64 hex digit literals are `b256` values
+            if lit_int.ty_opt.is_none() && text.strip_prefix("0x").map(|x| x.len() == 64).unwrap_or(false) {
+                return Some(ConstValue::B256(lit_int.parsed.clone()));
+            }
+
+            let bits = match lit_int.ty_opt.as_ref().map(|(ty, _)| ty) {
+                None => None,
+                Some(LitIntType::U8) => Some(8),
+                Some(LitIntType::U16) => Some(16),
+                Some(LitIntType::U32) => Some(32),
+                Some(LitIntType::U64) => Some(64),
+                Some(LitIntType::U256) => Some(256),
+                Some(LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64) => return None,
+            };
+
+            ConstValue::int(lit_int.parsed.clone(), bits)
+        }
+    }
+}
+
+/// Folds a binary operation over constant operands, or `None` if it overflows or is not supported.
+fn eval_binary(expr: &Expr, lhs: ConstValue, rhs: ConstValue) -> Option<ConstValue> {
+    use ConstValue::*;
+
+    match (expr, lhs, rhs) {
+        (Expr::Equal { .. }, lhs, rhs) => Some(Bool(lhs == rhs || matches!((&lhs, &rhs), (Int(a, _), Int(b, _)) if a == b))),
+        (Expr::NotEqual { .. }, lhs, rhs) => Some(Bool(lhs != rhs && !matches!((&lhs, &rhs), (Int(a, _), Int(b, _)) if a == b))),
+        (Expr::LessThan { .. }, Int(a, _), Int(b, _)) => Some(Bool(a < b)),
+        (Expr::GreaterThan { .. }, Int(a, _), Int(b, _)) => Some(Bool(a > b)),
+        (Expr::LessThanEq { .. }, Int(a, _), Int(b, _)) => Some(Bool(a <= b)),
+        (Expr::GreaterThanEq { .. }, Int(a, _), Int(b, _)) => Some(Bool(a >= b)),
+        (Expr::LogicalAnd { .. }, Bool(a), Bool(b)) => Some(Bool(a && b)),
+        (Expr::LogicalOr { .. }, Bool(a), Bool(b)) => Some(Bool(a || b)),
+
+        (_, Int(a, a_bits), Int(b, b_bits)) => {
+            let bits = a_bits.or(b_bits);
+
+            let value = match expr {
+                Expr::Add { .. } => a + b,
+                Expr::Sub { .. } => a.checked_sub(&b)?,
+                Expr::Mul { .. } => a * b,
+                Expr::Div { .. } if !b.is_zero() => a / b,
+                Expr::Modulo { .. } if !b.is_zero() => a % b,
+                Expr::Pow { .. } => a.pow(b.to_u32().filter(|x| *x <= 256)?),
+                Expr::BitAnd { .. } => a & b,
+                Expr::BitOr { .. } => a | b,
+                Expr::BitXor { .. } => a ^ b,
+
+                // Shifted out bits are discarded
+                Expr::Shl { .. } => (a << b.to_usize().filter(|x| *x <= 256)?) & max_value(a_bits.unwrap_or(64)),
+                Expr::Shr { .. } => a >> b.to_usize()?,
+
+                _ => return None,
+            };
+
+            ConstValue::int(value, if matches!(expr, Expr::Shl { .. } | Expr::Shr { .. }) { a_bits } else { bits })
+        }
+
+        (_, B256(a), B256(b)) => match expr {
+            Expr::BitAnd { .. } => Some(B256(a & b)),
+            Expr::BitOr { .. } => Some(B256(a | b)),
+            Expr::BitXor { .. } => Some(B256(a ^ b)),
+            _ => None,
+        },
+
+        (_, B256(a), Int(b, _)) => match expr {
+            Expr::Shl { .. } => Some(B256((a << b.to_usize().filter(|x| *x <= 256)?) & max_value(256))),
+            Expr::Shr { .. } => Some(B256(a >> b.to_usize()?)),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+/// A constant evaluator over a set of modules.
+///
+/// Folds `const` items, `configurable` defaults and immutable local bindings through arithmetic, bitwise, comparison and logical operators.
+///
+/// Constants are keyed by the module declaring them, and values derived from `configurable` defaults are recorded as such.
+#[derive(Default)]
+pub struct ConstEvaluator {
+    constants: HashMap<(usize, String), Option<Folded>>,
+    expr_values: HashMap<ExprKey, Folded>,
+    /// The source key of the module currently being evaluated.
+    module: usize,
+}
+
+impl ConstEvaluator {
+    /// Evaluates every constant expression in the supplied `modules`.
+    pub fn new<'a, I: IntoIterator<Item = &'a Module>>(modules: I) -> Self {
+        let modules = modules.into_iter().collect::<Vec<_>>();
+        let mut result = Self::default();
+
+        let mut constant_exprs = HashMap::new();
+
+        for module in modules.iter() {
+            let module_key = source_key(&module.span());
+
+            for item in module.items.iter() {
+                match &item.value {
+                    ItemKind::Const(item_const) => {
+                        if let Some(expr) = item_const.expr_opt.as_ref() {
+                            let ty = item_const.ty_opt.as_ref().map(|(_, ty)| ty);
+                            constant_exprs.insert((module_key, item_const.name.as_str().to_string()), (expr, ty, ConstOrigin::Fixed));
+                        }
+                    }
+
+                    ItemKind::Configurable(item_configurable) => {
+                        for field in (&item_configurable.fields.inner).into_iter() {
+                            let key = (module_key, field.value.name.as_str().to_string());
+                            constant_exprs.insert(key, (&field.value.initializer, Some(&field.value.ty), ConstOrigin::Configurable));
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        // Constants can refer to each other in any order, so evaluate them until no more are resolved
+        loop {
+            let mut resolved = false;
+
+            for (key, (expr, ty, origin)) in constant_exprs.iter() {
+                if result.constants.get(key).map(Option::is_some).unwrap_or(false) {
+                    continue;
+                }
+
+                result.module = key.0;
+
+                let value = result.eval_expr(expr, &mut vec![]).and_then(|(value, value_origin)| {
+                    let value = match ty {
+                        Some(ty) => value.with_type(ty)?,
+                        None => value,
+                    };
+
+                    Some((value, value_origin.max(*origin)))
+                });
+
+                resolved |= value.is_some();
+                result.constants.insert(key.clone(), value);
+            }
+
+            if !resolved {
+                break;
+            }
+        }
+
+        for module in modules.iter() {
+            result.module = source_key(&module.span());
+
+            for item in module.items.iter() {
+                result.eval_item(&item.value);
+            }
+        }
+
+        result
+    }
+
+    /// Gets the value of the supplied `expr` if it is fixed at compile time.
+    ///
+    /// Values derived from `configurable` defaults are treated as unknown, since they can be overridden when the contract is deployed.
+    pub fn value_of(&self, expr: &Expr) -> Option<ConstValue> {
+        self.value_with_origin_of(expr).and_then(|(value, origin)| (origin == ConstOrigin::Fixed).then_some(value))
+    }
+
+    /// Gets the value of the supplied `expr` along with its origin if it is constant, including values derived from `configurable` defaults.
+    pub fn value_with_origin_of(&self, expr: &Expr) -> Option<(ConstValue, ConstOrigin)> {
+        self.expr_values.get(&expr_key(expr)).cloned()
+    }
+
+    fn eval_item(&mut self, item: &ItemKind) {
+        match item {
+            ItemKind::Fn(item_fn) => self.eval_fn(item_fn),
+
+            ItemKind::Impl(item_impl) => {
+                for item in item_impl.contents.inner.iter() {
+                    match &item.value {
+                        ItemImplItem::Fn(item_fn) => self.eval_fn(item_fn),
+
+                        ItemImplItem::Const(item_const) => {
+                            if let Some(expr) = item_const.expr_opt.as_ref() {
+                                self.eval_expr(expr, &mut vec![]);
+                            }
+                        }
+
+                        ItemImplItem::Type(_) => {}
+                    }
+                }
+            }
+
+            ItemKind::Abi(item_abi) => {
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.eval_fn(&item.value);
+                }
+            }
+
+            ItemKind::Trait(item_trait) => {
+                for item in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.eval_fn(&item.value);
+                }
+            }
+
+            ItemKind::Const(item_const) => {
+                if let Some(expr) = item_const.expr_opt.as_ref() {
+                    self.eval_expr(expr, &mut vec![]);
+                }
+            }
+
+            ItemKind::Configurable(item_configurable) => {
+                for field in (&item_configurable.fields.inner).into_iter() {
+                    self.eval_expr(&field.value.initializer, &mut vec![]);
+                }
+            }
+
+            ItemKind::Storage(item_storage) => {
+                for field in (&item_storage.fields.inner).into_iter() {
+                    self.eval_expr(&field.value.initializer, &mut vec![]);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn eval_fn(&mut self, item_fn: &ItemFn) {
+        // Arguments shadow constants
+        let mut scope = HashMap::new();
+
+        let args = match &item_fn.fn_signature.arguments.inner {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.iter().flat_map(|(_, args)| args.into_iter()).collect(),
+        };
+
+        for arg in args {
+            Self::bind_pattern(&arg.pattern, None, &mut scope);
+        }
+
+        self.eval_block(&item_fn.body.inner, &mut vec![scope]);
+    }
+
+    /// Binds the variables in the supplied `pattern`, keeping the `value` of immutable single variable bindings.
+    fn bind_pattern(pattern: &Pattern, value: Option<Folded>, scope: &mut HashMap<String, Option<Folded>>) {
+        match pattern {
+            Pattern::AmbiguousSingleIdent(name) | Pattern::Var { reference: None, mutable: None, name } => {
+                scope.insert(name.as_str().to_string(), value);
+            }
+
+            pattern => {
+                for ident in utils::fold_pattern_idents(pattern) {
+                    scope.insert(ident.as_str().to_string(), None);
+                }
+            }
+        }
+    }
+
+    fn eval_block(&mut self, block: &CodeBlockContents, scopes: &mut Vec<HashMap<String, Option<Folded>>>) -> Option<Folded> {
+        scopes.push(HashMap::new());
+
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Let(statement_let) => {
+                    let value = self.eval_expr(&statement_let.expr, scopes).and_then(|(value, origin)| match statement_let.ty_opt.as_ref() {
+                        Some((_, ty)) => Some((value.with_type(ty)?, origin)),
+                        None => Some((value, origin)),
+                    });
+
+                    Self::bind_pattern(&statement_let.pattern, value, scopes.last_mut().unwrap());
+                }
+
+                Statement::Expr { expr, .. } => {
+                    self.eval_expr(expr, scopes);
+                }
+
+                Statement::Item(_) | Statement::Error(..) => {}
+            }
+        }
+
+        let result = block.final_expr_opt.as_ref().and_then(|expr| self.eval_expr(expr, scopes));
+
+        scopes.pop();
+
+        result
+    }
+
+    fn eval_if(&mut self, if_expr: &IfExpr, scopes: &mut Vec<HashMap<String, Option<Folded>>>) -> Option<Folded> {
+        let mut scope = HashMap::new();
+
+        let condition = match &if_expr.condition {
+            IfCondition::Expr(expr) => self.eval_expr(expr, scopes).and_then(|(value, origin)| Some((value.as_bool()?, origin))),
+
+            IfCondition::Let { lhs, rhs, .. } => {
+                self.eval_expr(rhs, scopes);
+                Self::bind_pattern(lhs, None, &mut scope);
+                None
+            }
+        };
+
+        scopes.push(scope);
+        let then_value = self.eval_block(&if_expr.then_block.inner, scopes);
+        scopes.pop();
+
+        let else_value = match if_expr.else_opt.as_ref() {
+            Some((_, LoopControlFlow::Continue(if_expr))) => self.eval_if(if_expr, scopes),
+            Some((_, LoopControlFlow::Break(block))) => self.eval_block(&block.inner, scopes),
+            None => None,
+        };
+
+        // The chosen branch depends on the origin of the condition
+        let (condition, condition_origin) = condition?;
+        let (value, origin) = if condition { then_value } else { else_value }?;
+
+        Some((value, origin.max(condition_origin)))
+    }
+
+    fn eval_exprs<'e, I: IntoIterator<Item = &'e Expr>>(&mut self, exprs: I, scopes: &mut Vec<HashMap<String, Option<Folded>>>) {
+        for expr in exprs {
+            self.eval_expr(expr, scopes);
+        }
+    }
+
+    fn eval_expr(&mut self, expr: &Expr, scopes: &mut Vec<HashMap<String, Option<Folded>>>) -> Option<Folded> {
+        let result = match expr {
+            Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() => {
+                let name = path.prefix.name.as_str();
+
+                // Constants declared in other modules are not folded, since they may belong to another package
+                match scopes.iter().rev().find_map(|scope| scope.get(name)) {
+                    Some(value) => value.clone(),
+                    None => self.constants.get(&(self.module, name.to_string())).cloned().flatten(),
+                }
+            }
+
+            Expr::Literal(literal) => eval_literal(literal).map(|value| (value, ConstOrigin::Fixed)),
+
+            Expr::Parens(expr) => self.eval_expr(&expr.inner, scopes),
+
+            Expr::Block(block) => self.eval_block(&block.inner, scopes),
+
+            Expr::If(if_expr) => self.eval_if(if_expr, scopes),
+
+            Expr::Not { expr, .. } => match self.eval_expr(expr, scopes) {
+                Some((ConstValue::Bool(value), origin)) => Some((ConstValue::Bool(!value), origin)),
+                Some((ConstValue::Int(value, bits), origin)) => Some((ConstValue::Int(value ^ max_value(bits.unwrap_or(64)), bits), origin)),
+                Some((ConstValue::B256(value), origin)) => Some((ConstValue::B256(value ^ max_value(256)), origin)),
+                _ => None,
+            },
+
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. }
+            | Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. }
+            | Expr::LogicalAnd { lhs, rhs, .. }
+            | Expr::LogicalOr { lhs, rhs, .. } => {
+                let lhs = self.eval_expr(lhs, scopes);
+                let rhs = self.eval_expr(rhs, scopes);
+                let ((lhs, lhs_origin), (rhs, rhs_origin)) = (lhs?, rhs?);
+                eval_binary(expr, lhs, rhs).map(|value| (value, lhs_origin.max(rhs_origin)))
+            }
+
+            // Evaluate nested expressions without folding the expression itself
+            Expr::Path(_) | Expr::Error(..) | Expr::Break { .. } | Expr::Continue { .. } => None,
+
+            Expr::AbiCast { args, .. } => {
+                self.eval_expr(&args.inner.address, scopes);
+                None
+            }
+
+            Expr::Struct { fields, .. } => {
+                self.eval_exprs((&fields.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                None
+            }
+
+            Expr::Tuple(tuple) => {
+                if let ExprTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                    self.eval_exprs(std::iter::once(head.as_ref()).chain(tail), scopes);
+                }
+
+                None
+            }
+
+            Expr::Array(array) => {
+                match &array.inner {
+                    ExprArrayDescriptor::Sequence(exprs) => self.eval_exprs(exprs, scopes),
+                    ExprArrayDescriptor::Repeat { value, length, .. } => self.eval_exprs([value.as_ref(), length.as_ref()], scopes),
+                }
+
+                None
+            }
+
+            Expr::Asm(asm) => {
+                self.eval_exprs((&asm.registers.inner).into_iter().filter_map(|register| register.value_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                None
+            }
+
+            Expr::Return { expr_opt, .. } => {
+                self.eval_exprs(expr_opt.iter().map(|expr| expr.as_ref()), scopes);
+                None
+            }
+
+            Expr::Match { value, branches, .. } => {
+                self.eval_expr(value, scopes);
+
+                for branch in branches.inner.iter() {
+                    let mut scope = HashMap::new();
+                    Self::bind_pattern(&branch.pattern, None, &mut scope);
+                    scopes.push(scope);
+
+                    match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => { self.eval_block(&block.inner, scopes); }
+                        MatchBranchKind::Expr { expr, .. } => { self.eval_expr(expr, scopes); }
+                    }
+
+                    scopes.pop();
+                }
+
+                None
+            }
+
+            Expr::While { condition, block, .. } => {
+                self.eval_expr(condition, scopes);
+                self.eval_block(&block.inner, scopes);
+                None
+            }
+
+            Expr::FuncApp { func, args } => {
+                self.eval_expr(func, scopes);
+                self.eval_exprs(&args.inner, scopes);
+                None
+            }
+
+            Expr::Index { target, arg } => {
+                self.eval_exprs([target.as_ref(), arg.inner.as_ref()], scopes);
+                None
+            }
+
+            Expr::MethodCall { target, contract_args_opt, args, .. } => {
+                self.eval_expr(target, scopes);
+
+                if let Some(contract_args) = contract_args_opt.as_ref() {
+                    self.eval_exprs((&contract_args.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                }
+
+                self.eval_exprs(&args.inner, scopes);
+                None
+            }
+
+            Expr::FieldProjection { target, .. }
+            | Expr::TupleFieldProjection { target, .. }
+            | Expr::Ref { expr: target, .. }
+            | Expr::Deref { expr: target, .. } => {
+                self.eval_expr(target, scopes);
+                None
+            }
+
+            Expr::Reassignment { expr, .. } => {
+                self.eval_expr(expr, scopes);
+                None
+            }
+        };
+
+        if let Some(value) = result.as_ref() {
+            self.expr_values.insert(expr_key(expr), value.clone());
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_value_of() {
+        let source = r#"contract;
+
+const MAX: u64 = LIMIT * 2;
+const LIMIT: u64 = 1 << 4;
+const MASK: b256 = 0x00000000000000000000000000000000000000000000000000000000000000ff;
+
+configurable {
+    ENABLED: bool = !false,
+}
+
+fn f(x: u64) -> u64 {
+    let limit = MAX * 2;
+    let mut i = 0;
+    let small = 255u8;
+    let overflow = small + 1;
+    let masked = MASK & (MASK >> 4);
+    while i < limit {
+        i += 1;
+    }
+    let limit = x;
+    if ENABLED && limit > 10 {
+        return 0;
+    }
+    let difference = 1 - 2;
+    let block = { let y = 3; y * (2 ** 3) };
+    if MAX > LIMIT { 1 } else { 2 }
+}
+"#;
+
+        let values = crate::tests::collect_expr_results(source, |context, project| project.const_value(context.expr).map(|x| x.to_string()));
+
+        for (expr, expected) in [
+            ("LIMIT * 2", Some("32")),
+            ("MAX * 2", Some("64")),
+            ("i < limit", None),
+            ("small + 1", None),
+            ("MASK & (MASK >> 4)", Some("0x000000000000000000000000000000000000000000000000000000000000000f")),
+            ("ENABLED && limit > 10", None),
+            ("ENABLED", None),
+            ("1 - 2", None),
+            ("{ let y = 3; y * (2 ** 3) }", Some("24")),
+            ("if MAX > LIMIT { 1 } else { 2 }", Some("1")),
+        ] {
+            assert_eq!(values.get(expr).cloned().flatten().as_deref(), expected, "Unexpected value for `{expr}`");
+        }
+    }
+
+    #[test]
+    fn test_value_of_per_module() {
+        let a = r#"library;
+
+const MAX: u64 = 10;
+
+configurable {
+    FEE: u64 = 0,
+}
+
+fn a() -> u64 {
+    let fee = FEE + 1;
+    MAX + 1
+}
+"#;
+
+        let b = r#"library;
+
+const MAX: u64 = 20;
+
+fn b() -> u64 {
+    MAX + 2
+}
+"#;
+
+        let mut project = crate::tests::parse_modules(&[("a.sw", a), ("b.sw", b)]);
+
+        let values = crate::tests::collect_project_expr_results(&mut project, |context, project| {
+            project.const_value(context.expr).map(|x| x.to_string())
+        });
+
+        assert_eq!(values["MAX + 1"].as_deref(), Some("11"));
+        assert_eq!(values["MAX + 2"].as_deref(), Some("22"));
+
+        // Configurable defaults can be overridden when deploying
+        assert_eq!(values["FEE + 1"], None);
+    }
+}
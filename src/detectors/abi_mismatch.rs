@@ -0,0 +1,81 @@
+use crate::{
+    abi::{AbiMismatchKind, FnAttribute},
+    error::Error,
+    project::Project,
+    report::Severity,
+    visitor::{AstVisitor, ModuleContext},
+};
+
+#[derive(Default)]
+pub struct AbiMismatchVisitor;
+
+fn attributes_to_string(attributes: &[FnAttribute]) -> String {
+    let attributes = attributes.iter().map(|attribute| attribute.to_string()).collect::<Vec<_>>();
+    format!("`{}`", attributes.join(" "))
+}
+
+impl AstVisitor for AbiMismatchVisitor {
+    fn leave_module(&mut self, context: &ModuleContext, project: &mut Project) -> Result<(), Error> {
+        for mismatch in project.abi().module_mismatches(context.module) {
+            let message = match &mismatch.kind {
+                AbiMismatchKind::MissingImplementation => format!(
+                    "The `{}::{}` ABI method is not implemented for `Contract`.",
+                    mismatch.abi_name,
+                    mismatch.method_name,
+                ),
+
+                AbiMismatchKind::UndeclaredMethod => format!(
+                    "The `Contract::{}` function is not declared in the `{}` ABI.",
+                    mismatch.method_name,
+                    mismatch.abi_name,
+                ),
+
+                AbiMismatchKind::Attributes { declared, implemented } if implemented.is_empty() => format!(
+                    "The `Contract::{}` function is missing {} declared in the `{}` ABI.",
+                    mismatch.method_name,
+                    attributes_to_string(declared),
+                    mismatch.abi_name,
+                ),
+
+                AbiMismatchKind::Attributes { declared, implemented } if declared.is_empty() => format!(
+                    "The `Contract::{}` function has {} which is not declared in the `{}` ABI.",
+                    mismatch.method_name,
+                    attributes_to_string(implemented),
+                    mismatch.abi_name,
+                ),
+
+                AbiMismatchKind::Attributes { declared, implemented } => format!(
+                    "The `Contract::{}` function has {} but the `{}` ABI declares {}.",
+                    mismatch.method_name,
+                    attributes_to_string(implemented),
+                    mismatch.abi_name,
+                    attributes_to_string(declared),
+                ),
+
+                AbiMismatchKind::Signature { declared } => format!(
+                    "The `Contract::{}` function has a signature which does not match its declaration in the `{}` ABI: `{}`.",
+                    mismatch.method_name,
+                    mismatch.abi_name,
+                    declared,
+                ),
+            };
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &mismatch.span)?,
+                Severity::Medium,
+                message,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_abi_mismatch() {
+        crate::tests::test_detector("abi_mismatch", 5);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    utils,
+    visitor::{AstVisitor, ExprContext},
+};
+use sway_ast::{expr::ReassignmentOpVariant, Expr};
+use sway_types::Spanned;
+
+#[derive(Default)]
+pub struct ArithmeticRevertVisitor;
+
+impl AstVisitor for ArithmeticRevertVisitor {
+    fn visit_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
+        // Check for arithmetic operations which revert for every possible operand value
+        if project.always_reverts(context.expr) {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &context.expr.span())?,
+                Severity::High,
+                format!(
+                    "{} contains an arithmetic operation which always reverts: `{}`. Consider checking the range of its operands.",
+                    utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                    context.expr.span().as_str(),
+                ),
+            );
+
+            return Ok(());
+        }
+
+        // Check for divisions by a value which can be zero
+        let divisor = match context.expr {
+            Expr::Div { rhs, .. } | Expr::Modulo { rhs, .. } => rhs.as_ref(),
+            Expr::Reassignment { reassignment_op, expr, .. } if matches!(reassignment_op.variant, ReassignmentOpVariant::DivEquals) => expr.as_ref(),
+            _ => return Ok(()),
+        };
+
+        let Some(interval) = project.interval_of(divisor) else { return Ok(()) };
+
+        if !interval.contains_zero() {
+            return Ok(());
+        }
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            Severity::Medium,
+            format!(
+                "{} contains a division by a value which can be zero: `{}`. Consider requiring a non-zero divisor.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                context.expr.span().as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_arithmetic_revert() {
+        crate::tests::test_detector("arithmetic_revert", 8);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    error::Error,
+    metrics::MetricsCollector,
+    project::Project,
+    report::Severity,
+    utils,
+    visitor::{AstVisitor, BlockContext, ExprContext, FnContext, StatementContext},
+};
+
+#[derive(Default)]
+pub struct ComplexEntryPointVisitor {
+    metrics: MetricsCollector,
+}
+
+impl AstVisitor for ComplexEntryPointVisitor {
+    fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        self.metrics.visit_fn(context, project)
+    }
+
+    fn leave_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        let Some(metrics) = self.metrics.fn_metrics(context.item_fn) else { return Ok(()) };
+
+        // Only check functions callable from outside of the contract
+        if !metrics.entry_point {
+            return Ok(());
+        }
+
+        let exceeded = metrics.exceeded_thresholds(project.metrics_thresholds());
+
+        if exceeded.is_empty() {
+            return Ok(());
+        }
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            metrics.line,
+            Severity::Low,
+            format!(
+                "{} is overly complex ({}). Consider splitting it into smaller functions.",
+                utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                exceeded.join(", "),
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, project: &mut Project) -> Result<(), Error> {
+        self.metrics.visit_block(context, project)
+    }
+
+    fn visit_statement(&mut self, context: &StatementContext, project: &mut Project) -> Result<(), Error> {
+        self.metrics.visit_statement(context, project)
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
+        self.metrics.visit_expr(context, project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_complex_entry_point() {
+        crate::tests::test_detector("complex_entry_point", 2);
+    }
+}
@@ -45,19 +45,18 @@ impl AstVisitor for LockedNativeAssetVisitor {
         Ok(())
     }
 
-    fn visit_fn(&mut self, context: &FnContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
         // Get the module state
         let module_state = self.module_states.get_mut(context.path).unwrap();
 
-        for attr in context.fn_attributes {
-            if attr.span().as_str().contains("payable") {
-                module_state.can_receive = true;
-                
-                module_state.locking_functions.push((
-                    context.item_fn.fn_signature.span(),
-                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
-                ));
-            }
+        // Check for the `#[payable]` attribute on the function or its ABI declaration
+        if project.abi().fn_has_attribute(&context.item_fn.fn_signature, context.fn_attributes, "payable", &[]) {
+            module_state.can_receive = true;
+
+            module_state.locking_functions.push((
+                context.item_fn.fn_signature.span(),
+                utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+            ));
         }
 
         Ok(())
@@ -1,6 +1,9 @@
+mod abi_mismatch;
 mod arbitrary_asset_transfer;
 mod arbitrary_code_execution;
+mod arithmetic_revert;
 mod boolean_comparison;
+mod complex_entry_point;
 mod discarded_assignment;
 mod division_before_multiplication;
 mod explicit_return_statement;
@@ -30,10 +33,11 @@ mod weak_prng;
 use crate::visitor::AstVisitor;
 
 use self::{
-    arbitrary_asset_transfer::*, arbitrary_code_execution::*, boolean_comparison::*,
-    discarded_assignment::*, division_before_multiplication::*, explicit_return_statement::*,
-    external_call_in_loop::*, inline_assembly_usage::*, large_literal::*, locked_native_asset::*,
-    magic_number::*, manipulatable_balance_usage::*, missing_logs::*, msg_amount_in_loop::*,
+    abi_mismatch::*, arbitrary_asset_transfer::*, arbitrary_code_execution::*, arithmetic_revert::*,
+    boolean_comparison::*, complex_entry_point::*, discarded_assignment::*,
+    division_before_multiplication::*, explicit_return_statement::*, external_call_in_loop::*,
+    inline_assembly_usage::*, large_literal::*, locked_native_asset::*, magic_number::*,
+    manipulatable_balance_usage::*, missing_logs::*, msg_amount_in_loop::*,
     non_zero_identity_validation::*, potential_infinite_loop::*, redundant_comparison::*,
     redundant_storage_access::*, storage_field_mutability::*, storage_not_updated::*,
     storage_read_in_loop_condition::*, strict_equality::*, unchecked_call_payload::*,
@@ -45,9 +49,12 @@ type DetectorConstructor = fn() -> Box<dyn AstVisitor>;
 type DetectorEntry = (&'static str, DetectorConstructor);
 
 pub const DETECTOR_TYPES: &[DetectorEntry] = &[
+    ("abi_mismatch", || Box::new(AbiMismatchVisitor::default())),
     ("arbitrary_asset_transfer", || Box::new(ArbitraryAssetTransferVisitor::default())),
     ("arbitrary_code_execution", || Box::new(ArbitraryCodeExecutionVisitor::default())),
+    ("arithmetic_revert", || Box::new(ArithmeticRevertVisitor::default())),
     ("boolean_comparison", || Box::new(BooleanComparisonVisitor::default())),
+    ("complex_entry_point", || Box::new(ComplexEntryPointVisitor::default())),
     ("discarded_assignment", || Box::new(DiscardedAssignmentVisitor::default())),
     ("division_before_multiplication", || Box::new(DivisionBeforeMultiplicationVisitor::default())),
     ("explicit_return_statement", || Box::new(ExplicitReturnStatementVisitor::default())),
@@ -3,14 +3,11 @@ use crate::{
     project::Project,
     report::Severity,
     utils,
-    visitor::{
-        AstVisitor, BlockContext, ExprContext, FnContext, ModuleContext, UseContext,
-        WhileExprContext,
-    },
+    visitor::{AstVisitor, ExprContext, ModuleContext, UseContext},
 };
 use std::{collections::HashMap, path::PathBuf};
 use sway_ast::Expr;
-use sway_types::{Span, Spanned};
+use sway_types::Spanned;
 
 #[derive(Default)]
 pub struct MsgAmountInLoopVisitor {
@@ -20,18 +17,6 @@ pub struct MsgAmountInLoopVisitor {
 #[derive(Default)]
 struct ModuleState {
     msg_amount_names: Vec<String>,
-    fn_states: HashMap<Span, FnState>,
-}
-
-#[derive(Default)]
-struct FnState {
-    block_states: HashMap<Span, BlockState>,
-}
-
-#[derive(Default)]
-struct BlockState {
-    is_loop: bool,
-    msg_amount_spans: Vec<Span>,
 }
 
 impl AstVisitor for MsgAmountInLoopVisitor {
@@ -61,125 +46,23 @@ impl AstVisitor for MsgAmountInLoopVisitor {
         Ok(())
     }
 
-    fn visit_fn(&mut self, context: &FnContext, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path.into()).unwrap();
-
-        // Create the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-
-        if !module_state.fn_states.contains_key(&fn_signature) {
-            module_state.fn_states.insert(fn_signature, FnState::default());
-        }
-
-        Ok(())
-    }
-
-    fn visit_block(&mut self, context: &BlockContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
         // Get the module state
-        let module_state = self.module_states.get_mut(context.path.into()).unwrap();
-
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+        let module_state = self.module_states.get(context.path).unwrap();
 
-        // Create the block state
-        let block_span = context.block.span();
-        
-        if !fn_state.block_states.contains_key(&block_span) {
-            fn_state.block_states.insert(block_span, BlockState::default());
+        // Only check expressions inside of a loop
+        if context.ancestors.enclosing_loop().is_none() {
+            return Ok(());
         }
 
-        Ok(())
-    }
-
-    fn leave_block(&mut self, context: &BlockContext, project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get(context.path.into()).unwrap();
-
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
-
-        // Get the block state
-        let block_span = context.block.span();
-        let block_state = fn_state.block_states.get(&block_span).unwrap();
-
-        if block_state.msg_amount_spans.is_empty() {
-            return Ok(())
-        }
-
-        let msg_amount_spans = block_state.msg_amount_spans.clone();
-
-        let mut blocks = context.blocks.clone();
-        blocks.push(block_span);
-
-        for block_span in blocks.iter().rev() {
-            let block_state = fn_state.block_states.get(block_span).unwrap();
-
-            if block_state.is_loop {
-                for msg_amount_span in msg_amount_spans.iter() {
-                    project.report.borrow_mut().add_entry(
-                        context.path,
-                        project.span_to_line(context.path, msg_amount_span)?,
-                        Severity::Medium,
-                        format!(
-                            "{} makes a call to `{}` in a loop. Store the value in a variable outside the loop and decrement it over each iteration.",
-                            utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
-                            msg_amount_span.as_str(),
-                        ),
-                    );
-                }
-                break;
-            }
-        }
-        
-        Ok(())
-    }
-
-    fn visit_while_expr(&mut self, context: &WhileExprContext, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path.into()).unwrap();
-
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
-
-        // Get or create the block state
-        let block_span = context.body.span();
-        let block_state = fn_state.block_states.entry(block_span).or_insert_with(BlockState::default);
-        
-        // Mark the block as a loop
-        block_state.is_loop = true;
-
-        Ok(())
-    }
-
-    fn visit_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path.into()).unwrap();
-
-        // Get the function state
-        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
-        let fn_signature = item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
-
-        // Get the block state
-        let Some(block_span) = context.blocks.last() else { return Ok(()) };
-        let block_state = fn_state.block_states.get_mut(block_span).unwrap();
-
         // Destructure the expression into a function application
         let Expr::FuncApp { func, .. } = context.expr else { return Ok(()) };
         let Expr::Path(path) = func.as_ref() else { return Ok(()) };
         
         // Check for calls to imported `msg_amount` or `balance` functions
         if path.suffix.is_empty() {
-            for msg_amount_name in module_state.msg_amount_names.iter() {
-                if path.prefix.name.as_str() == msg_amount_name {
-                    // Add the `msg_amount` span to the block state
-                    block_state.msg_amount_spans.push(context.expr.span());
-                    break;
-                }
+            if !module_state.msg_amount_names.iter().any(|name| path.prefix.name.as_str() == name) {
+                return Ok(());
             }
         }
         // Check for calls to `std::context::msg_amount` or `std::registers::balance` functions
@@ -187,9 +70,21 @@ impl AstVisitor for MsgAmountInLoopVisitor {
             let "std" = path.prefix.name.as_str() else { return Ok(()) };
             let ("context" | "registers") = path.suffix[0].1.name.as_str() else { return Ok(()) };
             let ("balance" | "msg_amount") = path.suffix[1].1.name.as_str() else { return Ok(()) };
-            block_state.msg_amount_spans.push(context.expr.span());
+        } else {
+            return Ok(());
         }
 
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            Severity::Medium,
+            format!(
+                "{} makes a call to `{}` in a loop. Store the value in a variable outside the loop and decrement it over each iteration.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                context.expr.span().as_str(),
+            ),
+        );
+
         Ok(())
     }
 }
@@ -198,6 +93,6 @@ impl AstVisitor for MsgAmountInLoopVisitor {
 mod tests {
     #[test]
     fn test_msg_amount_in_loop() {
-        crate::tests::test_detector("msg_amount_in_loop", 4);
+        crate::tests::test_detector("msg_amount_in_loop", 5);
     }
 }
@@ -2,14 +2,15 @@ use crate::{
     error::Error,
     project::Project,
     report::Severity,
+    types::Type,
     utils,
     visitor::{
-        AstVisitor, BlockContext, FnContext, IfExprContext, ModuleContext, StatementContext,
+        AstVisitor, BlockContext, FnArgContext, FnContext, IfExprContext, ModuleContext, StatementContext,
     },
 };
 use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
 use sway_ast::{
-    expr::LoopControlFlow, Expr, FnArg, FnArgs, IfCondition, IfExpr, MatchBranchKind, Statement,
+    expr::LoopControlFlow, Expr, IfCondition, IfExpr, MatchBranchKind, Statement,
 };
 use sway_types::{Span, Spanned};
 
@@ -108,35 +109,33 @@ impl AstVisitor for NonZeroIdentityValidationVisitor {
 
         // Create the function state
         let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.entry(fn_signature).or_insert_with(FnState::default);
+        module_state.fn_states.entry(fn_signature).or_default();
 
-        // Check function arguments for `Address`, `ContractId` or `Identity` types and queue them to be checked
-        let mut check_for_identity_argument = |arg: &FnArg| {
-            match arg.ty.span().as_str() {
-                "Address" => {
-                    fn_state.address_checks.insert(arg.pattern.span(), false);
-                }
+        Ok(())
+    }
 
-                "ContractId" => {
-                    fn_state.contract_id_checks.insert(arg.pattern.span(), false);
-                }
+    fn visit_fn_arg(&mut self, context: &FnArgContext, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
 
-                "Identity" => {
-                    fn_state.identity_checks.insert(arg.pattern.span(), (Rc::new(RefCell::new(false)), Rc::new(RefCell::new(false))));
-                }
+        // Get the function state, skipping arguments of functions without a body
+        let Some(fn_state) = module_state.fn_states.get_mut(&context.fn_signature.span()) else { return Ok(()) };
 
-                _ => {}
+        // Check function arguments for `Address`, `ContractId` or `Identity` types and queue them to be checked
+        match Type::from_ty(&context.fn_arg.ty).name() {
+            Some("Address") => {
+                fn_state.address_checks.insert(context.fn_arg.pattern.span(), false);
             }
-        };
 
-        let args = match &context.item_fn.fn_signature.arguments.inner {
-            FnArgs::Static(args) => args,
-            FnArgs::NonStatic { args_opt: Some(args), .. } => &args.1,
-            _ => return Ok(()),
-        };
-        
-        for arg in args {
-            check_for_identity_argument(arg);
+            Some("ContractId") => {
+                fn_state.contract_id_checks.insert(context.fn_arg.pattern.span(), false);
+            }
+
+            Some("Identity") => {
+                fn_state.identity_checks.insert(context.fn_arg.pattern.span(), (Rc::new(RefCell::new(false)), Rc::new(RefCell::new(false))));
+            }
+
+            _ => {}
         }
 
         Ok(())
@@ -29,6 +29,7 @@ struct BlockState {
     is_while_loop: bool,
     has_break: bool,
     condition: Option<Expr>,
+    condition_value: Option<bool>,
     condition_updated: bool,
 }
 
@@ -86,7 +87,13 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
         let block_span = context.block.span();
         let block_state = fn_state.block_states.get(&block_span).unwrap();
 
-        if block_state.is_while_loop && (!block_state.has_break && !block_state.condition_updated) {
+        // Loops with a constant `false` condition never run, and constant `true` conditions can't be updated
+        let condition_updated = match block_state.condition_value {
+            Some(value) => !value,
+            None => block_state.condition_updated,
+        };
+
+        if block_state.is_while_loop && (!block_state.has_break && !condition_updated) {
             project.report.borrow_mut().add_entry(
                 context.path,
                 project.span_to_line(context.path, &block_span)?,
@@ -102,7 +109,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
         Ok(())
     }
 
-    fn visit_while_expr(&mut self, context: &WhileExprContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_while_expr(&mut self, context: &WhileExprContext, project: &mut Project) -> Result<(), Error> {
         // Get the module state
         let module_state = self.module_states.get_mut(context.path).unwrap();
         
@@ -117,6 +124,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
         // Mark the block as a loop and store its condition
         block_state.is_while_loop = true;
         block_state.condition = Some(context.condition.clone());
+        block_state.condition_value = project.const_value(context.condition).and_then(|value| value.as_bool());
 
         Ok(())
     }
@@ -315,6 +323,6 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
 mod tests {
     #[test]
     fn test_potential_infinite_loop() {
-        crate::tests::test_detector("potential_infinite_loop", 3);
+        crate::tests::test_detector("potential_infinite_loop", 4);
     }
 }
@@ -102,8 +102,8 @@ impl AstVisitor for StorageNotUpdatedVisitor {
     }
 
     fn leave_block(&mut self, context: &BlockContext, project: &mut Project) -> Result<(), Error> {
-        // Check for `#[storage(write)]` attribute
-        if !utils::check_attribute_decls(context.fn_attributes, "storage", &["write"]) {
+        // Check for `#[storage(write)]` attribute on the function or its ABI declaration
+        if !project.abi().fn_has_attribute(&context.item_fn.fn_signature, context.fn_attributes, "storage", &["write"]) {
             return Ok(());
         }
 
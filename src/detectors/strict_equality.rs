@@ -79,8 +79,17 @@ impl AstVisitor for StrictEqualityVisitor {
 
         let sway_ast::Expr::Equal { lhs, rhs, .. } = context.expr else { return Ok(()) };
 
-        if matches!(lhs.as_ref(), Expr::Literal(_)) || matches!(rhs.as_ref(), Expr::Literal(_)) {
-            project.report.borrow_mut().add_entry(context.path,
+        let value = match (lhs.as_ref(), rhs.as_ref()) {
+            (Expr::Literal(_), value) | (value, Expr::Literal(_)) => value,
+            _ => return Ok(()),
+        };
+
+        // Only check comparisons of integer balances
+        let value_type = project.type_of(value);
+
+        if value_type.is_unknown() || value_type.is_integer() {
+            project.report.borrow_mut().add_entry(
+                context.path,
                 project.span_to_line(context.path, &context.expr.span())?,
                 Severity::High,
                 format!(
@@ -2,6 +2,7 @@ use crate::{
     error::Error,
     project::Project,
     report::Severity,
+    storage::StorageAccessKind,
     utils,
     visitor::{
         AstVisitor, AstVisitorRecursive, BlockContext, ExprContext, FnContext, IfExprContext,
@@ -9,7 +10,7 @@ use crate::{
     },
 };
 use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
-use sway_ast::{Expr, IfCondition, ItemImplItem, ItemKind, Pattern};
+use sway_ast::{Expr, IfCondition, ItemImplItem, ItemKind, Pattern, Statement};
 use sway_types::{Span, Spanned};
 
 #[derive(Default)]
@@ -199,7 +200,7 @@ impl AstVisitor for UnprotectedStorageVariableVisitor {
         Ok(())
     }
 
-    fn visit_statement(&mut self, context: &StatementContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_statement(&mut self, context: &StatementContext, project: &mut Project) -> Result<(), Error> {
         // Get the module state
         let mut module_states = self.module_states.borrow_mut();
         let module_state = module_states.get_mut(context.path).unwrap();
@@ -209,8 +210,14 @@ impl AstVisitor for UnprotectedStorageVariableVisitor {
         let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
 
         // Get the storage variable name from the storage write statement
-        let Some(storage_ident) = utils::storage_write_statement_to_storage_variable_ident(context.statement) else { return Ok(()) };
-        let storage_variable = storage_ident.as_str().to_string();
+        let Statement::Expr { expr, .. } = context.statement else { return Ok(()) };
+        let Some(access) = project.storage().access_of(expr) else { return Ok(()) };
+
+        if access.kind != StorageAccessKind::Write {
+            return Ok(());
+        }
+
+        let storage_variable = access.field.clone();
 
         // Add the storage variable name to the function state's written variables
         if !fn_state.written_variables.contains(&storage_variable) {
@@ -4,17 +4,12 @@ use crate::{
     report::Severity,
     utils,
     visitor::{
-        AstVisitor, ConfigurableFieldContext, ConstContext, EnumContext, EnumFieldContext,
-        ExprContext, FnContext, ModuleContext, StatementLetContext, StorageFieldContext,
-        StructContext, StructFieldContext, TraitContext, TraitTypeContext, TypeAliasContext,
-        UseContext,
+        AstVisitor, ExprContext, GenericParamContext, ModuleContext, PatternContext,
+        TraitContext, TypeContext, UseContext,
     },
 };
 use std::{collections::HashMap, path::PathBuf};
-use sway_ast::{
-    ty::TyTupleDescriptor, Expr, FnArgs, PathExpr, PathExprSegment, PathType, Pattern, Traits, Ty,
-    UseTree,
-};
+use sway_ast::{Expr, PathExpr, PathType, Pattern, Traits, Ty, UseTree};
 use sway_types::{Span, Spanned};
 
 #[derive(Default)]
@@ -67,81 +62,29 @@ impl ModuleState {
         });
     }
 
-    fn check_pattern_usage(&mut self, pattern: &Pattern) {
-        utils::map_pattern(pattern, &mut |pattern| {
-            if let Pattern::Constructor { path, .. } | Pattern::Struct { path, .. } = pattern {
-                self.check_path_expr_usage(path);
-            }
-        });
-    }
-
     fn check_path_expr_usage(&mut self, path: &PathExpr) {
-        let mut check_segment_usage = |segment: &PathExprSegment| {
-            self.check_span_usage(&segment.name.span());
-
-            if let Some((_, generics)) = segment.generics_opt.as_ref() {
-                for ty in &generics.parameters.inner {
-                    self.check_ty_usage(ty);
-                }
-            }
-        };
-
-        check_segment_usage(&path.prefix);
+        self.check_span_usage(&path.prefix.name.span());
 
         for (_, suffix) in path.suffix.iter() {
-            check_segment_usage(suffix);
+            self.check_span_usage(&suffix.name.span());
         }
     }
 
     fn check_path_type_usage(&mut self, path: &PathType) {
         self.check_span_usage(&path.prefix.name.span());
-                
-        if let Some(generics) = path.prefix.generics_opt.as_ref() {
-            for ty in &generics.1.parameters.inner {
-                self.check_ty_usage(ty);
-            }
-        }
 
-        if let Some(root) = path.root_opt.as_ref() {
-            if let Some(root) = root.0.as_ref() {
-                self.check_ty_usage(root.inner.ty.as_ref());
-                
-                if let Some(as_trait) = root.inner.as_trait.as_ref() {
-                    self.check_path_type_usage(as_trait.1.as_ref());
-                }
+        if let Some((Some(root), _)) = path.root_opt.as_ref() {
+            if let Some(as_trait) = root.inner.as_trait.as_ref() {
+                self.check_path_type_usage(as_trait.1.as_ref());
             }
         }
     }
 
-    fn check_ty_usage(&mut self, ty: &Ty) {
-        match ty {
-            Ty::Path(path) => {
-                self.check_path_type_usage(path);
-            }
-
-            Ty::Tuple(tuple) => {
-                if let TyTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
-                    self.check_ty_usage(head.as_ref());
-                    
-                    for ty in tail {
-                        self.check_ty_usage(ty);
-                    }
-                }
-            }
+    fn check_traits_usage(&mut self, traits: &Traits) {
+        self.check_path_type_usage(&traits.prefix);
 
-            Ty::Array(array) => {
-                self.check_ty_usage(&array.inner.ty);
-                self.check_expr_usage(array.inner.length.as_ref());
-            }
-            
-            Ty::StringSlice(_) => {}
-            Ty::StringArray { .. } => {}
-            Ty::Infer { .. } => {}
-            
-            Ty::Ptr { ty, .. } |
-            Ty::Slice { ty, .. } => {
-                self.check_ty_usage(ty.inner.as_ref());
-            }
+        for (_, path_type) in traits.suffixes.iter() {
+            self.check_path_type_usage(path_type);
         }
     }
 }
@@ -183,95 +126,6 @@ impl AstVisitor for UnusedImportVisitor {
         Ok(())
     }
 
-    fn visit_struct(&mut self, context: &StructContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        if let Some(where_clause) = context.item_struct.where_clause_opt.as_ref() {
-            for bound in &where_clause.bounds {
-                module_state.check_path_type_usage(&bound.bounds.prefix);
-    
-                for (_, path_type) in bound.bounds.suffixes.iter() {
-                    module_state.check_path_type_usage(path_type);
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    fn visit_struct_field(&mut self, context: &StructFieldContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        module_state.check_ty_usage(&context.field.ty);
-
-        Ok(())
-    }
-
-    fn visit_enum(&mut self, context: &EnumContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        if let Some(where_clause) = context.item_enum.where_clause_opt.as_ref() {
-            for bound in &where_clause.bounds {
-                module_state.check_path_type_usage(&bound.bounds.prefix);
-    
-                for (_, path_type) in bound.bounds.suffixes.iter() {
-                    module_state.check_path_type_usage(path_type);
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    fn visit_enum_field(&mut self, context: &EnumFieldContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        module_state.check_ty_usage(&context.field.ty);
-
-        Ok(())
-    }
-
-    fn visit_fn(&mut self, context: &FnContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        if let Some(where_clause) = context.item_fn.fn_signature.where_clause_opt.as_ref() {
-            for bound in &where_clause.bounds {
-                module_state.check_path_type_usage(&bound.bounds.prefix);
-    
-                for (_, path_type) in bound.bounds.suffixes.iter() {
-                    module_state.check_path_type_usage(path_type);
-                }
-            }
-        }
-
-        let args = match &context.item_fn.fn_signature.arguments.inner {
-            FnArgs::Static(args) => args,
-            FnArgs::NonStatic { args_opt: Some(args), .. } => &args.1,
-            _ => return Ok(()),
-        };
-
-        for arg in args {
-            module_state.check_pattern_usage(&arg.pattern);
-            module_state.check_ty_usage(&arg.ty);
-        }
-
-        Ok(())
-    }
-
-    fn visit_statement_let(&mut self, context: &StatementLetContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        module_state.check_pattern_usage(&context.statement_let.pattern);
-
-        if let Some((_, ty)) = context.statement_let.ty_opt.as_ref() {
-            module_state.check_ty_usage(ty);
-        }
-
-        module_state.check_expr_usage(&context.statement_let.expr);
-
-        Ok(())
-    }
-
     fn visit_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
         let module_state = self.module_states.get_mut(context.path).unwrap();
         
@@ -279,7 +133,9 @@ impl AstVisitor for UnusedImportVisitor {
             module_state.check_path_type_usage(&args.inner.name);
         }
 
-        module_state.check_expr_usage(context.expr);
+        if let Expr::Path(path_expr) = context.expr {
+            module_state.check_path_expr_usage(path_expr);
+        }
 
         Ok(())
     }
@@ -287,72 +143,43 @@ impl AstVisitor for UnusedImportVisitor {
     fn visit_trait(&mut self, context: &TraitContext, _project: &mut Project) -> Result<(), Error> {
         let module_state = self.module_states.get_mut(context.path).unwrap();
 
-        let mut check_traits = |traits: &Traits| {
-            module_state.check_path_type_usage(&traits.prefix);
-
-            for (_, path_type) in traits.suffixes.iter() {
-                module_state.check_path_type_usage(path_type);
-            }
-        };
-
         if let Some((_, super_traits)) = context.item_trait.super_traits.as_ref() {
-            check_traits(super_traits);
-        }
-
-        if let Some(where_clause) = context.item_trait.where_clause_opt.as_ref() {
-            for bound in &where_clause.bounds {
-                check_traits(&bound.bounds);
-            }
+            module_state.check_traits_usage(super_traits);
         }
 
         Ok(())
     }
 
-    fn visit_const(&mut self, context: &ConstContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_generic_param(&mut self, context: &GenericParamContext, _project: &mut Project) -> Result<(), Error> {
         let module_state = self.module_states.get_mut(context.path).unwrap();
 
-        if let Some((_, ty)) = context.item_const.ty_opt.as_ref() {
-            module_state.check_ty_usage(ty);
-        }
-
-        if let Some(expr) = context.item_const.expr_opt.as_ref() {
-            module_state.check_expr_usage(expr);
+        for bound in context.where_bounds.iter() {
+            module_state.check_traits_usage(&bound.bounds);
         }
 
         Ok(())
     }
 
-    fn visit_storage_field(&mut self, context: &StorageFieldContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_pattern(&mut self, context: &PatternContext, _project: &mut Project) -> Result<(), Error> {
         let module_state = self.module_states.get_mut(context.path).unwrap();
 
-        module_state.check_ty_usage(&context.field.ty);
-        module_state.check_expr_usage(&context.field.initializer);
-        
+        if let Pattern::Constructor { path, .. } | Pattern::Struct { path, .. } = context.pattern {
+            module_state.check_path_expr_usage(path);
+        }
+
         Ok(())
     }
 
-    fn visit_configurable_field(&mut self, context: &ConfigurableFieldContext, _project: &mut Project) -> Result<(), Error> {
+    fn visit_type(&mut self, context: &TypeContext, _project: &mut Project) -> Result<(), Error> {
         let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        module_state.check_ty_usage(&context.field.ty);
-        module_state.check_expr_usage(&context.field.initializer);
 
-        Ok(())
-    }
+        match context.ty {
+            Ty::Path(path) => module_state.check_path_type_usage(path),
 
-    fn visit_type_alias(&mut self, context: &TypeAliasContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        module_state.check_ty_usage(&context.item_type_alias.ty);
-        
-        Ok(())
-    }
+            // Array lengths are not visited as expressions
+            Ty::Array(array) => module_state.check_expr_usage(array.inner.length.as_ref()),
 
-    fn visit_trait_type(&mut self, context: &TraitTypeContext, _project: &mut Project) -> Result<(), Error> {
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        if let Some(ty) = context.item_type.ty_opt.as_ref() {
-            module_state.check_ty_usage(ty);
+            _ => {}
         }
 
         Ok(())
@@ -0,0 +1,341 @@
+use crate::error::Error;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    process::Command,
+};
+use sway_ast::{ItemImplItem, ItemKind, Module};
+use sway_types::{Span, Spanned};
+
+/// The set of lines changed since a git base ref, used to restrict analysis to a diff.
+#[derive(Clone, Debug, Default)]
+pub struct DiffScope {
+    /// The changed line ranges of each changed file, keyed by canonical path. (1-based, inclusive)
+    changed_lines: HashMap<PathBuf, Vec<(usize, usize)>>,
+
+    /// The canonical paths of the Forc package directories containing changed files.
+    changed_packages: HashSet<PathBuf>,
+}
+
+impl DiffScope {
+    /// Computes the lines changed in the working tree relative to `base_ref` using the local git repository containing `directory`.
+    pub fn from_git<P: AsRef<Path>>(directory: P, base_ref: &str) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let root = PathBuf::from(run_git(directory, &["rev-parse", "--show-toplevel"])?.trim());
+
+        let mut scope = DiffScope::default();
+
+        // Collect the changed hunks of all tracked files
+        let diff = run_git(&root, &["diff", "--no-color", "--no-ext-diff", "--unified=0", base_ref, "--"])?;
+        let mut current_path: Option<PathBuf> = None;
+
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current_path = parse_diff_header_path(path).map(|path| root.join(path));
+                continue;
+            }
+
+            let Some(hunk) = line.strip_prefix("@@ ") else { continue };
+            let Some(path) = current_path.as_ref() else { continue };
+            let Some((start, count)) = parse_hunk_new_range(hunk) else { continue };
+
+            // Pure deletions are attributed to the line preceding them
+            let range = if count == 0 {
+                (start.max(1), start.max(1))
+            } else {
+                (start, start + count - 1)
+            };
+
+            scope.add_changed_lines(path, range);
+        }
+
+        // Treat untracked files as entirely changed
+        let untracked = run_git(&root, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+
+        for path in untracked.split('\0').filter(|path| !path.is_empty()) {
+            scope.add_changed_lines(&root.join(path), (1, usize::MAX));
+        }
+
+        Ok(scope)
+    }
+
+    fn add_changed_lines(&mut self, path: &Path, range: (usize, usize)) {
+        let Ok(path) = path.canonicalize() else { return };
+
+        if path.extension().map(|x| x == "sw").unwrap_or(false) {
+            if let Some(package) = find_package_root(&path) {
+                self.changed_packages.insert(package);
+            }
+        }
+
+        self.changed_lines.entry(path).or_default().push(range);
+    }
+
+    /// Checks if the supplied `path` belongs to a package containing changes, or is itself changed when not in a package.
+    pub fn is_package_affected<P: AsRef<Path>>(&self, path: P) -> bool {
+        let Ok(path) = path.as_ref().canonicalize() else { return false };
+
+        match find_package_root(&path) {
+            Some(package) => self.changed_packages.contains(&package),
+            None => self.changed_lines.contains_key(&path),
+        }
+    }
+
+    /// Checks if the supplied `path` contains any changes.
+    pub fn is_file_changed<P: AsRef<Path>>(&self, path: P) -> bool {
+        let Ok(path) = path.as_ref().canonicalize() else { return false };
+        self.changed_lines.contains_key(&path)
+    }
+
+    /// Checks if any line in the inclusive `range` of the supplied `path` has been changed.
+    pub fn intersects<P: AsRef<Path>>(&self, path: P, range: (usize, usize)) -> bool {
+        let Ok(path) = path.as_ref().canonicalize() else { return false };
+        let Some(changed_lines) = self.changed_lines.get(&path) else { return false };
+
+        changed_lines.iter().any(|&(start, end)| start <= range.1 && range.0 <= end)
+    }
+}
+
+/// Attempts to find the directory of the Forc package containing the supplied `path`.
+pub fn find_package_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.join("Forc.toml").is_file())
+        .map(PathBuf::from)
+}
+
+/// Attempts to read the name of the Forc package in the supplied `package_root` directory from its `Forc.toml` file.
+pub fn read_package_name(package_root: &Path) -> Option<String> {
+    let source = std::fs::read_to_string(package_root.join("Forc.toml")).ok()?;
+    let manifest = source.parse::<toml::Table>().ok()?;
+
+    manifest.get("project")?.get("name")?.as_str().map(String::from)
+}
+
+/// Gets the spans of the functions declared at the top level of the supplied `module`, including impl methods and `abi` and `trait` default methods.
+pub fn module_fn_spans(module: &Module) -> Vec<Span> {
+    let mut fn_spans = vec![];
+
+    for item in module.items.iter() {
+        match &item.value {
+            ItemKind::Fn(item_fn) => fn_spans.push(item_fn.span()),
+
+            ItemKind::Impl(item_impl) => {
+                for item in item_impl.contents.inner.iter() {
+                    if let ItemImplItem::Fn(item_fn) = &item.value {
+                        fn_spans.push(item_fn.span());
+                    }
+                }
+            }
+
+            ItemKind::Abi(item_abi) => {
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    fn_spans.push(item.value.span());
+                }
+            }
+
+            ItemKind::Trait(item_trait) => {
+                for item in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    fn_spans.push(item.value.span());
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn_spans
+}
+
+/// Parses the path of the new file from a `+++ b/<path>` diff header, unquoting it if git quoted it.
+fn parse_diff_header_path(header: &str) -> Option<String> {
+    // Git appends a tab to unquoted paths containing spaces
+    let header = header.strip_suffix('\t').unwrap_or(header);
+
+    let path = match header.strip_prefix('"') {
+        Some(quoted) => unquote_c_style(quoted.strip_suffix('"')?)?,
+        None => header.to_string(),
+    };
+
+    path.strip_prefix("b/").map(String::from)
+}
+
+/// Unquotes the contents of a C-style quoted string, as used by git for paths containing special or non-ASCII characters.
+fn unquote_c_style(text: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut input = text.bytes().peekable();
+
+    while let Some(byte) = input.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+
+        let escaped = match input.next()? {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b't' => b'\t',
+            b'n' => b'\n',
+            b'v' => 0x0b,
+            b'f' => 0x0c,
+            b'r' => b'\r',
+
+            // Non-ASCII bytes are escaped as three octal digits
+            digit @ b'0'..=b'7' => {
+                let mut value = (digit - b'0') as u32;
+
+                for _ in 0..2 {
+                    let digit = input.next_if(|x| (b'0'..=b'7').contains(x))?;
+                    value = value * 8 + (digit - b'0') as u32;
+                }
+
+                u8::try_from(value).ok()?
+            }
+
+            other => other,
+        };
+
+        bytes.push(escaped);
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Parses the new file range (`+start,count`) from the header of a unified diff hunk.
+fn parse_hunk_new_range(hunk: &str) -> Option<(usize, usize)> {
+    let range = hunk.split_whitespace().find_map(|x| x.strip_prefix('+'))?;
+
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+fn run_git(directory: &Path, args: &[&str]) -> Result<String, Error> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(directory)
+        .args(args)
+        .output()
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    if !output.status.success() {
+        return Err(Error::GitFailed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hunk_new_range() {
+        assert_eq!(parse_hunk_new_range("-10,2 +12,3 @@ fn foo() {"), Some((12, 3)));
+        assert_eq!(parse_hunk_new_range("-10 +12 @@"), Some((12, 1)));
+        assert_eq!(parse_hunk_new_range("-10,2 +9,0 @@"), Some((9, 0)));
+    }
+
+    #[test]
+    fn test_parse_diff_header_path() {
+        assert_eq!(parse_diff_header_path("b/src/main.sw"), Some("src/main.sw".into()));
+        assert_eq!(parse_diff_header_path("b/my package/src/main.sw\t"), Some("my package/src/main.sw".into()));
+        assert_eq!(parse_diff_header_path("\"b/caf\\303\\251/src/main.sw\""), Some("café/src/main.sw".into()));
+        assert_eq!(parse_diff_header_path("\"b/a\\\"b\\\\c.sw\""), Some("a\"b\\c.sw".into()));
+        assert_eq!(parse_diff_header_path("/dev/null"), None);
+    }
+
+    #[test]
+    fn test_module_fn_spans() {
+        let source = r#"contract;
+
+abi Counter {
+    fn count() -> u64;
+} {
+    fn default_count() -> u64 { 0 }
+}
+
+trait Named {
+    fn name() -> u64;
+} {
+    fn default_name() -> u64 { 1 }
+}
+
+fn free() {}
+
+impl Counter for Contract {
+    fn count() -> u64 { 2 }
+}
+"#;
+
+        let handler = sway_error::handler::Handler::default();
+        let module = sway_parse::parse_file(&handler, std::sync::Arc::from(source), None).unwrap();
+
+        let fn_names = module_fn_spans(&module.value).iter()
+            .map(|span| span.as_str().split(['(', ' ']).nth(1).unwrap().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(fn_names, vec!["default_count", "default_name", "free", "count"]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let directory = std::env::temp_dir().join(format!("sway-analyzer-diff-{}", std::process::id()));
+        let package = directory.join("boolean comparison é");
+        let source_path = package.join("src/main.sw");
+
+        std::fs::create_dir_all(package.join("src")).unwrap();
+        std::fs::copy("test/boolean_comparison/Forc.toml", package.join("Forc.toml")).unwrap();
+        std::fs::copy("test/boolean_comparison/src/main.sw", &source_path).unwrap();
+
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&directory)
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "initial"]);
+
+        // Introduce a new boolean comparison on line 12 and an unrelated change inside `test_boolean_comparison_3`
+        let source = std::fs::read_to_string(&source_path).unwrap()
+            .replace("pub const BOOL1: bool = true;", "pub const BOOL1: bool = true == false;")
+            .replace("fn test_boolean_comparison_3() -> bool {\n", "fn test_boolean_comparison_3() -> bool {\n        let _x = 5;\n");
+        std::fs::write(&source_path, source).unwrap();
+
+        let scope = DiffScope::from_git(&directory, "HEAD").unwrap();
+
+        assert!(scope.is_package_affected(&source_path));
+        assert!(scope.intersects(&source_path, (12, 12)));
+        assert!(scope.intersects(&source_path, (63, 63)));
+        assert!(!scope.intersects(&source_path, (16, 16)));
+
+        for (diff_functions, expected_lines) in [(false, vec![12]), (true, vec![12, 64])] {
+            let options = crate::Options {
+                directory: Some(format!("{}/", directory.to_string_lossy()).into()),
+                detectors: vec!["boolean_comparison".to_string()],
+                diff: Some("HEAD".to_string()),
+                diff_functions,
+                ..Default::default()
+            };
+
+            let mut project = crate::project::Project::try_from(&options).unwrap();
+            project.analyze_modules().unwrap();
+
+            let report = project.report.borrow();
+            let lines = report.entries.iter().flat_map(|(_, entries)| entries.iter().filter_map(|x| x.line)).collect::<Vec<_>>();
+            assert_eq!(lines, expected_lines);
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
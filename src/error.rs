@@ -5,9 +5,17 @@ pub enum Error {
     Wrapped(Box<dyn std::error::Error>),
     InvalidDisplayFormat(String),
     InvalidSorting(String),
+    InvalidSeverity(String),
+    InvalidRule(PathBuf, String),
+    InvalidQuery(String),
+    InvalidPlugin(PathBuf, String),
+    InvalidPrinter(String),
+    UnsupportedDisplayFormat(String),
     ParseFailed(PathBuf),
     FileNotFound(PathBuf),
+    PackageNotFound(String),
     LineNotFound(PathBuf, usize),
+    GitFailed(String),
 }
 
 impl std::fmt::Display for Error {
@@ -16,9 +24,17 @@ impl std::fmt::Display for Error {
             Error::Wrapped(e) => write!(f, "{e}"),
             Error::InvalidDisplayFormat(format) => write!(f, "Invalid display format: {format}"),
             Error::InvalidSorting(sorting) => write!(f, "Invalid sorting: {sorting}"),
+            Error::InvalidSeverity(severity) => write!(f, "Invalid severity: {severity}"),
+            Error::InvalidRule(path, message) => write!(f, "Invalid rule in file \"{}\": {message}", path.to_string_lossy()),
+            Error::InvalidQuery(query) => write!(f, "Invalid query: {query}"),
+            Error::InvalidPlugin(path, message) => write!(f, "Invalid plugin \"{}\": {message}", path.to_string_lossy()),
+            Error::InvalidPrinter(printer) => write!(f, "Invalid printer: {printer}"),
+            Error::UnsupportedDisplayFormat(printer) => write!(f, "The \"{printer}\" printer only supports the Text, Json and Markdown display formats"),
             Error::ParseFailed(path) => write!(f, "Failed to parse file: \"{}\"", path.to_string_lossy()),
             Error::FileNotFound(path) => write!(f, "File not found: \"{}\"", path.to_string_lossy()),
+            Error::PackageNotFound(package) => write!(f, "Package not found: \"{package}\""),
             Error::LineNotFound(path, offset) => write!(f, "Offset {offset} not found in file: \"{}\"", path.to_string_lossy()),
+            Error::GitFailed(message) => write!(f, "Git command failed: {message}"),
         }
     }
 }
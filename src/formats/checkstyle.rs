@@ -0,0 +1,64 @@
+use super::escape_xml;
+use crate::{project::Project, report::Severity};
+use std::fmt::Write;
+
+/// Writes the report of the supplied `project` as a Checkstyle XML document.
+pub fn write_checkstyle<W: Write>(f: &mut W, project: &Project) -> std::fmt::Result {
+    let report = project.report.borrow();
+
+    writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(f, r#"<checkstyle version="4.3">"#)?;
+
+    for (path, entries) in report.entries.iter() {
+        writeln!(f, r#"  <file name="{}">"#, escape_xml(&path.to_string_lossy()))?;
+
+        for entry in entries.iter() {
+            writeln!(
+                f,
+                r#"    <error line="{}" severity="{}" message="{}" source="sway-analyzer.{}"/>"#,
+                entry.line.unwrap_or(0),
+                checkstyle_severity(entry.severity),
+                escape_xml(&entry.text),
+                escape_xml(&entry.detector),
+            )?;
+        }
+
+        writeln!(f, "  </file>")?;
+    }
+
+    writeln!(f, "</checkstyle>")?;
+
+    Ok(())
+}
+
+/// Gets the name of the Checkstyle severity level corresponding to the supplied `severity`.
+fn checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::DisplayFormat;
+
+    #[test]
+    fn test_checkstyle() {
+        crate::formats::tests::test_golden("arbitrary_code_execution", DisplayFormat::Checkstyle, "arbitrary_code_execution.checkstyle.xml");
+    }
+
+    #[test]
+    fn test_checkstyle_escapes_detector_names() {
+        let project = crate::project::Project::default();
+        project.report.borrow_mut().detector = Some("rule \"<name>\"".into());
+        project.report.borrow_mut().add_entry("main.sw", Some(1), crate::report::Severity::Low, "Message");
+
+        let mut output = String::new();
+        super::write_checkstyle(&mut output, &project).unwrap();
+
+        assert!(output.contains("rule &quot;&lt;name&gt;&quot;"));
+        assert!(!output.contains("rule \"<name>\""));
+    }
+}
@@ -0,0 +1,72 @@
+use super::repository_relative_path;
+use crate::{project::Project, report::Severity};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLines {
+    begin: usize,
+}
+
+/// Writes the report of the supplied `project` as a GitLab Code Quality JSON document.
+pub fn write_gitlab<W: Write>(f: &mut W, project: &Project) -> std::fmt::Result {
+    let report = project.report.borrow();
+
+    let mut issues = vec![];
+
+    for (path, entries) in report.entries.iter() {
+        let path = repository_relative_path(path);
+
+        for entry in entries.iter() {
+            issues.push(CodeQualityIssue {
+                description: entry.text.clone(),
+                check_name: entry.detector.clone(),
+                fingerprint: entry.fingerprint.clone(),
+                severity: gitlab_severity(entry.severity),
+                location: CodeQualityLocation {
+                    path: path.clone(),
+                    lines: CodeQualityLines {
+                        begin: entry.line.unwrap_or(1),
+                    },
+                },
+            });
+        }
+    }
+
+    let value = serde_json::to_string_pretty(&issues).map_err(|_| std::fmt::Error)?;
+    writeln!(f, "{value}")
+}
+
+/// Gets the name of the GitLab Code Quality severity level corresponding to the supplied `severity`.
+fn gitlab_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "critical",
+        Severity::Medium => "major",
+        Severity::Low => "minor",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::DisplayFormat;
+
+    #[test]
+    fn test_gitlab() {
+        crate::formats::tests::test_golden("arbitrary_code_execution", DisplayFormat::GitLab, "arbitrary_code_execution.gitlab.json");
+    }
+}
@@ -0,0 +1,74 @@
+use super::escape_xml;
+use crate::{project::Project, report::Entry};
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+/// Writes the report of the supplied `project` as a JUnit XML document, with a test suite for each detector.
+pub fn write_junit<W: Write>(f: &mut W, project: &Project) -> std::fmt::Result {
+    let report = project.report.borrow();
+
+    // Group all entries by the detector which created them
+    let mut detector_entries: BTreeMap<&str, Vec<(&PathBuf, &Entry)>> = BTreeMap::new();
+
+    for (path, entries) in report.entries.iter() {
+        for entry in entries.iter() {
+            detector_entries.entry(entry.detector.as_str()).or_default().push((path, entry));
+        }
+    }
+
+    let entry_count: usize = detector_entries.values().map(Vec::len).sum();
+
+    writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(f, r#"<testsuites name="sway-analyzer" tests="{entry_count}" failures="{entry_count}">"#)?;
+
+    for (detector, entries) in detector_entries.iter() {
+        let detector = escape_xml(detector);
+
+        writeln!(f, r#"  <testsuite name="{detector}" tests="{0}" failures="{0}">"#, entries.len())?;
+
+        for (path, entry) in entries.iter() {
+            let location = match entry.line {
+                Some(line) => format!("{}:{line}", path.to_string_lossy()),
+                None => path.to_string_lossy().to_string(),
+            };
+
+            writeln!(f, r#"    <testcase name="{}" classname="{detector}">"#, escape_xml(&location))?;
+            writeln!(
+                f,
+                r#"      <failure message="{0}" type="{1}">{2}: {0}</failure>"#,
+                escape_xml(&entry.text),
+                entry.severity,
+                escape_xml(&location),
+            )?;
+            writeln!(f, "    </testcase>")?;
+        }
+
+        writeln!(f, "  </testsuite>")?;
+    }
+
+    writeln!(f, "</testsuites>")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::DisplayFormat;
+
+    #[test]
+    fn test_junit() {
+        crate::formats::tests::test_golden("arbitrary_code_execution", DisplayFormat::JUnit, "arbitrary_code_execution.junit.xml");
+    }
+
+    #[test]
+    fn test_junit_escapes_detector_names() {
+        let project = crate::project::Project::default();
+        project.report.borrow_mut().detector = Some("rule \"<name>\"".into());
+        project.report.borrow_mut().add_entry("main.sw", Some(1), crate::report::Severity::Low, "Message");
+
+        let mut output = String::new();
+        super::write_junit(&mut output, &project).unwrap();
+
+        assert!(output.contains("rule &quot;&lt;name&gt;&quot;"));
+        assert!(!output.contains("rule \"<name>\""));
+    }
+}
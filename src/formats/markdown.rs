@@ -0,0 +1,178 @@
+use super::repository_relative_path;
+use crate::{
+    project::Project,
+    report::{Entry, Severity},
+};
+use std::{collections::{BTreeMap, HashMap}, fmt::Write, path::PathBuf};
+
+/// Writes the report of the supplied `project` as a Markdown document suitable for pull request comments.
+pub fn write_markdown<W: Write>(f: &mut W, project: &Project) -> std::fmt::Result {
+    let report = project.report.borrow();
+
+    writeln!(f, "## sway-analyzer report")?;
+    writeln!(f)?;
+
+    // Group all entries by the detector which created them
+    let mut detector_entries: BTreeMap<&str, Vec<(&PathBuf, &Entry)>> = BTreeMap::new();
+
+    for (path, entries) in report.entries.iter() {
+        for entry in entries.iter() {
+            detector_entries.entry(entry.detector.as_str()).or_default().push((path, entry));
+        }
+    }
+
+    if detector_entries.is_empty() {
+        writeln!(f, "No findings.")?;
+        return Ok(());
+    }
+
+    // Write the severity summary table
+    writeln!(f, "| Severity | Findings |")?;
+    writeln!(f, "|-|-|")?;
+
+    for severity in [Severity::High, Severity::Medium, Severity::Low] {
+        let count = detector_entries.values()
+            .flatten()
+            .filter(|(_, entry)| entry.severity == severity)
+            .count();
+
+        writeln!(f, "| {} {severity} | {count} |", severity.emoji())?;
+    }
+
+    // Resolve the repository-relative path of each file once, since it requires filesystem access
+    let relative_paths: HashMap<&PathBuf, String> = report.entries.iter()
+        .map(|(path, _)| (path, repository_relative_path(path)))
+        .collect();
+
+    // Write the findings of each detector
+    for (detector, entries) in detector_entries.iter() {
+        writeln!(f)?;
+        writeln!(f, "### {} ({})", code_span(detector), entries.len())?;
+        writeln!(f)?;
+
+        for (path, entry) in entries.iter() {
+            let relative_path = &relative_paths[path];
+
+            let location = match entry.line {
+                Some(line) => format!("{relative_path}:{line}"),
+                None => relative_path.clone(),
+            };
+
+            // Only link to the source when its absolute URL is known, since relative links do not resolve in pull request comments
+            let location = match (project.source_url(), entry.line) {
+                (Some(source_url), Some(line)) => format!("[{}]({}/{relative_path}#L{line})", code_span(&location), source_url.trim_end_matches('/')),
+                (Some(source_url), None) => format!("[{}]({}/{relative_path})", code_span(&location), source_url.trim_end_matches('/')),
+                (None, _) => code_span(&location),
+            };
+
+            writeln!(f, "- {} {location}: {}", entry.severity.emoji(), escape_markdown(&entry.text))?;
+
+            let Some(source_line) = entry.line.and_then(|line| project.get_source_line(path, line)) else { continue };
+
+            // Make the fence longer than any run of backticks in the source line so it cannot be closed early
+            let fence = "`".repeat(longest_backtick_run(source_line).max(2) + 1);
+
+            writeln!(f)?;
+            writeln!(f, "  {fence}sway")?;
+            writeln!(f, "  {}", source_line.trim())?;
+            writeln!(f, "  {fence}")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the length of the longest run of consecutive backticks in the supplied `text`.
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Wraps the supplied `text` in a code span delimited by more backticks than it contains consecutively.
+fn code_span(text: &str) -> String {
+    let delimiter = "`".repeat(longest_backtick_run(text) + 1);
+
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{delimiter} {text} {delimiter}")
+    } else {
+        format!("{delimiter}{text}{delimiter}")
+    }
+}
+
+/// Escapes the supplied entry `text` for use in Markdown, re-delimiting its `` `code` `` spans and escaping the characters between them.
+fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let segments = text.split('`').collect::<Vec<_>>();
+
+    for (i, segment) in segments.iter().enumerate() {
+        // Odd segments are code spans, unless the last backtick is unmatched
+        if i % 2 == 1 && i + 1 < segments.len() {
+            result.push_str(&code_span(segment));
+            continue;
+        }
+
+        if i % 2 == 1 {
+            result.push_str("\\`");
+        }
+
+        for c in segment.chars() {
+            if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#') {
+                result.push('\\');
+            }
+
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{project::{DisplayFormat, Project}, Options};
+
+    #[test]
+    fn test_markdown() {
+        let options = Options {
+            display_format: Some(DisplayFormat::Markdown),
+            directory: Some("test/boolean_comparison/".into()),
+            detectors: vec!["boolean_comparison".to_string()],
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        let output = project.to_string();
+
+        assert!(output.contains("| 🟢 Low | 10 |"));
+        assert!(output.contains("### `boolean_comparison` (10)"));
+        assert!(output.contains("- 🟢 `test/boolean_comparison/src/main.sw:16`: The "));
+        assert!(output.contains("  ```sway\n  pub const BOOL2: bool = true != false; //~ boolean_comparison:"));
+    }
+
+    #[test]
+    fn test_markdown_links() {
+        let options = Options {
+            display_format: Some(DisplayFormat::Markdown),
+            directory: Some("test/boolean_comparison/".into()),
+            detectors: vec!["boolean_comparison".to_string()],
+            source_url: Some("https://github.com/owner/repo/blob/main/".into()),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        let output = project.to_string();
+
+        assert!(output.contains("[`test/boolean_comparison/src/main.sw:16`](https://github.com/owner/repo/blob/main/test/boolean_comparison/src/main.sw#L16)"));
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(super::escape_markdown("The `a || b` value | 2 * x_y"), "The `a || b` value \\| 2 \\* x\\_y");
+        assert_eq!(super::escape_markdown("Found `x` and an unmatched ` tick"), "Found `x` and an unmatched \\` tick");
+        assert_eq!(super::code_span("a`b"), "``a`b``");
+        assert_eq!(super::longest_backtick_run("let x = \"```\";"), 3);
+    }
+}
@@ -0,0 +1,121 @@
+mod checkstyle;
+mod gitlab;
+mod junit;
+mod markdown;
+
+pub use self::{checkstyle::*, gitlab::*, junit::*, markdown::*};
+
+use std::path::{Path, PathBuf};
+
+/// Attempts to find the root directory of the git repository containing the supplied `path`.
+pub fn find_repository_root<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    let path = path.as_ref().canonicalize().ok()?;
+
+    path.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(PathBuf::from)
+}
+
+/// Gets the supplied `path` relative to the root of its git repository, or the unmodified `path` if it is not in one.
+pub fn repository_relative_path<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+
+    let relative_path = find_repository_root(path).and_then(|root| {
+        let path = path.canonicalize().ok()?;
+        path.strip_prefix(root).ok().map(PathBuf::from)
+    });
+
+    relative_path
+        .unwrap_or_else(|| path.into())
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Gets the base URL of the source files at the commit being checked when running in GitHub Actions.
+pub fn github_source_url() -> Option<String> {
+    let server_url = std::env::var("GITHUB_SERVER_URL").ok()?;
+    let repository = std::env::var("GITHUB_REPOSITORY").ok()?;
+    let sha = std::env::var("GITHUB_SHA").ok()?;
+
+    Some(format!("{server_url}/{repository}/blob/{sha}"))
+}
+
+/// Escapes the supplied `text` for use in XML attribute values and text content.
+pub fn escape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\n' => result.push_str("&#10;"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{project::{DisplayFormat, Project}, Options};
+
+    pub fn test_golden(name: &str, display_format: DisplayFormat, golden_file_name: &str) {
+        let options = Options {
+            display_format: Some(display_format),
+            directory: Some(format!("test/{name}/").into()),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        assert_golden(&project.to_string(), golden_file_name);
+    }
+
+    /// Removes the ANSI escape sequences used for terminal colors from the supplied `text`.
+    fn strip_ansi_codes(text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut chars = text.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                result.push(c);
+                continue;
+            }
+
+            // Skip the control sequence up to and including its final byte
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Compares the `actual` output with the contents of `test/golden/<golden_file_name>`, ignoring terminal colors.
+    ///
+    /// Set the `SWAY_ANALYZER_BLESS` environment variable to overwrite the golden file with the actual output instead.
+    pub fn assert_golden(actual: &str, golden_file_name: &str) {
+        let actual = strip_ansi_codes(actual);
+        let golden_path = format!("test/golden/{golden_file_name}");
+
+        if std::env::var_os("SWAY_ANALYZER_BLESS").is_some() {
+            std::fs::write(&golden_path, &actual).unwrap();
+            return;
+        }
+
+        let expected = std::fs::read_to_string(&golden_path).unwrap_or_default();
+
+        if expected != actual {
+            panic!("Output does not match golden file \"{golden_path}\" (set SWAY_ANALYZER_BLESS=1 to update it):\n{actual}");
+        }
+    }
+}
@@ -0,0 +1,988 @@
+use crate::{
+    constants::{max_value, ConstEvaluator},
+    types::{Type, TypeInference},
+    utils::{self, expr_key, ExprKey},
+};
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use sway_ast::{
+    expr::{LoopControlFlow, ReassignmentOpVariant}, Assignable, CodeBlockContents, Expr,
+    ExprArrayDescriptor, ExprTupleDescriptor, FnArgs, IfCondition, IfExpr, ItemFn, ItemImplItem,
+    ItemKind, MatchBranchKind, Module, Pattern, Statement,
+};
+use sway_types::Spanned;
+
+/// An inclusive range of values an unsigned integer expression can hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub min: BigUint,
+    pub max: BigUint,
+}
+
+impl Interval {
+    pub fn new(min: BigUint, max: BigUint) -> Self {
+        Self { min, max }
+    }
+
+    pub fn constant(value: BigUint) -> Self {
+        Self::new(value.clone(), value)
+    }
+
+    /// Gets the full range of an unsigned integer type with the supplied `bits`.
+    pub fn full(bits: u16) -> Self {
+        Self::new(BigUint::zero(), max_value(bits))
+    }
+
+    pub fn contains(&self, value: &BigUint) -> bool {
+        self.min <= *value && *value <= self.max
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.min.is_zero()
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Gets the smallest interval containing both intervals.
+    fn hull(&self, other: &Interval) -> Interval {
+        Interval::new(self.min.clone().min(other.min.clone()), self.max.clone().max(other.max.clone()))
+    }
+
+    /// Gets the values contained in both intervals, or `None` if there are none.
+    fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let result = Interval::new(self.min.clone().max(other.min.clone()), self.max.clone().min(other.max.clone()));
+        (result.min <= result.max).then_some(result)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Modulo,
+    Pow,
+    Shl,
+    Shr,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl BinaryOp {
+    fn from_expr(expr: &Expr) -> Option<Self> {
+        match expr {
+            Expr::Add { .. } => Some(BinaryOp::Add),
+            Expr::Sub { .. } => Some(BinaryOp::Sub),
+            Expr::Mul { .. } => Some(BinaryOp::Mul),
+            Expr::Div { .. } => Some(BinaryOp::Div),
+            Expr::Modulo { .. } => Some(BinaryOp::Modulo),
+            Expr::Pow { .. } => Some(BinaryOp::Pow),
+            Expr::Shl { .. } => Some(BinaryOp::Shl),
+            Expr::Shr { .. } => Some(BinaryOp::Shr),
+            Expr::BitAnd { .. } => Some(BinaryOp::BitAnd),
+            Expr::BitOr { .. } => Some(BinaryOp::BitOr),
+            Expr::BitXor { .. } => Some(BinaryOp::BitXor),
+            _ => None,
+        }
+    }
+
+    fn from_reassignment(variant: &ReassignmentOpVariant) -> Option<Self> {
+        match variant {
+            ReassignmentOpVariant::Equals => None,
+            ReassignmentOpVariant::AddEquals => Some(BinaryOp::Add),
+            ReassignmentOpVariant::SubEquals => Some(BinaryOp::Sub),
+            ReassignmentOpVariant::MulEquals => Some(BinaryOp::Mul),
+            ReassignmentOpVariant::DivEquals => Some(BinaryOp::Div),
+            ReassignmentOpVariant::ShlEquals => Some(BinaryOp::Shl),
+            ReassignmentOpVariant::ShrEquals => Some(BinaryOp::Shr),
+        }
+    }
+
+    /// Computes the range of the operation over the operand ranges `a` and `b`, or `Err` if it always reverts.
+    fn apply(self, a: &Interval, b: &Interval, bits: u16) -> Result<Interval, ()> {
+        let max = max_value(bits);
+
+        let (min, high) = match self {
+            BinaryOp::Add => (&a.min + &b.min, &a.max + &b.max),
+
+            BinaryOp::Sub => {
+                if a.max < b.min {
+                    return Err(());
+                }
+
+                let min = if a.min > b.max { &a.min - &b.max } else { BigUint::zero() };
+                (min, &a.max - &b.min)
+            }
+
+            BinaryOp::Mul => (&a.min * &b.min, &a.max * &b.max),
+
+            BinaryOp::Div | BinaryOp::Modulo if b.max.is_zero() => return Err(()),
+
+            BinaryOp::Div => {
+                let divisor = b.min.clone().max(BigUint::from(1u8));
+                (&a.min / &b.max, &a.max / divisor)
+            }
+
+            BinaryOp::Modulo => (BigUint::zero(), a.max.clone().min(&b.max - 1u8)),
+
+            BinaryOp::Pow => {
+                let pow = |base: &BigUint, exponent: &BigUint| match exponent.to_u32().filter(|x| *x <= 256) {
+                    Some(exponent) => base.pow(exponent),
+                    None if *base <= BigUint::from(1u8) => base.clone(),
+                    None => &max + 1u8,
+                };
+
+                // Zero raised to any positive exponent is zero, even though `0 ** 0` is one
+                let min = if a.min.is_zero() && !b.max.is_zero() { BigUint::zero() } else { pow(&a.min, &b.min) };
+
+                (min, pow(&a.max, &b.max))
+            }
+
+            // Any set bit can be shifted into any position of the result
+            BinaryOp::Shl => return Ok(Interval::full(bits)),
+
+            BinaryOp::Shr => {
+                let shift = |x: &BigUint| x.to_usize().unwrap_or(usize::MAX).min(256);
+                (&a.min >> shift(&b.max), &a.max >> shift(&b.min))
+            }
+
+            BinaryOp::BitAnd => (BigUint::zero(), a.max.clone().min(b.max.clone())),
+
+            BinaryOp::BitOr | BinaryOp::BitXor => {
+                let high = a.max.clone().max(b.max.clone());
+                let mask = max_value(high.bits() as u16);
+
+                match self {
+                    BinaryOp::BitOr => (a.min.clone().max(b.min.clone()), mask),
+                    _ => (BigUint::zero(), mask),
+                }
+            }
+        };
+
+        if min > max {
+            return Err(());
+        }
+
+        Ok(Interval::new(min, high.min(max)))
+    }
+}
+
+/// A local variable binding with its known range, integer width and mutability.
+#[derive(Clone, Default, PartialEq)]
+struct Binding {
+    interval: Option<Interval>,
+    bits: Option<u16>,
+    mutable: bool,
+}
+
+impl Binding {
+    fn interval(&self) -> Option<Interval> {
+        self.interval.clone().or_else(|| self.bits.map(Interval::full))
+    }
+}
+
+type Scopes = Vec<HashMap<String, Binding>>;
+
+/// Joins the bindings of two control flow paths, keeping the range covering both.
+fn join_scopes(lhs: &mut Scopes, rhs: &Scopes) {
+    for (lhs_scope, rhs_scope) in lhs.iter_mut().zip(rhs.iter()) {
+        for (name, binding) in lhs_scope.iter_mut() {
+            binding.interval = match (binding.interval.as_ref(), rhs_scope.get(name).and_then(|x| x.interval.as_ref())) {
+                (Some(a), Some(b)) => Some(a.hull(b)),
+                _ => None,
+            };
+        }
+    }
+}
+
+fn find_binding<'s>(scopes: &'s mut Scopes, name: &str) -> Option<&'s mut Binding> {
+    scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name))
+}
+
+/// Gets the name of the supplied `expr` if it is a plain local variable path.
+fn variable_name(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() && path.prefix.generics_opt.is_none() => Some(path.prefix.name.as_str()),
+        Expr::Parens(expr) => variable_name(&expr.inner),
+        _ => None,
+    }
+}
+
+/// Checks if the supplied `block` always ends in a `return` or `revert`.
+fn block_diverges(block: &CodeBlockContents) -> bool {
+    let is_diverging = |expr: &Expr| match expr {
+        Expr::Return { .. } => true,
+        Expr::FuncApp { func, .. } => func.span().as_str() == "revert",
+        _ => false,
+    };
+
+    block.statements.iter().any(|statement| matches!(statement, Statement::Expr { expr, .. } if is_diverging(expr)))
+        || block.final_expr_opt.as_ref().map(|expr| is_diverging(expr)).unwrap_or(false)
+}
+
+/// An integer range analysis over a set of modules.
+///
+/// Ranges are derived from literals, constants, type bounds, `require` guards, `if` and `while` conditions and assignments to local variables.
+#[derive(Default)]
+pub struct IntervalAnalysis {
+    expr_intervals: HashMap<ExprKey, Interval>,
+    always_reverts: HashSet<ExprKey>,
+    comparison_results: HashMap<ExprKey, bool>,
+}
+
+impl IntervalAnalysis {
+    /// Analyzes every function in the supplied `modules`.
+    pub fn new<'a, I: IntoIterator<Item = &'a Module>>(modules: I, types: &TypeInference, constants: &ConstEvaluator) -> Self {
+        let mut analyzer = Analyzer {
+            types,
+            constants,
+            recording: true,
+            loop_exits: vec![],
+            result: Self::default(),
+        };
+
+        for module in modules {
+            for item in module.items.iter() {
+                analyzer.analyze_item(&item.value);
+            }
+        }
+
+        analyzer.result
+    }
+
+    /// Gets the range of values the supplied `expr` can hold if it is an integer.
+    pub fn interval_of(&self, expr: &Expr) -> Option<Interval> {
+        self.expr_intervals.get(&expr_key(expr)).cloned()
+    }
+
+    /// Checks if the supplied arithmetic `expr` always reverts from an overflow, underflow or division by zero.
+    pub fn always_reverts(&self, expr: &Expr) -> bool {
+        self.always_reverts.contains(&expr_key(expr))
+    }
+
+    /// Gets the result of the supplied comparison `expr` if the ranges of its operands always decide it.
+    pub fn comparison_result(&self, expr: &Expr) -> Option<bool> {
+        self.comparison_results.get(&expr_key(expr)).copied()
+    }
+}
+
+struct Analyzer<'a> {
+    types: &'a TypeInference,
+    constants: &'a ConstEvaluator,
+
+    /// Whether results are recorded, which is disabled while discovering the variables a loop modifies.
+    recording: bool,
+
+    /// The joined bindings at each `break` of the loops being analyzed, innermost last.
+    loop_exits: Vec<Option<Scopes>>,
+
+    result: IntervalAnalysis,
+}
+
+impl Analyzer<'_> {
+    fn analyze_item(&mut self, item: &ItemKind) {
+        match item {
+            ItemKind::Fn(item_fn) => self.analyze_fn(item_fn),
+
+            ItemKind::Impl(item_impl) => {
+                for item in item_impl.contents.inner.iter() {
+                    if let ItemImplItem::Fn(item_fn) = &item.value {
+                        self.analyze_fn(item_fn);
+                    }
+                }
+            }
+
+            ItemKind::Abi(item_abi) => {
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.analyze_fn(&item.value);
+                }
+            }
+
+            ItemKind::Trait(item_trait) => {
+                for item in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.analyze_fn(&item.value);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn analyze_fn(&mut self, item_fn: &ItemFn) {
+        let mut scope = HashMap::new();
+
+        let args = match &item_fn.fn_signature.arguments.inner {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.iter().flat_map(|(_, args)| args.into_iter()).collect(),
+        };
+
+        for arg in args {
+            let bits = Type::from_ty(&arg.ty).int_bits();
+            Self::bind_pattern(&arg.pattern, Binding { interval: None, bits, mutable: false }, &mut scope);
+        }
+
+        self.analyze_block(&item_fn.body.inner, &mut vec![scope]);
+    }
+
+    /// Binds the variables in the supplied `pattern`, keeping the `binding` of single variable bindings.
+    fn bind_pattern(pattern: &Pattern, binding: Binding, scope: &mut HashMap<String, Binding>) {
+        match pattern {
+            Pattern::AmbiguousSingleIdent(name) => {
+                scope.insert(name.as_str().to_string(), binding);
+            }
+
+            Pattern::Var { reference: None, mutable, name } => {
+                scope.insert(name.as_str().to_string(), Binding { mutable: mutable.is_some(), ..binding });
+            }
+
+            pattern => {
+                for ident in utils::fold_pattern_idents(pattern) {
+                    scope.insert(ident.as_str().to_string(), Binding::default());
+                }
+            }
+        }
+    }
+
+    fn analyze_block(&mut self, block: &CodeBlockContents, scopes: &mut Scopes) -> Option<Interval> {
+        scopes.push(HashMap::new());
+
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Let(statement_let) => {
+                    let interval = self.analyze_expr(&statement_let.expr, scopes);
+
+                    let bits = match statement_let.ty_opt.as_ref() {
+                        Some((_, ty)) => Type::from_ty(ty).int_bits(),
+                        None => self.types.type_of(&statement_let.expr).int_bits(),
+                    };
+
+                    let interval = match (interval, bits) {
+                        (Some(interval), Some(bits)) => interval.intersect(&Interval::full(bits)),
+                        _ => None,
+                    };
+
+                    Self::bind_pattern(&statement_let.pattern, Binding { interval, bits, mutable: false }, scopes.last_mut().unwrap());
+                }
+
+                Statement::Expr { expr, .. } => {
+                    self.analyze_expr(expr, scopes);
+                }
+
+                Statement::Item(_) | Statement::Error(..) => {}
+            }
+        }
+
+        let result = block.final_expr_opt.as_ref().and_then(|expr| self.analyze_expr(expr, scopes));
+
+        scopes.pop();
+
+        result
+    }
+
+    fn analyze_if(&mut self, if_expr: &IfExpr, scopes: &mut Scopes) -> Option<Interval> {
+        let mut then_scopes = scopes.clone();
+        let mut else_scopes;
+
+        match &if_expr.condition {
+            IfCondition::Expr(expr) => {
+                self.analyze_expr(expr, scopes);
+
+                then_scopes = scopes.clone();
+                self.refine(expr, true, &mut then_scopes);
+
+                else_scopes = scopes.clone();
+                self.refine(expr, false, &mut else_scopes);
+            }
+
+            IfCondition::Let { lhs, rhs, .. } => {
+                self.analyze_expr(rhs, scopes);
+
+                else_scopes = scopes.clone();
+
+                let mut scope = HashMap::new();
+                Self::bind_pattern(lhs, Binding::default(), &mut scope);
+                then_scopes.push(scope);
+            }
+        }
+
+        let then_value = self.analyze_block(&if_expr.then_block.inner, &mut then_scopes);
+
+        if matches!(if_expr.condition, IfCondition::Let { .. }) {
+            then_scopes.pop();
+        }
+
+        let else_value = match if_expr.else_opt.as_ref() {
+            Some((_, LoopControlFlow::Continue(if_expr))) => self.analyze_if(if_expr, &mut else_scopes),
+            Some((_, LoopControlFlow::Break(block))) => self.analyze_block(&block.inner, &mut else_scopes),
+            None => None,
+        };
+
+        let then_diverges = block_diverges(&if_expr.then_block.inner);
+
+        let else_diverges = match if_expr.else_opt.as_ref() {
+            Some((_, LoopControlFlow::Break(block))) => block_diverges(&block.inner),
+            _ => false,
+        };
+
+        // Only the paths which do not return or revert continue after the `if` expression
+        *scopes = match (then_diverges, else_diverges) {
+            (true, false) => else_scopes,
+            (false, true) => then_scopes,
+            _ => {
+                join_scopes(&mut then_scopes, &else_scopes);
+                then_scopes
+            }
+        };
+
+        match (then_value, else_value) {
+            (Some(a), Some(b)) => Some(a.hull(&b)),
+            _ => None,
+        }
+    }
+
+    fn analyze_while(&mut self, condition: &Expr, block: &CodeBlockContents, scopes: &mut Scopes) {
+        // Analyze the loop once without recording results in order to find the variables it modifies
+        let recording = self.recording;
+        self.recording = false;
+
+        let mut loop_scopes = scopes.clone();
+        self.analyze_expr(condition, &mut loop_scopes);
+        self.refine(condition, true, &mut loop_scopes);
+        self.loop_exits.push(None);
+        self.analyze_block(block, &mut loop_scopes);
+        self.loop_exits.pop();
+
+        self.recording = recording;
+
+        // Modified variables can hold any value of their type at the start of an iteration
+        for (scope, loop_scope) in scopes.iter_mut().zip(loop_scopes.iter()) {
+            for (name, binding) in scope.iter_mut() {
+                if loop_scope.get(name) != Some(binding) {
+                    binding.interval = None;
+                }
+            }
+        }
+
+        self.analyze_expr(condition, scopes);
+
+        let mut loop_scopes = scopes.clone();
+        self.refine(condition, true, &mut loop_scopes);
+        self.loop_exits.push(None);
+        self.analyze_block(block, &mut loop_scopes);
+        let break_scopes = self.loop_exits.pop().unwrap();
+
+        // The loop is exited either when its condition is false or at a `break`
+        self.refine(condition, false, scopes);
+
+        if let Some(break_scopes) = break_scopes {
+            join_scopes(scopes, &break_scopes);
+        }
+    }
+
+    fn analyze_exprs<'e, I: IntoIterator<Item = &'e Expr>>(&mut self, exprs: I, scopes: &mut Scopes) {
+        for expr in exprs {
+            self.analyze_expr(expr, scopes);
+        }
+    }
+
+    /// Forgets the range of each mutable variable in `exprs`, since it may be modified through a `ref mut` parameter or reference.
+    fn widen_mutable<'e, I: IntoIterator<Item = &'e Expr>>(exprs: I, scopes: &mut Scopes) {
+        for expr in exprs {
+            let Some(binding) = variable_name(expr).and_then(|name| find_binding(scopes, name)) else { continue };
+
+            if binding.mutable {
+                binding.interval = None;
+            }
+        }
+    }
+
+    /// Gets the width in bits of the supplied `expr`, defaulting to `u64` for unsuffixed literals.
+    fn expr_bits(&self, expr: &Expr) -> u16 {
+        self.types.type_of(expr).int_bits().unwrap_or(64)
+    }
+
+    fn analyze_expr(&mut self, expr: &Expr, scopes: &mut Scopes) -> Option<Interval> {
+        let result = match expr {
+            Expr::Path(_) if variable_name(expr).is_some() => {
+                scopes.iter().rev().find_map(|scope| scope.get(variable_name(expr).unwrap())).and_then(Binding::interval)
+            }
+
+            Expr::Parens(expr) => self.analyze_expr(&expr.inner, scopes),
+
+            Expr::Block(block) => self.analyze_block(&block.inner, scopes),
+
+            Expr::If(if_expr) => self.analyze_if(if_expr, scopes),
+
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. } => {
+                let lhs = self.analyze_expr(lhs, scopes);
+                let rhs = self.analyze_expr(rhs, scopes);
+
+                match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) => self.apply(expr, BinaryOp::from_expr(expr).unwrap(), &lhs, &rhs, self.expr_bits(expr)),
+                    _ => None,
+                }
+            }
+
+            Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. } => {
+                let lhs = self.analyze_expr(lhs, scopes);
+                let rhs = self.analyze_expr(rhs, scopes);
+
+                if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+                    if let Some(result) = compare(expr, &lhs, &rhs) {
+                        if self.recording {
+                            self.result.comparison_results.insert(expr_key(expr), result);
+                        }
+                    }
+                }
+
+                None
+            }
+
+            // The right operand is only evaluated when the left operand does not decide the result
+            Expr::LogicalAnd { lhs, rhs, .. } | Expr::LogicalOr { lhs, rhs, .. } => {
+                self.analyze_expr(lhs, scopes);
+
+                let mut rhs_scopes = scopes.clone();
+                self.refine(lhs, matches!(expr, Expr::LogicalAnd { .. }), &mut rhs_scopes);
+                self.analyze_expr(rhs, &mut rhs_scopes);
+
+                None
+            }
+
+            Expr::Not { expr, .. } => {
+                self.analyze_expr(expr, scopes);
+                None
+            }
+
+            Expr::Reassignment { assignable, reassignment_op, expr: value } => {
+                if let Assignable::Index { arg, .. } = assignable {
+                    self.analyze_expr(&arg.inner, scopes);
+                }
+
+                let value = self.analyze_expr(value, scopes);
+
+                if let Assignable::Var(name) = assignable {
+                    let current = scopes.iter().rev().find_map(|scope| scope.get(name.as_str())).cloned();
+
+                    if let Some(binding) = current {
+                        let interval = match BinaryOp::from_reassignment(&reassignment_op.variant) {
+                            None => value,
+
+                            Some(op) => match (binding.interval(), value) {
+                                (Some(current), Some(value)) => self.apply(expr, op, &current, &value, binding.bits.unwrap_or(64)),
+                                _ => None,
+                            },
+                        };
+
+                        find_binding(scopes, name.as_str()).unwrap().interval = match (interval, binding.bits) {
+                            (Some(interval), Some(bits)) => interval.intersect(&Interval::full(bits)),
+                            _ => None,
+                        };
+                    }
+                }
+
+                None
+            }
+
+            Expr::While { condition, block, .. } => {
+                self.analyze_while(condition, &block.inner, scopes);
+                None
+            }
+
+            Expr::Match { value, branches, .. } => {
+                self.analyze_expr(value, scopes);
+
+                let mut joined_scopes: Option<Scopes> = None;
+
+                for branch in branches.inner.iter() {
+                    let mut branch_scopes = scopes.clone();
+
+                    let mut scope = HashMap::new();
+                    Self::bind_pattern(&branch.pattern, Binding::default(), &mut scope);
+                    branch_scopes.push(scope);
+
+                    match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => { self.analyze_block(&block.inner, &mut branch_scopes); }
+                        MatchBranchKind::Expr { expr, .. } => { self.analyze_expr(expr, &mut branch_scopes); }
+                    }
+
+                    branch_scopes.pop();
+
+                    match joined_scopes.as_mut() {
+                        Some(joined_scopes) => join_scopes(joined_scopes, &branch_scopes),
+                        None => joined_scopes = Some(branch_scopes),
+                    }
+                }
+
+                if let Some(joined_scopes) = joined_scopes {
+                    *scopes = joined_scopes;
+                }
+
+                None
+            }
+
+            Expr::FuncApp { func, args } => {
+                self.analyze_expr(func, scopes);
+                self.analyze_exprs(&args.inner, scopes);
+                Self::widen_mutable(&args.inner, scopes);
+
+                // Code following a `require` call only runs when its condition holds
+                if let Some(condition) = utils::get_require_args(expr).and_then(|args| args.first().copied()) {
+                    self.refine(condition, true, scopes);
+                }
+
+                None
+            }
+
+            Expr::Path(_) | Expr::Literal(_) | Expr::Error(..) | Expr::Continue { .. } => None,
+
+            Expr::Break { .. } => {
+                match self.loop_exits.last_mut() {
+                    Some(Some(exit_scopes)) => join_scopes(exit_scopes, scopes),
+                    Some(exit_scopes) => *exit_scopes = Some(scopes.clone()),
+                    None => {}
+                }
+
+                None
+            }
+
+            Expr::AbiCast { args, .. } => {
+                self.analyze_expr(&args.inner.address, scopes);
+                None
+            }
+
+            Expr::Struct { fields, .. } => {
+                self.analyze_exprs((&fields.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                None
+            }
+
+            Expr::Tuple(tuple) => {
+                if let ExprTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                    self.analyze_exprs(std::iter::once(head.as_ref()).chain(tail), scopes);
+                }
+
+                None
+            }
+
+            Expr::Array(array) => {
+                match &array.inner {
+                    ExprArrayDescriptor::Sequence(exprs) => self.analyze_exprs(exprs, scopes),
+                    ExprArrayDescriptor::Repeat { value, length, .. } => self.analyze_exprs([value.as_ref(), length.as_ref()], scopes),
+                }
+
+                None
+            }
+
+            Expr::Asm(asm) => {
+                self.analyze_exprs((&asm.registers.inner).into_iter().filter_map(|register| register.value_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                None
+            }
+
+            Expr::Return { expr_opt, .. } => {
+                self.analyze_exprs(expr_opt.iter().map(|expr| expr.as_ref()), scopes);
+                None
+            }
+
+            Expr::Index { target, arg } => {
+                self.analyze_exprs([target.as_ref(), arg.inner.as_ref()], scopes);
+                None
+            }
+
+            Expr::MethodCall { target, contract_args_opt, args, .. } => {
+                self.analyze_expr(target, scopes);
+
+                if let Some(contract_args) = contract_args_opt.as_ref() {
+                    self.analyze_exprs((&contract_args.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), scopes);
+                }
+
+                self.analyze_exprs(&args.inner, scopes);
+                Self::widen_mutable(std::iter::once(target.as_ref()).chain(&args.inner), scopes);
+                None
+            }
+
+            Expr::Ref { expr: target, .. } => {
+                self.analyze_expr(target, scopes);
+                Self::widen_mutable([target.as_ref()], scopes);
+                None
+            }
+
+            Expr::FieldProjection { target, .. }
+            | Expr::TupleFieldProjection { target, .. }
+            | Expr::Deref { expr: target, .. } => {
+                self.analyze_expr(target, scopes);
+                None
+            }
+        };
+
+        // Constants are exact, and any other integer can hold every value of its type
+        let result = match self.constants.value_of(expr).and_then(|value| value.as_int().cloned()) {
+            Some(value) => Some(Interval::constant(value)),
+            None => result.or_else(|| self.types.type_of(expr).int_bits().map(Interval::full)),
+        };
+
+        if self.recording {
+            if let Some(interval) = result.as_ref() {
+                self.result.expr_intervals.insert(expr_key(expr), interval.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Applies a binary operation, recording the supplied `expr` if it always reverts.
+    fn apply(&mut self, expr: &Expr, op: BinaryOp, lhs: &Interval, rhs: &Interval, bits: u16) -> Option<Interval> {
+        match op.apply(lhs, rhs, bits) {
+            Ok(interval) => Some(interval),
+
+            Err(()) => {
+                if self.recording {
+                    self.result.always_reverts.insert(expr_key(expr));
+                }
+
+                None
+            }
+        }
+    }
+
+    /// Narrows the ranges of the variables in the supplied `condition` assuming it evaluates to `value`.
+    fn refine(&mut self, condition: &Expr, value: bool, scopes: &mut Scopes) {
+        match condition {
+            Expr::Parens(expr) => self.refine(&expr.inner, value, scopes),
+            Expr::Not { expr, .. } => self.refine(expr, !value, scopes),
+
+            Expr::LogicalAnd { lhs, rhs, .. } if value => {
+                self.refine(lhs, true, scopes);
+                self.refine(rhs, true, scopes);
+            }
+
+            Expr::LogicalOr { lhs, rhs, .. } if !value => {
+                self.refine(lhs, false, scopes);
+                self.refine(rhs, false, scopes);
+            }
+
+            Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. } => {
+                let Some(op) = Comparison::from_expr(condition) else { return };
+                let op = if value { op } else { op.negate() };
+
+                // Evaluate the operands without recording, since they were already analyzed in place
+                let recording = self.recording;
+                self.recording = false;
+                let lhs_interval = self.analyze_expr(lhs, &mut scopes.clone());
+                let rhs_interval = self.analyze_expr(rhs, &mut scopes.clone());
+                self.recording = recording;
+
+                if let (Some(name), Some(other)) = (variable_name(lhs), rhs_interval.as_ref()) {
+                    Self::narrow(name, op, other, scopes);
+                }
+
+                if let (Some(name), Some(other)) = (variable_name(rhs), lhs_interval.as_ref()) {
+                    Self::narrow(name, op.flip(), other, scopes);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Narrows the range of the variable called `name` so that `name op other` holds.
+    fn narrow(name: &str, op: Comparison, other: &Interval, scopes: &mut Scopes) {
+        let Some(binding) = find_binding(scopes, name) else { return };
+        let Some(current) = binding.interval() else { return };
+
+        let one = BigUint::from(1u8);
+
+        let bound = match op {
+            Comparison::Less if other.max.is_zero() => return,
+            Comparison::Less => Interval::new(BigUint::zero(), &other.max - &one),
+            Comparison::LessEq => Interval::new(BigUint::zero(), other.max.clone()),
+            Comparison::Greater => Interval::new(&other.min + &one, current.max.clone()),
+            Comparison::GreaterEq => Interval::new(other.min.clone(), current.max.clone()),
+            Comparison::Equal => other.clone(),
+
+            Comparison::NotEqual if other.is_constant() && current.min == other.min => Interval::new(&current.min + &one, current.max.clone()),
+            Comparison::NotEqual if other.is_constant() && current.max == other.max && !current.max.is_zero() => Interval::new(current.min.clone(), &current.max - &one),
+            Comparison::NotEqual => return,
+        };
+
+        // An empty range means the path is unreachable, so keep the current range
+        if let Some(interval) = current.intersect(&bound) {
+            binding.interval = Some(interval);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessEq,
+    GreaterEq,
+}
+
+impl Comparison {
+    fn from_expr(expr: &Expr) -> Option<Self> {
+        match expr {
+            Expr::Equal { .. } => Some(Comparison::Equal),
+            Expr::NotEqual { .. } => Some(Comparison::NotEqual),
+            Expr::LessThan { .. } => Some(Comparison::Less),
+            Expr::GreaterThan { .. } => Some(Comparison::Greater),
+            Expr::LessThanEq { .. } => Some(Comparison::LessEq),
+            Expr::GreaterThanEq { .. } => Some(Comparison::GreaterEq),
+            _ => None,
+        }
+    }
+
+    /// Gets the comparison which holds when this one does not.
+    fn negate(self) -> Self {
+        match self {
+            Comparison::Equal => Comparison::NotEqual,
+            Comparison::NotEqual => Comparison::Equal,
+            Comparison::Less => Comparison::GreaterEq,
+            Comparison::Greater => Comparison::LessEq,
+            Comparison::LessEq => Comparison::Greater,
+            Comparison::GreaterEq => Comparison::Less,
+        }
+    }
+
+    /// Gets the comparison which holds with its operands swapped.
+    fn flip(self) -> Self {
+        match self {
+            Comparison::Less => Comparison::Greater,
+            Comparison::Greater => Comparison::Less,
+            Comparison::LessEq => Comparison::GreaterEq,
+            Comparison::GreaterEq => Comparison::LessEq,
+            op => op,
+        }
+    }
+}
+
+/// Gets the result of the comparison `expr` if it is the same for every value in the operand ranges `a` and `b`.
+fn compare(expr: &Expr, a: &Interval, b: &Interval) -> Option<bool> {
+    let op = Comparison::from_expr(expr)?;
+
+    let (always, never) = match op {
+        Comparison::Less => (a.max < b.min, a.min >= b.max),
+        Comparison::LessEq => (a.max <= b.min, a.min > b.max),
+        Comparison::Greater => (a.min > b.max, a.max <= b.min),
+        Comparison::GreaterEq => (a.min >= b.max, a.max < b.min),
+        Comparison::Equal => (a.is_constant() && a == b, a.intersect(b).is_none()),
+        Comparison::NotEqual => (a.intersect(b).is_none(), a.is_constant() && a == b),
+    };
+
+    if always {
+        Some(true)
+    } else if never {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_interval_of() {
+        let source = r#"contract;
+
+const MAX: u64 = 100;
+
+fn f(x: u64, y: u8, d: u64) -> u64 {
+    require(x < MAX, "x is too large");
+    let a = x * 2;
+    if y == 0 {
+        revert(0);
+    }
+    let b = y;
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+    }
+    let c = i;
+    let mut j = 0;
+    while j < 10 {
+        if j == d {
+            break;
+        }
+        j += 1;
+    }
+    let k = 5 - j;
+    if j < 10 {
+        return 2;
+    }
+    let p = 100 / (x ** d);
+    let e = x / d;
+    let f = 255u8 + 1;
+    let g = a - 500;
+    let h = x % 8;
+    if x >= 0 {
+        return 0;
+    }
+    if a > 300 && b > 0 {
+        return 1;
+    }
+    c
+}
+"#;
+
+        let results = crate::tests::collect_expr_results(source, |context, project| (
+            project.interval_of(context.expr).map(|x| x.to_string()),
+            project.always_reverts(context.expr),
+            project.comparison_result(context.expr),
+        ));
+
+        for (expr, expected) in [
+            ("x * 2", (Some("[0, 198]"), false, None)),
+            ("y", (Some("[1, 255]"), false, None)),
+            ("i", (Some("[10, 18446744073709551615]"), false, None)),
+            ("x / d", (Some("[0, 99]"), false, None)),
+            ("255u8 + 1", (Some("[0, 255]"), true, None)),
+            ("a - 500", (Some("[0, 18446744073709551615]"), true, None)),
+            ("x % 8", (Some("[0, 7]"), false, None)),
+            ("5 - j", (Some("[0, 5]"), false, None)),
+            ("j < 10", (None, false, None)),
+            ("x ** d", (Some("[0, 18446744073709551615]"), false, None)),
+            ("x >= 0", (None, false, Some(true))),
+            ("a > 300", (None, false, Some(false))),
+            ("b > 0", (None, false, Some(true))),
+        ] {
+            let actual = results.get(expr).cloned().unwrap_or_else(|| panic!("Missing expression `{expr}`"));
+            assert_eq!((actual.0.as_deref(), actual.1, actual.2), expected, "Unexpected result for `{expr}`");
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{error::Error, project::Project, utils, visitor::*};
+use std::{cell::RefCell, collections::HashSet, path::Path, rc::Rc};
+use sway_types::Spanned;
+
+/// The statistics of a traversal checked by `check_traversal`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TraversalStats {
+    /// The number of distinct expressions visited.
+    pub expr_count: usize,
+
+    /// The total number of `visit_*` calls.
+    pub visit_count: usize,
+}
+
+#[derive(Default)]
+struct TraversalState {
+    /// The hook name and node address of each `visit_*` call awaiting its `leave_*` call.
+    stack: Vec<(&'static str, usize)>,
+    visited_exprs: HashSet<usize>,
+    reachable_exprs: HashSet<usize>,
+    visit_count: usize,
+}
+
+struct InvariantVisitor {
+    state: Rc<RefCell<TraversalState>>,
+}
+
+impl InvariantVisitor {
+    fn visit(&mut self, hook: &'static str, address: usize) {
+        let mut state = self.state.borrow_mut();
+        state.stack.push((hook, address));
+        state.visit_count += 1;
+    }
+
+    fn leave(&mut self, hook: &'static str, address: usize) {
+        let expected = self.state.borrow_mut().stack.pop();
+        let expected_hook = hook.replacen("leave_", "visit_", 1);
+
+        assert!(
+            expected.map(|(visit, visit_address)| visit == expected_hook && visit_address == address).unwrap_or(false),
+            "`{hook}` was called without a matching `{expected_hook}`, expected to leave {expected:?}",
+        );
+    }
+}
+
+/// Gets the address of the supplied AST `node`, which identifies it for the duration of a traversal.
+fn node_address<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+macro_rules! balanced_hooks {
+    ($($visit:ident, $leave:ident, $context:ident, $node:ident;)*) => {
+        $(
+            fn $visit(&mut self, context: &$context, _project: &mut Project) -> Result<(), Error> {
+                self.visit(stringify!($visit), node_address(context.$node));
+                Ok(())
+            }
+
+            fn $leave(&mut self, context: &$context, _project: &mut Project) -> Result<(), Error> {
+                self.leave(stringify!($leave), node_address(context.$node));
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl AstVisitor for InvariantVisitor {
+    balanced_hooks! {
+        visit_module, leave_module, ModuleContext, module;
+        visit_module_item, leave_module_item, ItemContext, item;
+        visit_submodule, leave_submodule, SubmoduleContext, submodule;
+        visit_use, leave_use, UseContext, item_use;
+        visit_struct, leave_struct, StructContext, item_struct;
+        visit_struct_field, leave_struct_field, StructFieldContext, field;
+        visit_enum, leave_enum, EnumContext, item_enum;
+        visit_enum_field, leave_enum_field, EnumFieldContext, field;
+        visit_fn, leave_fn, FnContext, item_fn;
+        visit_statement, leave_statement, StatementContext, statement;
+        visit_statement_let, leave_statement_let, StatementLetContext, statement_let;
+        visit_block, leave_block, BlockContext, block;
+        visit_asm_block, leave_asm_block, AsmBlockContext, asm;
+        visit_asm_instruction, leave_asm_instruction, AsmInstructionContext, instruction;
+        visit_asm_final_expr, leave_asm_final_expr, AsmFinalExprContext, final_expr;
+        visit_if_expr, leave_if_expr, IfExprContext, if_expr;
+        visit_match_expr, leave_match_expr, MatchExprContext, branches;
+        visit_match_branch, leave_match_branch, MatchBranchContext, branch;
+        visit_while_expr, leave_while_expr, WhileExprContext, body;
+        visit_trait, leave_trait, TraitContext, item_trait;
+        visit_impl, leave_impl, ImplContext, item_impl;
+        visit_abi, leave_abi, AbiContext, item_abi;
+        visit_const, leave_const, ConstContext, item_const;
+        visit_storage, leave_storage, StorageContext, item_storage;
+        visit_storage_field, leave_storage_field, StorageFieldContext, field;
+        visit_configurable, leave_configurable, ConfigurableContext, item_configurable;
+        visit_configurable_field, leave_configurable_field, ConfigurableFieldContext, field;
+        visit_type_alias, leave_type_alias, TypeAliasContext, item_type_alias;
+        visit_trait_type, leave_trait_type, TraitTypeContext, item_type;
+        visit_attribute, leave_attribute, AttributeContext, attribute;
+        visit_fn_arg, leave_fn_arg, FnArgContext, fn_arg;
+        visit_pattern, leave_pattern, PatternContext, pattern;
+        visit_type, leave_type, TypeContext, ty;
+        visit_generic_param, leave_generic_param, GenericParamContext, name;
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
+        let address = node_address(context.expr);
+        self.visit("visit_expr", address);
+
+        let mut state = self.state.borrow_mut();
+        assert!(state.visited_exprs.insert(address), "Expression visited more than once: `{}`", context.expr.span().as_str());
+
+        // Every ancestor should enclose the expression, and should not be the expression itself
+        let span = context.expr.span();
+
+        for ancestor in context.ancestors.iter() {
+            let ancestor_span = ancestor.span();
+            assert!(ancestor_span.start() <= span.start() && span.end() <= ancestor_span.end(), "Ancestor `{}` does not enclose `{}`", ancestor_span.as_str(), span.as_str());
+            assert!(!matches!(ancestor, Ancestor::Expr(expr) if std::ptr::eq(*expr, context.expr)), "Expression is its own ancestor: `{}`", span.as_str());
+        }
+
+        // Every expression reachable through `utils::map_expr` should also be visited
+        utils::map_expr(context.expr, &mut |expr| {
+            state.reachable_exprs.insert(node_address(expr));
+        });
+
+        // The recursive helpers should handle any expression without panicking
+        utils::fold_expr_ident_spans(context.expr);
+        utils::fold_expr_idents(context.expr);
+        utils::find_storage_access_in_expr(context.expr);
+
+        Ok(())
+    }
+
+    fn leave_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
+        self.leave("leave_expr", node_address(context.expr));
+        Ok(())
+    }
+}
+
+/// Parses the supplied Sway `source` and traverses it with `AstVisitorRecursive`, panicking if a traversal invariant is violated:
+///
+/// * Every `visit_*` call is followed by the matching `leave_*` call for the same node, in stack order.
+/// * Every expression is enclosed by each of its ancestors.
+/// * Every expression is visited exactly once, including every expression reachable through `utils::map_expr`.
+/// * The recursive `utils` helpers do not panic on any visited expression.
+///
+/// Returns `None` if the source cannot be parsed.
+pub fn check_traversal(source: &str) -> Option<TraversalStats> {
+    let path = Path::new("fuzz.sw");
+
+    let mut project = Project::default();
+    project.parse_source(path, source).ok()?;
+
+    let state = Rc::new(RefCell::new(TraversalState::default()));
+
+    let mut visitor = AstVisitorRecursive::default();
+    visitor.visitors.push(("invariants".into(), Box::new(InvariantVisitor { state: state.clone() })));
+
+    project.visit_modules(&mut visitor).unwrap();
+
+    let state = state.borrow();
+    assert!(state.stack.is_empty(), "Traversal ended without leaving {:?}", state.stack);
+
+    let unvisited_count = state.reachable_exprs.difference(&state.visited_exprs).count();
+    assert_eq!(unvisited_count, 0, "{unvisited_count} expressions reachable through `utils::map_expr` were not visited");
+
+    Some(TraversalStats {
+        expr_count: state.visited_exprs.len(),
+        visit_count: state.visit_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Generates the source of a Sway expression along with the number of `Expr` nodes it parses into.
+    fn arb_expr() -> impl Strategy<Value = (String, usize)> {
+        let leaf = prop_oneof![
+            (0u64..1000).prop_map(|x| (x.to_string(), 1)),
+            any::<bool>().prop_map(|x| (x.to_string(), 1)),
+            Just(("x".to_string(), 1)),
+            Just(("\"text\"".to_string(), 1)),
+            Just(("storage.value.read()".to_string(), 3)),
+        ];
+
+        leaf.prop_recursive(6, 64, 3, |inner| {
+            prop_oneof![
+                (inner.clone(), inner.clone(), prop::sample::select(vec!["+", "-", "*", "/", "%", "==", "!=", "<", ">=", "&&", "||", "&", "|", "^", "<<"]))
+                    .prop_map(|((a, m), (b, n), op)| (format!("({a} {op} {b})"), m + n + 2)),
+                inner.clone().prop_map(|(a, m)| (format!("!{a}"), m + 1)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("g({a}, {b})"), m + n + 2)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("({a}).method({b})"), m + n + 2)),
+                inner.clone().prop_map(|(a, m)| (format!("({a}).field"), m + 2)),
+                inner.clone().prop_map(|(a, m)| (format!("({a}).0"), m + 2)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("({a})[{b}]"), m + n + 2)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("({a}, {b})"), m + n + 1)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("[{a}, {b}]"), m + n + 1)),
+                inner.clone().prop_map(|(a, m)| (format!("(S {{ field: {a} }})"), m + 2)),
+                (inner.clone(), inner.clone(), inner.clone())
+                    .prop_map(|((a, m), (b, n), (c, o))| (format!("if {a} {{ {b} }} else {{ {c} }}"), m + n + o + 1)),
+                (inner.clone(), inner.clone(), inner.clone())
+                    .prop_map(|((a, m), (b, n), (c, o))| (format!("match {a} {{ 0 => {{ {b} }}, _ => ({c}), }}"), m + n + o + 2)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("{{ let y = {a}; {b} }}"), m + n + 1)),
+                (inner.clone(), inner.clone()).prop_map(|((a, m), (b, n))| (format!("{{ while {a} {{ {b}; }} 0 }}"), m + n + 3)),
+                inner.clone().prop_map(|(a, m)| (format!("asm(r1: {a}) {{ move r1 r1; r1: u64 }}"), m + 1)),
+                inner.prop_map(|(a, m)| (format!("abi(A, {a})"), m + 1)),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_traversal((a, m) in arb_expr(), (b, n) in arb_expr(), (c, o) in arb_expr(), (d, p) in arb_expr()) {
+            let source = format!(
+                "contract;\n\nconst C: u64 = {d};\n\nstorage {{\n    value: u64 = 0,\n}}\n\nstruct S {{\n    field: u64,\n}}\n\nabi A {{\n    #[storage(read)]\n    fn f(x: u64) -> u64;\n}}\n\nimpl A for Contract {{\n    #[storage(read)]\n    fn f(x: u64) -> u64 {{\n        {a}\n    }}\n}}\n\n#[storage(read)]\nfn g(x: u64, z: u64) -> u64 {{\n    let y = {b};\n    {c}\n}}\n"
+            );
+
+            let stats = check_traversal(&source);
+            prop_assert!(stats.is_some(), "Failed to parse:\n{}", source);
+
+            // The storage field initializer is the only expression outside of the generated expressions
+            prop_assert_eq!(stats.unwrap().expr_count, m + n + o + p + 1, "Unexpected expression count for:\n{}", source);
+        }
+    }
+
+    #[test]
+    fn test_traversal_fixtures() {
+        for entry in std::fs::read_dir("test").unwrap().flatten() {
+            let Ok(source) = std::fs::read_to_string(entry.path().join("src/main.sw")) else { continue };
+            assert!(check_traversal(&source).is_some(), "Failed to parse {}", entry.path().to_string_lossy());
+        }
+    }
+}
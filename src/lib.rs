@@ -0,0 +1,249 @@
+pub mod abi;
+#[cfg(test)]
+pub mod annotations;
+pub mod benchmark;
+pub mod constants;
+pub mod detectors;
+pub mod diff;
+pub mod error;
+pub mod formats;
+pub mod intervals;
+#[cfg(any(test, fuzzing))]
+pub mod invariants;
+pub mod metrics;
+pub mod plugin;
+pub mod printers;
+pub mod project;
+pub mod query;
+pub mod report;
+pub mod rules;
+pub mod storage;
+pub mod summary;
+pub mod triage;
+pub mod types;
+pub mod utils;
+pub mod visitor;
+
+use project::DisplayFormat;
+use report::Sorting;
+use std::path::PathBuf;
+use structopt::{clap::AppSettings, StructOpt};
+
+#[derive(Default, StructOpt)]
+#[structopt(global_settings = &[AppSettings::ColoredHelp], settings = &[AppSettings::ArgRequiredElseHelp])]
+pub struct Options {
+    /// The display format of the report. Can be "Text", "Json", "Markdown", "Checkstyle", "JUnit" or "GitLab". (Default = Text)
+    #[structopt(long)]
+    pub display_format: Option<DisplayFormat>,
+
+    /// The base URL that source file links in Markdown reports are relative to, i.e: "https://github.com/owner/repo/blob/<commit>". (Default = The GitHub Actions commit, if any)
+    #[structopt(long)]
+    pub source_url: Option<String>,
+
+    /// The order to sort report entries by. Can be "Line" or "Severity". (Default = Line)
+    #[structopt(long)]
+    pub sorting: Option<Sorting>,
+
+    /// The path to the Forc project directory. (Optional)
+    #[structopt(long)]
+    pub directory: Option<PathBuf>,
+
+    /// The paths to the Sway source files. (Optional)
+    #[structopt(long)]
+    pub files: Vec<PathBuf>,
+
+    /// The name of the Forc package to limit the analysis to. (Optional)
+    #[structopt(long)]
+    pub package: Option<String>,
+
+    /// The cyclomatic complexity above which entry points are reported as overly complex. (Default = 10)
+    #[structopt(long)]
+    pub max_complexity: Option<usize>,
+
+    /// The block nesting depth above which entry points are reported as overly complex. (Default = 4)
+    #[structopt(long)]
+    pub max_nesting: Option<usize>,
+
+    /// The statement count above which entry points are reported as overly complex. (Default = 50)
+    #[structopt(long)]
+    pub max_statements: Option<usize>,
+
+    /// The specific detectors to utilize. (Optional; Leave unused for all)
+    #[structopt(long)]
+    pub detectors: Vec<String>,
+
+    /// The paths to TOML files containing declarative rules to utilize as additional detectors. (Optional)
+    #[structopt(long)]
+    pub rules: Vec<PathBuf>,
+
+    /// The paths to plugin libraries containing additional detectors. (Optional)
+    #[structopt(long = "plugin")]
+    pub plugins: Vec<PathBuf>,
+
+    /// The printers to utilize instead of the detectors, i.e: "function-summary". (Optional)
+    #[structopt(long = "printer")]
+    pub printers: Vec<String>,
+
+    /// The git ref to compare against, limiting the report to changed lines. (Optional)
+    #[structopt(long)]
+    pub diff: Option<String>,
+
+    /// Whether to include entries anywhere inside functions containing changed lines when using `--diff`.
+    #[structopt(long)]
+    pub diff_functions: bool,
+
+    /// Whether to measure the time spent in each detector.
+    #[structopt(long)]
+    pub timings: bool,
+
+    /// The path to the triage database. (Default = <directory>/.sway-analyzer/triage.json)
+    #[structopt(long)]
+    pub triage_file: Option<PathBuf>,
+
+    /// Whether to include entries triaged as false positives or won't fix in the report.
+    #[structopt(long)]
+    pub show_triaged: bool,
+
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(StructOpt)]
+pub enum Command {
+    /// Interactively triage each untriaged report entry as confirmed, false positive or won't fix.
+    Triage,
+
+    /// Searches the code for expressions matching a structural pattern, i.e: `storage.owner.write($value)`.
+    Query {
+        /// The pattern to search for. `$name` matches any expression or identifier and `...` matches any number of arguments, statements or instructions.
+        pattern: String,
+
+        /// The number of lines of source code to print before and after each match.
+        #[structopt(long, default_value = "2")]
+        context: usize,
+    },
+
+    /// Measures the precision and recall of each detector over a corpus of packages labeled with `expected.json` files.
+    Benchmark {
+        /// The path to the corpus directory.
+        corpus: PathBuf,
+    },
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::{
+        project::Project,
+        visitor::{AstVisitorRecursive, ExprContext, FnContext},
+    };
+    use std::collections::HashMap;
+    use sway_types::Spanned;
+
+    /// Parses the supplied Sway `source` as the `main.sw` module of a new project.
+    pub fn parse_project(source: &str) -> Project<'static> {
+        parse_modules(&[("main.sw", source)])
+    }
+
+    /// Parses each of the supplied `(path, source)` pairs as a module of a new project.
+    pub fn parse_modules(modules: &[(&str, &str)]) -> Project<'static> {
+        let mut project = Project::default();
+
+        for &(path, source) in modules {
+            project.parse_source(path, source).unwrap();
+        }
+
+        project
+    }
+
+    /// Parses the supplied Sway `source` and collects the result of `f` for each expression, keyed by the source text of the expression.
+    pub fn collect_expr_results<T, F: FnMut(&ExprContext, &Project) -> T>(source: &str, f: F) -> HashMap<String, T> {
+        collect_project_expr_results(&mut parse_project(source), f)
+    }
+
+    /// Collects the result of `f` for each expression in the supplied `project`, keyed by the source text of the expression.
+    pub fn collect_project_expr_results<T, F: FnMut(&ExprContext, &Project) -> T>(project: &mut Project, mut f: F) -> HashMap<String, T> {
+        let mut results = HashMap::new();
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_expr_hooks.push(Box::new(|context, project| {
+            results.insert(context.expr.span().as_str().to_string(), f(context, project));
+            Ok(())
+        }));
+
+        project.visit_modules(&mut visitor).unwrap();
+        drop(visitor);
+
+        results
+    }
+
+    /// Collects the result of `f` for each function in the supplied `project`, keyed by the name of the function.
+    pub fn collect_fn_results<T, F: FnMut(&FnContext, &Project) -> T>(project: &mut Project, mut f: F) -> HashMap<String, T> {
+        let mut results = HashMap::new();
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_fn_hooks.push(Box::new(|context, project| {
+            results.insert(context.item_fn.fn_signature.name.as_str().to_string(), f(context, project));
+            Ok(())
+        }));
+
+        project.visit_modules(&mut visitor).unwrap();
+        drop(visitor);
+
+        results
+    }
+
+    pub fn test_detector(name: &str, entry_count: usize) {
+        let options = Options {
+            directory: Some(format!("test/{name}").into()),
+            detectors: vec![name.to_string()],
+            ..Default::default()
+        };
+    
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+    
+        println!("{project}");
+
+        if let Err(diff) = crate::annotations::check_annotations(&project, &[name]) {
+            panic!("{diff}");
+        }
+    
+        let mut actual_entry_count = 0;
+    
+        for (_, entries) in project.report.borrow().entries.iter() {
+            actual_entry_count += entries.len();
+        }
+    
+        if entry_count != actual_entry_count {
+            panic!(
+                "Expected {entry_count} {}, found {actual_entry_count} {}",
+                if entry_count == 1 { "entry" } else { "entries" },
+                if actual_entry_count == 1 { "entry" } else { "entries" },
+            );
+        }
+    }
+
+    #[test]
+    fn test_detectors() {
+        for (display_format, golden_file_name) in [
+            (DisplayFormat::Text, "detectors.txt"),
+            (DisplayFormat::Json, "detectors.json"),
+            (DisplayFormat::Markdown, "detectors.md"),
+            (DisplayFormat::Checkstyle, "detectors.checkstyle.xml"),
+            (DisplayFormat::JUnit, "detectors.junit.xml"),
+            (DisplayFormat::GitLab, "detectors.gitlab.json"),
+        ] {
+            let options = Options {
+                display_format: Some(display_format),
+                directory: Some(PathBuf::from("test/")),
+                ..Default::default()
+            };
+
+            let mut project = Project::try_from(&options).unwrap();
+            project.analyze_modules().unwrap();
+
+            crate::formats::tests::assert_golden(&project.to_string(), golden_file_name);
+        }
+    }
+}
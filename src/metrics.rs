@@ -0,0 +1,251 @@
+use crate::{
+    error::Error,
+    project::Project,
+    utils::{self, span_key, ExprKey},
+    visitor::{AstVisitor, AstVisitorRecursive, BlockContext, ExprContext, FnContext, StatementContext},
+};
+use serde::Serialize;
+use std::{cell::RefCell, collections::HashMap, path::PathBuf};
+use sway_ast::{Expr, ItemFn, Statement, StatementLet};
+use sway_types::Spanned;
+
+/// The limits above which a function is considered overly complex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetricsThresholds {
+    pub max_complexity: usize,
+    pub max_nesting: usize,
+    pub max_statements: usize,
+}
+
+impl Default for MetricsThresholds {
+    fn default() -> Self {
+        Self {
+            max_complexity: 10,
+            max_nesting: 4,
+            max_statements: 50,
+        }
+    }
+}
+
+/// Size and complexity metrics of a single function.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FunctionMetrics {
+    pub name: String,
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub entry_point: bool,
+    /// The number of independent paths through the function, counting `if`, `match` branches, `while`, `&&` and `||`.
+    pub complexity: usize,
+    /// The deepest level of blocks nested inside the function body.
+    pub max_nesting: usize,
+    pub statements: usize,
+    pub storage_accesses: usize,
+    pub external_calls: usize,
+}
+
+impl FunctionMetrics {
+    /// Gets a description of each metric exceeding the supplied `thresholds`, i.e: `complexity 12 > 10`.
+    pub fn exceeded_thresholds(&self, thresholds: &MetricsThresholds) -> Vec<String> {
+        let mut result = vec![];
+
+        for (name, value, max) in [
+            ("complexity", self.complexity, thresholds.max_complexity),
+            ("nesting", self.max_nesting, thresholds.max_nesting),
+            ("statements", self.statements, thresholds.max_statements),
+        ] {
+            if value > max {
+                result.push(format!("{name} {value} > {max}"));
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    metrics: FunctionMetrics,
+    /// Local variables bound to an ABI cast.
+    abi_vars: Vec<String>,
+}
+
+/// Computes the metrics of each function it visits.
+#[derive(Default)]
+pub struct MetricsCollector {
+    fn_states: HashMap<ExprKey, FnState>,
+}
+
+impl MetricsCollector {
+    /// Collects the metrics of every function in the supplied `project`, ordered by location.
+    pub fn collect(project: &mut Project) -> Result<Vec<FunctionMetrics>, Error> {
+        let collector = RefCell::new(Self::default());
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_fn_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_fn(context, project)));
+        visitor.visit_block_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_block(context, project)));
+        visitor.visit_statement_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_statement(context, project)));
+        visitor.visit_expr_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_expr(context, project)));
+
+        project.visit_modules(&mut visitor)?;
+
+        drop(visitor);
+
+        Ok(collector.into_inner().into_metrics())
+    }
+
+    /// Gets the metrics of the supplied `item_fn`, if it has been visited.
+    pub fn fn_metrics(&self, item_fn: &ItemFn) -> Option<&FunctionMetrics> {
+        self.fn_states.get(&span_key(&item_fn.fn_signature.span())).map(|fn_state| &fn_state.metrics)
+    }
+
+    /// Consumes the collector, returning the metrics of every visited function ordered by location.
+    pub fn into_metrics(self) -> Vec<FunctionMetrics> {
+        let mut result = self.fn_states.into_values().map(|fn_state| fn_state.metrics).collect::<Vec<_>>();
+        result.sort_by(|a, b| (&a.path, a.line, &a.name).cmp(&(&b.path, b.line, &b.name)));
+        result
+    }
+}
+
+impl AstVisitor for MetricsCollector {
+    fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        let metrics = FunctionMetrics {
+            name: project.fn_name(context.item_impl, context.item_fn),
+            path: context.path.into(),
+            line: project.span_to_line(context.path, &context.item_fn.fn_signature.name.span())?,
+            entry_point: project.abi().entry_point(&context.item_fn.fn_signature).is_some(),
+            complexity: 1,
+            storage_accesses: project.storage().fn_accesses(context.item_fn).len(),
+            ..Default::default()
+        };
+
+        self.fn_states.insert(span_key(&context.item_fn.fn_signature.span()), FnState { metrics, abi_vars: vec![] });
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, _project: &mut Project) -> Result<(), Error> {
+        let Some(fn_state) = self.fn_states.get_mut(&span_key(&context.item_fn.fn_signature.span())) else { return Ok(()) };
+
+        // The enclosing blocks exclude the visited block, so the function body has a nesting of 0
+        fn_state.metrics.max_nesting = fn_state.metrics.max_nesting.max(context.blocks.len());
+
+        Ok(())
+    }
+
+    fn visit_statement(&mut self, context: &StatementContext, _project: &mut Project) -> Result<(), Error> {
+        let Some(fn_state) = self.fn_states.get_mut(&span_key(&context.item_fn.fn_signature.span())) else { return Ok(()) };
+
+        fn_state.metrics.statements += 1;
+
+        if let Statement::Let(StatementLet { pattern, expr: Expr::AbiCast { .. }, .. }) = context.statement {
+            let idents = utils::fold_pattern_idents(pattern);
+
+            if idents.len() == 1 {
+                fn_state.abi_vars.push(idents[0].as_str().to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+        let Some(fn_state) = self.fn_states.get_mut(&span_key(&item_fn.fn_signature.span())) else { return Ok(()) };
+
+        match context.expr {
+            Expr::If(_) | Expr::While { .. } | Expr::LogicalAnd { .. } | Expr::LogicalOr { .. } => {
+                fn_state.metrics.complexity += 1;
+            }
+
+            Expr::Match { branches, .. } => {
+                fn_state.metrics.complexity += branches.inner.len().saturating_sub(1);
+            }
+
+            Expr::MethodCall { target, .. } => match target.as_ref() {
+                Expr::AbiCast { .. } => fn_state.metrics.external_calls += 1,
+
+                Expr::Path(_) if fn_state.abi_vars.iter().any(|name| name == target.span().as_str()) => {
+                    fn_state.metrics.external_calls += 1;
+                }
+
+                _ => {}
+            },
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_metrics() {
+        let source = r#"contract;
+
+abi Token {
+    fn transfer_to(amount: u64);
+}
+
+abi Vault {
+    #[storage(read, write)]
+    fn withdraw(amount: u64, kind: u8);
+}
+
+storage {
+    total: u64 = 0,
+}
+
+fn flat(a: u64) -> u64 {
+    a + 1
+}
+
+impl Vault for Contract {
+    #[storage(read, write)]
+    fn withdraw(amount: u64, kind: u8) {
+        let total = storage.total.read();
+
+        if amount > 0 && amount <= total {
+            let mut i = 0;
+
+            while i < 3 {
+                if i == 1 || kind == 2 {
+                    i += 1;
+                }
+
+                i += 1;
+            }
+        }
+
+        match kind {
+            0 => storage.total.write(total - amount),
+            1 => {},
+            _ => revert(0),
+        }
+
+        let token = abi(Token, 0x0000000000000000000000000000000000000000000000000000000000000000);
+        token.transfer_to(amount);
+    }
+}
+"#;
+
+        let metrics = super::MetricsCollector::collect(&mut crate::tests::parse_project(source)).unwrap();
+        assert_eq!(metrics.len(), 2);
+
+        let flat = &metrics[0];
+        assert_eq!(flat.name, "flat");
+        assert_eq!(flat.complexity, 1);
+        assert_eq!(flat.max_nesting, 0);
+        assert!(!flat.entry_point);
+
+        let withdraw = &metrics[1];
+        assert_eq!(withdraw.name, "Vault::withdraw");
+        assert!(withdraw.entry_point);
+        assert_eq!(withdraw.complexity, 1 + 2 + 1 + 2 + 2);
+        assert_eq!(withdraw.max_nesting, 3);
+        assert_eq!(withdraw.statements, 9);
+        assert_eq!(withdraw.storage_accesses, 2);
+        assert_eq!(withdraw.external_calls, 1);
+    }
+}
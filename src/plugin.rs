@@ -0,0 +1,109 @@
+use crate::{error::Error, visitor::{AstVisitor, CONTEXT_VERSION}};
+use std::path::{Path, PathBuf};
+
+/// The version of `sway-analyzer` that plugins are built against.
+pub const ANALYZER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The version of the compiler that `sway-analyzer` was built with.
+pub const RUSTC_VERSION: &str = env!("SWAY_ANALYZER_RUSTC_VERSION");
+
+/// The name of the symbol exported by plugins using the `export_plugin!` macro.
+pub const PLUGIN_DECLARATION_SYMBOL: &[u8] = b"SWAY_ANALYZER_PLUGIN_DECLARATION\0";
+
+/// A function creating a new instance of a detector.
+pub type CreateDetectorFn = fn() -> Box<dyn AstVisitor>;
+
+/// The declaration exported by a plugin library, describing the versions it was built with and how to register its detectors.
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub context_version: u32,
+    pub analyzer_version: &'static str,
+    pub rustc_version: &'static str,
+    pub register: fn(&mut PluginRegistrar),
+}
+
+/// Collects the detectors registered by a plugin.
+#[derive(Default)]
+pub struct PluginRegistrar {
+    detector_types: Vec<(String, CreateDetectorFn)>,
+}
+
+impl PluginRegistrar {
+    /// Registers a detector under the supplied `name`, which is used to select it with `--detectors`.
+    pub fn register_detector<S: Into<String>>(&mut self, name: S, create_detector: CreateDetectorFn) {
+        self.detector_types.push((name.into(), create_detector));
+    }
+}
+
+/// Exports a plugin declaration from a `cdylib` crate, i.e:
+///
+/// ```ignore
+/// sway_analyzer::export_plugin!(|registrar| {
+///     registrar.register_detector("my_detector", || Box::<MyDetector>::default());
+/// });
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    ($register:expr) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static SWAY_ANALYZER_PLUGIN_DECLARATION: $crate::plugin::PluginDeclaration = $crate::plugin::PluginDeclaration {
+            context_version: $crate::visitor::CONTEXT_VERSION,
+            analyzer_version: $crate::plugin::ANALYZER_VERSION,
+            rustc_version: $crate::plugin::RUSTC_VERSION,
+            register: $register,
+        };
+    };
+}
+
+/// A dynamically loaded plugin library.
+///
+/// The library must outlive every detector created from it, since their code and vtables live inside of it.
+pub struct Plugin {
+    pub path: PathBuf,
+    pub detector_types: Vec<(String, CreateDetectorFn)>,
+    _library: libloading::Library,
+}
+
+impl Plugin {
+    /// Attempts to load the plugin library at the supplied `path`, checking that it was built against a compatible version.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let invalid_plugin = |message: String| Error::InvalidPlugin(path.into(), message);
+
+        // SAFETY: Loading a library runs its initialization routines, which is inherent to loading plugins.
+        let library = unsafe { libloading::Library::new(path) }.map_err(|e| invalid_plugin(e.to_string()))?;
+
+        // SAFETY: The symbol is declared by `export_plugin!` with the `PluginDeclaration` type.
+        let declaration = unsafe { library.get::<*const PluginDeclaration>(PLUGIN_DECLARATION_SYMBOL) }
+            .map_err(|e| invalid_plugin(e.to_string()))?;
+
+        // SAFETY: The declaration is a static in the library, which is kept loaded for the lifetime of the plugin.
+        let declaration = unsafe { &**declaration };
+
+        if declaration.context_version != CONTEXT_VERSION {
+            return Err(invalid_plugin(format!(
+                "built against context version {}, expected {CONTEXT_VERSION}",
+                declaration.context_version,
+            )));
+        }
+
+        if declaration.analyzer_version != ANALYZER_VERSION || declaration.rustc_version != RUSTC_VERSION {
+            return Err(invalid_plugin(format!(
+                "built against sway-analyzer {} with {}, expected sway-analyzer {ANALYZER_VERSION} with {RUSTC_VERSION}",
+                declaration.analyzer_version,
+                declaration.rustc_version,
+            )));
+        }
+
+        let mut registrar = PluginRegistrar::default();
+        (declaration.register)(&mut registrar);
+
+        Ok(Self {
+            path: path.into(),
+            detector_types: registrar.detector_types,
+            _library: library,
+        })
+    }
+}
+
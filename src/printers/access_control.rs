@@ -0,0 +1,267 @@
+use super::{function_summaries, join_cell, location_cell, write_markdown_table, write_text_table, Guard, GuardKind};
+use crate::{
+    error::Error,
+    project::{DisplayFormat, Project},
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    path::PathBuf,
+};
+
+/// The authorization guards of every ABI entry point and the storage fields holding privileged identities.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AccessControlMatrix {
+    pub functions: Vec<AccessControlFunction>,
+    pub privileged_fields: Vec<PrivilegedField>,
+}
+
+/// An ABI entry point with the authorization guards it performs, including through the functions it calls.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AccessControlFunction {
+    pub abi: String,
+    pub name: String,
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub guards: BTreeSet<Guard>,
+    pub state_changing: bool,
+    pub unguarded: bool,
+}
+
+/// A storage field which `msg_sender()` is checked against, with the entry points writing it.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct PrivilegedField {
+    pub path: PathBuf,
+    pub name: String,
+    pub written_by: BTreeSet<String>,
+}
+
+impl AccessControlFunction {
+    fn to_row(&self, unguarded: &str) -> Vec<String> {
+        vec![
+            format!("{}::{}", self.abi, self.name),
+            location_cell(&self.path, self.line),
+            join_cell(self.guards.iter().map(Guard::to_string)),
+            if self.state_changing { "yes" } else { "no" }.to_string(),
+            if self.unguarded { unguarded } else { "-" }.to_string(),
+        ]
+    }
+}
+
+const HEADERS: &[&str] = &["Function", "Location", "Guard", "State Changing", "Status"];
+
+/// Prints the authorization guards of every ABI entry point in the supplied `project` in the supplied `display_format`.
+pub fn print_access_control(project: &mut Project, display_format: DisplayFormat) -> Result<String, Error> {
+    let matrix = access_control_matrix(project)?;
+    let mut result = String::new();
+
+    match display_format {
+        DisplayFormat::Text => write_text(&mut result, &matrix).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Markdown => write_markdown(&mut result, &matrix).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Json => result = serde_json::to_string(&matrix).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        _ => return Err(Error::UnsupportedDisplayFormat("access-control".into())),
+    }
+
+    Ok(result)
+}
+
+fn write_text<W: Write>(f: &mut W, matrix: &AccessControlMatrix) -> std::fmt::Result {
+    let rows = matrix.functions.iter().map(|function| function.to_row("UNGUARDED")).collect::<Vec<_>>();
+    write_text_table(f, HEADERS, &rows)?;
+
+    writeln!(f)?;
+    writeln!(f, "Privileged storage fields:")?;
+
+    if matrix.privileged_fields.is_empty() {
+        writeln!(f, "\tNone")?;
+    }
+
+    for field in matrix.privileged_fields.iter() {
+        writeln!(f, "\t{}: {} (written by: {})", field.path.to_string_lossy(), field.name, join_cell(&field.written_by))?;
+    }
+
+    Ok(())
+}
+
+fn write_markdown<W: Write>(f: &mut W, matrix: &AccessControlMatrix) -> std::fmt::Result {
+    writeln!(f, "## Access control matrix")?;
+    writeln!(f)?;
+
+    let rows = matrix.functions.iter().map(|function| function.to_row("⚠️ **Unguarded**")).collect::<Vec<_>>();
+    write_markdown_table(f, HEADERS, &rows)?;
+
+    writeln!(f)?;
+    writeln!(f, "### Privileged storage fields")?;
+    writeln!(f)?;
+
+    if matrix.privileged_fields.is_empty() {
+        writeln!(f, "None.")?;
+    }
+
+    for field in matrix.privileged_fields.iter() {
+        writeln!(
+            f,
+            "- `{}` in `{}` (written by: {})",
+            field.name,
+            field.path.to_string_lossy(),
+            join_cell(field.written_by.iter().map(|x| format!("`{x}`"))),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Maps every ABI entry point in the supplied `project` to its authorization guards, flagging unguarded state-changing functions.
+pub fn access_control_matrix(project: &mut Project) -> Result<AccessControlMatrix, Error> {
+    let summaries = function_summaries(project)?;
+
+    let mut result = AccessControlMatrix::default();
+    let mut privileged_fields = BTreeMap::new();
+
+    for summary in summaries.iter() {
+        for guard in summary.guards.iter().filter(|guard| guard.kind == GuardKind::Storage) {
+            privileged_fields.entry((summary.path.clone(), guard.principal.clone())).or_insert_with(BTreeSet::new);
+        }
+    }
+
+    for summary in summaries {
+        let state_changing = !summary.writes.is_empty() || !summary.asset_operations.is_empty();
+
+        for field in summary.writes.iter() {
+            if let Some(written_by) = privileged_fields.get_mut(&(summary.path.clone(), field.clone())) {
+                written_by.insert(format!("{}::{}", summary.abi, summary.name));
+            }
+        }
+
+        result.functions.push(AccessControlFunction {
+            abi: summary.abi,
+            name: summary.name,
+            path: summary.path,
+            line: summary.line,
+            unguarded: state_changing && summary.guards.is_empty(),
+            guards: summary.guards,
+            state_changing,
+        });
+    }
+
+    result.privileged_fields = privileged_fields.into_iter()
+        .map(|((path, name), written_by)| PrivilegedField { path, name, written_by })
+        .collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_access_control() {
+        let source = r#"contract;
+
+abi Admin {
+    #[storage(read, write)]
+    fn set_owner(owner: Identity);
+
+    #[storage(read, write)]
+    fn add_admin(admin: Identity);
+
+    #[storage(read, write)]
+    fn pause();
+
+    #[storage(write)]
+    fn set_fee(fee: u64);
+
+    #[storage(read, write)]
+    fn claim();
+
+    #[storage(read)]
+    fn fee() -> u64;
+}
+
+configurable {
+    GUARDIAN: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+}
+
+const TREASURY: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+
+storage {
+    owner: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+    admins: StorageMap<Identity, bool> = StorageMap {},
+    paused: bool = false,
+    fee: u64 = 0,
+}
+
+#[storage(read)]
+fn only_owner() {
+    let owner = storage.owner.read();
+    require(msg_sender().unwrap() == owner, "not owner");
+}
+
+impl Admin for Contract {
+    #[storage(read, write)]
+    fn set_owner(owner: Identity) {
+        only_owner();
+        storage.owner.write(owner);
+    }
+
+    #[storage(read, write)]
+    fn add_admin(admin: Identity) {
+        require(storage.admins.get(msg_sender().unwrap()).try_read().unwrap_or(false), "not admin");
+        storage.admins.insert(admin, true);
+    }
+
+    #[storage(read, write)]
+    fn pause() {
+        if msg_sender().unwrap() != GUARDIAN {
+            revert(0);
+        }
+
+        storage.paused.write(true);
+    }
+
+    #[storage(write)]
+    fn set_fee(fee: u64) {
+        storage.fee.write(fee);
+    }
+
+    #[storage(read, write)]
+    fn claim() {
+        let sender = msg_sender().unwrap();
+        require(sender == Identity::Address(Address::from(TREASURY)), "not treasury");
+        storage.fee.write(0);
+    }
+
+    #[storage(read)]
+    fn fee() -> u64 {
+        storage.fee.read()
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let matrix = super::access_control_matrix(&mut project).unwrap();
+
+        let guards = matrix.functions.iter()
+            .map(|function| (function.name.as_str(), function.guards.iter().map(|x| x.to_string()).collect::<Vec<_>>(), function.unguarded))
+            .collect::<Vec<_>>();
+
+        assert_eq!(guards, vec![
+            ("set_owner", vec!["storage.owner".to_string(), "only_owner()".to_string()], false),
+            ("add_admin", vec!["storage.admins".to_string()], false),
+            ("pause", vec!["GUARDIAN (configurable)".to_string()], false),
+            ("set_fee", vec![], true),
+            ("claim", vec!["TREASURY (constant)".to_string()], false),
+            ("fee", vec![], false),
+        ]);
+
+        let privileged_fields = matrix.privileged_fields.iter()
+            .map(|field| (field.name.as_str(), field.written_by.iter().map(String::as_str).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(privileged_fields, vec![
+            ("admins", vec!["Admin::add_admin"]),
+            ("owner", vec!["Admin::set_owner"]),
+        ]);
+    }
+}
@@ -0,0 +1,365 @@
+use crate::{
+    error::Error,
+    project::{DisplayFormat, Project},
+    storage::{Callee, StorageAccessKind},
+    utils::{self, span_key, ExprKey},
+    visitor::{AstVisitorRecursive, ExprContext, FnContext},
+};
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    fmt::Write,
+    path::PathBuf,
+};
+use sway_ast::Expr;
+use sway_types::Spanned;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallGraphNodeKind {
+    AbiMethod,
+    ImplMethod,
+    Fn,
+    ExternalAbi,
+}
+
+/// A function in the call graph, or an external contract called through an `abi(...)` cast.
+#[derive(Clone, Debug, Serialize)]
+pub struct CallGraphNode {
+    pub id: usize,
+    pub name: String,
+    pub kind: CallGraphNodeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub entry_point: bool,
+    pub writes_storage: bool,
+    pub transfers_assets: bool,
+}
+
+/// A direct call between two nodes, labeled with the called method for external calls.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct CallGraphEdge {
+    pub from: usize,
+    pub to: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CallGraph {
+    pub nodes: Vec<CallGraphNode>,
+    pub edges: Vec<CallGraphEdge>,
+}
+
+impl CallGraph {
+    /// Gets the node called `name`, if any.
+    pub fn node(&self, name: &str) -> Option<&CallGraphNode> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+
+    /// Checks if there is an edge from the node called `from` to the node called `to`.
+    pub fn has_edge(&self, from: &str, to: &str) -> bool {
+        let (Some(from), Some(to)) = (self.node(from), self.node(to)) else { return false };
+        self.edges.iter().any(|edge| edge.from == from.id && edge.to == to.id)
+    }
+
+    /// Writes the call graph as a Graphviz DOT digraph.
+    pub fn write_dot<W: Write>(&self, f: &mut W) -> std::fmt::Result {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+        writeln!(f, "digraph \"call-graph\" {{")?;
+        writeln!(f, "    node [shape=box];")?;
+
+        for node in self.nodes.iter() {
+            let mut attributes = vec![format!("label=\"{}\"", escape(&node.name))];
+            let mut styles = vec![];
+
+            if node.kind == CallGraphNodeKind::ExternalAbi {
+                attributes.push("shape=ellipse".into());
+                styles.push("dashed");
+            }
+
+            if node.entry_point {
+                attributes.push("penwidth=2".into());
+                styles.push("bold");
+            }
+
+            if node.writes_storage {
+                attributes.push("fillcolor=\"lightyellow\"".into());
+                styles.push("filled");
+            }
+
+            if node.transfers_assets {
+                attributes.push("color=\"red\"".into());
+            }
+
+            if !styles.is_empty() {
+                attributes.push(format!("style=\"{}\"", styles.join(",")));
+            }
+
+            writeln!(f, "    n{} [{}];", node.id, attributes.join(", "))?;
+        }
+
+        for edge in self.edges.iter() {
+            match edge.label.as_ref() {
+                Some(label) => writeln!(f, "    n{} -> n{} [label=\"{}\"];", edge.from, edge.to, escape(label))?,
+                None => writeln!(f, "    n{} -> n{};", edge.from, edge.to)?,
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+/// Prints the call graph of the supplied `project` as Graphviz DOT, or as JSON when using the `Json` display format.
+pub fn print_call_graph(project: &mut Project, display_format: DisplayFormat) -> Result<String, Error> {
+    let call_graph = call_graph(project)?;
+    let mut result = String::new();
+
+    match display_format {
+        DisplayFormat::Text => call_graph.write_dot(&mut result).map_err(|e| Error::Wrapped(Box::new(e)))?,
+
+        DisplayFormat::Markdown => {
+            result.push_str("## Call graph\n\n```dot\n");
+            call_graph.write_dot(&mut result).map_err(|e| Error::Wrapped(Box::new(e)))?;
+            result.push_str("```\n");
+        }
+
+        DisplayFormat::Json => result = serde_json::to_string(&call_graph).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        _ => return Err(Error::UnsupportedDisplayFormat("call-graph".into())),
+    }
+
+    Ok(result)
+}
+
+/// Builds the graph of direct calls between the functions in the supplied `project`.
+pub fn call_graph(project: &mut Project) -> Result<CallGraph, Error> {
+    let builder = RefCell::new(CallGraphBuilder::default());
+    let mut visitor = AstVisitorRecursive::default();
+
+    visitor.visit_fn_hooks.push(Box::new(|context, project| builder.borrow_mut().visit_fn(context, project)));
+    visitor.visit_expr_hooks.push(Box::new(|context, _| builder.borrow_mut().visit_expr(context)));
+
+    project.visit_modules(&mut visitor)?;
+
+    drop(visitor);
+
+    Ok(builder.into_inner().build())
+}
+
+struct FnState {
+    node: CallGraphNode,
+    callees: Vec<Callee>,
+}
+
+#[derive(Default)]
+struct CallGraphBuilder {
+    fn_states: HashMap<ExprKey, FnState>,
+}
+
+impl CallGraphBuilder {
+    fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        let key = span_key(&context.item_fn.fn_signature.span());
+        let entry_point = project.abi().entry_point(&context.item_fn.fn_signature);
+
+        let kind = match (context.item_impl, entry_point) {
+            (_, Some(_)) => CallGraphNodeKind::AbiMethod,
+            (Some(_), None) => CallGraphNodeKind::ImplMethod,
+            _ => CallGraphNodeKind::Fn,
+        };
+
+        let name = project.fn_name(context.item_impl, context.item_fn);
+
+        let writes_storage = project.storage().fn_accesses(context.item_fn).iter().any(|access| access.kind == StorageAccessKind::Write);
+
+        self.fn_states.insert(key, FnState {
+            node: CallGraphNode {
+                id: 0,
+                name,
+                kind,
+                path: Some(context.path.into()),
+                line: project.span_to_line(context.path, &context.item_fn.fn_signature.name.span())?,
+                entry_point: entry_point.is_some(),
+                writes_storage,
+                transfers_assets: false,
+            },
+            callees: project.storage().fn_callees(context.item_fn).to_vec(),
+        });
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext) -> Result<(), Error> {
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+        let Some(fn_state) = self.fn_states.get_mut(&span_key(&item_fn.fn_signature.span())) else { return Ok(()) };
+
+        if let Expr::FuncApp { func, .. } = context.expr {
+            if utils::is_asset_fn_name(func.span().as_str().rsplit("::").next().unwrap_or_default()) {
+                fn_state.node.transfers_assets = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn build(self) -> CallGraph {
+        let mut keys = self.fn_states.keys().copied().collect::<Vec<_>>();
+
+        keys.sort_by(|a, b| {
+            let (a, b) = (&self.fn_states[a].node, &self.fn_states[b].node);
+            (&a.path, a.line, &a.name).cmp(&(&b.path, b.line, &b.name))
+        });
+
+        let ids = keys.iter().enumerate().map(|(id, key)| (*key, id)).collect::<HashMap<_, _>>();
+
+        let mut result = CallGraph::default();
+
+        for key in keys.iter() {
+            let mut node = self.fn_states[key].node.clone();
+            node.id = ids[key];
+            result.nodes.push(node);
+        }
+
+        // Create a node for each ABI called externally
+        let external_abis = self.fn_states.values()
+            .flat_map(|fn_state| fn_state.callees.iter())
+            .filter_map(|callee| match callee {
+                Callee::External(abi_name, _) => Some(abi_name.clone()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut external_ids = HashMap::new();
+
+        for abi_name in external_abis {
+            let id = result.nodes.len();
+            external_ids.insert(abi_name.clone(), id);
+
+            result.nodes.push(CallGraphNode {
+                id,
+                name: format!("abi({abi_name})"),
+                kind: CallGraphNodeKind::ExternalAbi,
+                path: None,
+                line: None,
+                entry_point: false,
+                writes_storage: false,
+                transfers_assets: false,
+            });
+        }
+
+        let mut edges = BTreeSet::new();
+
+        for (key, fn_state) in self.fn_states.iter() {
+            for callee in fn_state.callees.iter() {
+                match callee {
+                    Callee::Internal(to, _) => {
+                        if let Some(&to) = ids.get(to) {
+                            edges.insert(CallGraphEdge { from: ids[key], to, label: None });
+                        }
+                    }
+
+                    Callee::External(abi_name, name) => {
+                        edges.insert(CallGraphEdge { from: ids[key], to: external_ids[abi_name], label: Some(name.clone()) });
+                    }
+                }
+            }
+        }
+
+        result.edges = edges.into_iter().collect();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::Error, project::Project, Options};
+
+    #[test]
+    fn test_call_graph() {
+        let source = r#"contract;
+
+abi Token {
+    fn transfer_to(recipient: Identity, amount: u64);
+}
+
+abi Vault {
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+}
+
+struct Fees {
+    rate: u64,
+}
+
+impl Fees {
+    fn apply(self, amount: u64) -> u64 {
+        amount - amount * self.rate / 100
+    }
+}
+
+storage {
+    total: u64 = 0,
+}
+
+fn fees() -> Fees {
+    Fees { rate: 1 }
+}
+
+impl Vault for Contract {
+    #[storage(read, write)]
+    fn withdraw(amount: u64) {
+        let amount = fees().apply(amount);
+        storage.total.write(storage.total.read() - amount);
+        transfer(msg_sender().unwrap(), AssetId::base(), amount);
+        let token = abi(Token, 0x0000000000000000000000000000000000000000000000000000000000000000);
+        token.transfer_to(msg_sender().unwrap(), amount);
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let call_graph = super::call_graph(&mut project).unwrap();
+
+        let withdraw = call_graph.node("Vault::withdraw").unwrap();
+        assert!(withdraw.entry_point);
+        assert!(withdraw.writes_storage);
+        assert!(withdraw.transfers_assets);
+
+        let apply = call_graph.node("Fees::apply").unwrap();
+        assert!(!apply.entry_point);
+        assert!(!apply.writes_storage);
+
+        assert!(call_graph.has_edge("Vault::withdraw", "fees"));
+        assert!(call_graph.has_edge("Vault::withdraw", "Fees::apply"));
+        assert!(call_graph.has_edge("Vault::withdraw", "abi(Token)"));
+        assert!(!call_graph.has_edge("fees", "Fees::apply"));
+
+        let mut dot = String::new();
+        call_graph.write_dot(&mut dot).unwrap();
+        assert!(dot.contains(&format!("n{} -> n{} [label=\"transfer_to\"];", withdraw.id, call_graph.node("abi(Token)").unwrap().id)));
+
+        let options = Options {
+            directory: Some("test/".into()),
+            package: Some("external_call_in_loop".into()),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        let call_graph = super::call_graph(&mut project).unwrap();
+
+        assert_eq!(call_graph.nodes.len(), 5);
+        assert!(call_graph.has_edge("TestExternalCallInLoop::test_external_call_in_loop_1", "abi(ContractA)"));
+
+        let options = Options {
+            directory: Some("test/".into()),
+            package: Some("missing_package".into()),
+            ..Default::default()
+        };
+
+        assert!(matches!(Project::try_from(&options), Err(Error::PackageNotFound(package)) if package == "missing_package"));
+    }
+}
@@ -0,0 +1,576 @@
+use super::{join_cell, location_cell, write_markdown_table, write_text_table};
+use crate::{
+    error::Error,
+    project::{DisplayFormat, Project},
+    storage::Callee,
+    types::Type,
+    utils::{self, expr_key, span_key, ExprKey},
+    visitor::{AstVisitor, AstVisitorRecursive, ExprContext, FnContext, ModuleContext, StatementContext},
+};
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    path::PathBuf,
+};
+use sway_ast::{Expr, IfCondition, ItemKind, Statement, StatementLet};
+use sway_types::Spanned;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardKind {
+    Storage,
+    Configurable,
+    Constant,
+    Helper,
+    Other,
+}
+
+/// An authorization check comparing `msg_sender()` against a principal, or a call to an ownership helper performing one.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Guard {
+    pub kind: GuardKind,
+    pub principal: String,
+}
+
+impl Display for Guard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            GuardKind::Storage => write!(f, "storage.{}", self.principal),
+            GuardKind::Configurable => write!(f, "{} (configurable)", self.principal),
+            GuardKind::Constant => write!(f, "{} (constant)", self.principal),
+            GuardKind::Helper => write!(f, "{}()", self.principal),
+            GuardKind::Other => write!(f, "{}", self.principal),
+        }
+    }
+}
+
+/// An overview of the attack surface of a single ABI entry point.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FunctionSummary {
+    pub abi: String,
+    pub name: String,
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub payable: bool,
+    pub storage: Vec<String>,
+    pub reads: BTreeSet<String>,
+    pub writes: BTreeSet<String>,
+    pub checks_msg_sender: bool,
+    pub guards: BTreeSet<Guard>,
+    pub external_calls: BTreeSet<String>,
+    pub asset_operations: BTreeSet<String>,
+    pub logs: BTreeSet<String>,
+    pub reverts: BTreeSet<String>,
+}
+
+const HEADERS: &[&str] = &[
+    "Function",
+    "Location",
+    "Payable",
+    "Storage",
+    "Reads",
+    "Writes",
+    "Checks msg_sender",
+    "External Calls",
+    "Assets",
+    "Logs",
+    "Reverts",
+];
+
+impl FunctionSummary {
+    fn to_row(&self) -> Vec<String> {
+        let yes_no = |value: bool| if value { "yes" } else { "no" }.to_string();
+
+        vec![
+            format!("{}::{}", self.abi, self.name),
+            location_cell(&self.path, self.line),
+            yes_no(self.payable),
+            join_cell(&self.storage),
+            join_cell(&self.reads),
+            join_cell(&self.writes),
+            yes_no(self.checks_msg_sender),
+            join_cell(&self.external_calls),
+            join_cell(&self.asset_operations),
+            join_cell(&self.logs),
+            join_cell(&self.reverts),
+        ]
+    }
+}
+
+/// Prints a summary of every ABI entry point in the supplied `project` as a table in the supplied `display_format`.
+pub fn print_function_summary(project: &mut Project, display_format: DisplayFormat) -> Result<String, Error> {
+    let summaries = function_summaries(project)?;
+    let mut result = String::new();
+
+    match display_format {
+        DisplayFormat::Text => {
+            let rows = summaries.iter().map(FunctionSummary::to_row).collect::<Vec<_>>();
+            write_text_table(&mut result, HEADERS, &rows).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        DisplayFormat::Markdown => {
+            let rows = summaries.iter().map(FunctionSummary::to_row).collect::<Vec<_>>();
+            result.push_str("## Function summary\n\n");
+            write_markdown_table(&mut result, HEADERS, &rows).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        DisplayFormat::Json => {
+            result = serde_json::to_string(&summaries).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        _ => return Err(Error::UnsupportedDisplayFormat("function-summary".into())),
+    }
+
+    Ok(result)
+}
+
+/// Summarizes every ABI entry point in the supplied `project`, ordered by location.
+pub fn function_summaries(project: &mut Project) -> Result<Vec<FunctionSummary>, Error> {
+    let collector = RefCell::new(FactCollector::default());
+    let mut visitor = AstVisitorRecursive::default();
+
+    visitor.visit_module_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_module(context, project)));
+    visitor.visit_fn_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_fn(context, project)));
+    visitor.visit_statement_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_statement(context, project)));
+    visitor.visit_expr_hooks.push(Box::new(|context, project| collector.borrow_mut().visit_expr(context, project)));
+
+    project.visit_modules(&mut visitor)?;
+
+    drop(visitor);
+
+    let collector = collector.into_inner();
+
+    let facts = collector.merge_calls(project);
+
+    let mut result = collector.summaries.into_iter()
+        .map(|(key, mut summary)| {
+            if let Some(facts) = facts.get(&key) {
+                summary.checks_msg_sender = facts.checks_msg_sender;
+                summary.guards = facts.guards.clone();
+                summary.external_calls = facts.external_calls.clone();
+                summary.asset_operations = facts.asset_operations.clone();
+                summary.logs = facts.logs.clone();
+                summary.reverts = facts.reverts.clone();
+            }
+
+            summary
+        })
+        .collect::<Vec<_>>();
+
+    result.sort_by(|a, b| (&a.path, a.line, &a.name).cmp(&(&b.path, b.line, &b.name)));
+
+    Ok(result)
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+struct Facts {
+    checks_msg_sender: bool,
+    guards: BTreeSet<Guard>,
+    external_calls: BTreeSet<String>,
+    asset_operations: BTreeSet<String>,
+    logs: BTreeSet<String>,
+    reverts: BTreeSet<String>,
+}
+
+impl Facts {
+    fn extend(&mut self, other: &Facts) {
+        self.checks_msg_sender |= other.checks_msg_sender;
+        self.guards.extend(other.guards.iter().cloned());
+        self.external_calls.extend(other.external_calls.iter().cloned());
+        self.asset_operations.extend(other.asset_operations.iter().cloned());
+        self.logs.extend(other.logs.iter().cloned());
+        self.reverts.extend(other.reverts.iter().cloned());
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    facts: Facts,
+    /// The names `msg_sender` can be called by in the file containing the function, including imported aliases.
+    msg_sender_names: Vec<String>,
+    /// Local variables bound to the result of `msg_sender()`.
+    msg_sender_vars: Vec<String>,
+    /// Local variables bound to a value read from storage, with the name of the storage field.
+    storage_vars: HashMap<String, String>,
+}
+
+impl FnState {
+    fn mentions_msg_sender(&self, expr: &Expr) -> bool {
+        let mut result = false;
+
+        utils::map_expr(expr, &mut |expr| match expr {
+            Expr::FuncApp { func, .. } if self.msg_sender_names.iter().any(|name| name == func.span().as_str()) => result = true,
+            Expr::Path(_) if self.msg_sender_vars.iter().any(|name| name == expr.span().as_str()) => result = true,
+            _ => {}
+        });
+
+        result
+    }
+
+    /// Collects the principals `msg_sender()` is compared against in the supplied `condition`, including storage keys derived from it.
+    fn collect_guards(&self, condition: &Expr, project: &Project, constant_kinds: &HashMap<(usize, String), GuardKind>) -> Vec<Guard> {
+        let source = expr_key(condition).0;
+        let mut result = vec![];
+
+        utils::map_expr(condition, &mut |expr| {
+            // Check for membership in a storage field keyed by the sender, i.e: `storage.admins.get(msg_sender().unwrap()).read()`
+            if let Some(access) = project.storage().access_of(expr) {
+                if self.mentions_msg_sender(expr) {
+                    result.push(Guard { kind: GuardKind::Storage, principal: access.field.clone() });
+                }
+
+                return;
+            }
+
+            let (Expr::Equal { lhs, rhs, .. } | Expr::NotEqual { lhs, rhs, .. }) = expr else { return };
+
+            let (sender, principal) = match (self.mentions_msg_sender(lhs), self.mentions_msg_sender(rhs)) {
+                (true, false) => (lhs, rhs),
+                (false, true) => (rhs, lhs),
+                _ => return,
+            };
+
+            if Self::contains_storage_access(sender, project) {
+                return;
+            }
+
+            let mut guard = None;
+
+            utils::map_expr(principal, &mut |expr| {
+                if guard.is_some() {
+                    return;
+                }
+
+                if let Some(access) = project.storage().access_of(expr) {
+                    guard = Some(Guard { kind: GuardKind::Storage, principal: access.field.clone() });
+                } else if let Expr::Path(_) = expr {
+                    let name = expr.span().as_str().to_string();
+
+                    if let Some(field) = self.storage_vars.get(&name) {
+                        guard = Some(Guard { kind: GuardKind::Storage, principal: field.clone() });
+                    } else if let Some(&kind) = constant_kinds.get(&(source, name.clone())) {
+                        guard = Some(Guard { kind, principal: name });
+                    }
+                }
+            });
+
+            result.push(guard.unwrap_or_else(|| Guard { kind: GuardKind::Other, principal: principal.span().as_str().to_string() }));
+        });
+
+        result
+    }
+
+    fn contains_storage_access(expr: &Expr, project: &Project) -> bool {
+        let mut result = false;
+        utils::map_expr(expr, &mut |expr| result |= project.storage().access_of(expr).is_some());
+        result
+    }
+}
+
+#[derive(Default)]
+struct FactCollector {
+    /// The kinds of the constants and configurables declared in each file, keyed by source and name.
+    constant_kinds: HashMap<(usize, String), GuardKind>,
+    /// The aliases `std::auth::msg_sender` is imported as in each file, keyed by source.
+    msg_sender_aliases: HashMap<usize, Vec<String>>,
+    fn_states: HashMap<ExprKey, FnState>,
+    summaries: Vec<(ExprKey, FunctionSummary)>,
+}
+
+impl FactCollector {
+    /// Includes the facts of called functions in the facts of their callers until no facts change.
+    fn merge_calls(&self, project: &Project) -> HashMap<ExprKey, Facts> {
+        let mut result = self.fn_states.iter()
+            .map(|(key, state)| (*key, state.facts.clone()))
+            .collect::<HashMap<_, _>>();
+
+        project.storage().propagate_calls(&mut result, |facts, callee_facts, name| {
+            facts.extend(callee_facts);
+
+            // Calls to functions performing authorization checks are ownership helpers
+            if !callee_facts.guards.is_empty() {
+                facts.guards.insert(Guard { kind: GuardKind::Helper, principal: name.to_string() });
+            }
+        });
+
+        result
+    }
+}
+
+impl AstVisitor for FactCollector {
+    fn visit_module(&mut self, context: &ModuleContext, _project: &mut Project) -> Result<(), Error> {
+        let source = span_key(&context.module.span()).0;
+
+        for item in context.module.items.iter() {
+            match &item.value {
+                ItemKind::Const(item_const) => {
+                    self.constant_kinds.insert((source, item_const.name.as_str().to_string()), GuardKind::Constant);
+                }
+
+                ItemKind::Configurable(item_configurable) => {
+                    for field in (&item_configurable.fields.inner).into_iter() {
+                        self.constant_kinds.insert((source, field.value.name.as_str().to_string()), GuardKind::Configurable);
+                    }
+                }
+
+                ItemKind::Use(item_use) => {
+                    for (local_name, full_path) in utils::fold_use_tree_imports(&item_use.tree, "") {
+                        if full_path == "std::auth::msg_sender" {
+                            self.msg_sender_aliases.entry(source).or_default().push(local_name);
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        let key = span_key(&context.item_fn.fn_signature.span());
+        let name = context.item_fn.fn_signature.name.as_str().to_string();
+
+        // Since `std::auth::msg_sender` is part of the prelude, include it here
+        let mut msg_sender_names = vec!["msg_sender".to_string(), "std::auth::msg_sender".to_string()];
+        msg_sender_names.extend(self.msg_sender_aliases.get(&key.0).cloned().unwrap_or_default());
+
+        let mut facts = Facts::default();
+
+        for callee in project.storage().fn_callees(context.item_fn) {
+            if let Callee::External(abi_name, name) = callee {
+                facts.external_calls.insert(format!("{abi_name}::{name}"));
+            }
+        }
+
+        self.fn_states.insert(key, FnState { facts, msg_sender_names, ..Default::default() });
+
+        let Some(method) = project.abi().entry_point(&context.item_fn.fn_signature) else { return Ok(()) };
+
+        let storage = method.declaration_attributes.iter()
+            .chain(method.implementation_attributes.iter())
+            .filter(|attribute| attribute.name == "storage")
+            .flat_map(|attribute| attribute.args.iter().cloned())
+            .collect::<BTreeSet<_>>();
+
+        let mut summary = FunctionSummary {
+            abi: method.abi_name.clone(),
+            name,
+            path: context.path.into(),
+            line: project.span_to_line(context.path, &context.item_fn.fn_signature.name.span())?,
+            payable: method.is_payable(),
+            storage: storage.into_iter().collect(),
+            ..Default::default()
+        };
+
+        if let Some(storage_summary) = project.storage().fn_summary(context.item_fn) {
+            summary.reads = storage_summary.reads.clone();
+            summary.writes = storage_summary.writes.clone();
+        }
+
+        self.summaries.push((key, summary));
+
+        Ok(())
+    }
+
+    fn visit_statement(&mut self, context: &StatementContext, project: &mut Project) -> Result<(), Error> {
+        let Statement::Let(StatementLet { pattern, expr, .. }) = context.statement else { return Ok(()) };
+
+        let idents = utils::fold_pattern_idents(pattern);
+        if idents.len() != 1 { return Ok(()) }
+
+        let fn_state = self.fn_states.get_mut(&span_key(&context.item_fn.fn_signature.span())).unwrap();
+        let name = idents[0].as_str().to_string();
+
+        // Calls through ABI casts are resolved by the storage model
+        if let Expr::AbiCast { .. } = expr {
+            return Ok(());
+        }
+
+        if fn_state.mentions_msg_sender(expr) {
+            fn_state.msg_sender_vars.push(name);
+        } else {
+            let mut field = None;
+            utils::map_expr(expr, &mut |expr| field = field.take().or_else(|| project.storage().access_of(expr).map(|access| access.field.clone())));
+
+            if let Some(field) = field {
+                fn_state.storage_vars.insert(name, field);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+        let Some(fn_state) = self.fn_states.get_mut(&span_key(&item_fn.fn_signature.span())) else { return Ok(()) };
+
+        match context.expr {
+            Expr::FuncApp { func, args } => {
+                let name = func.span().as_str().rsplit("::").next().unwrap_or_default().to_string();
+                let args = utils::fold_punctuated(&args.inner);
+
+                match name.as_str() {
+                    "require" | "assert" | "assert_eq" | "assert_ne" | "revert" => {
+                        if name != "revert" && args.iter().any(|arg| fn_state.mentions_msg_sender(arg)) {
+                            fn_state.facts.checks_msg_sender = true;
+
+                            for arg in args.iter() {
+                                let guards = fn_state.collect_guards(arg, project, &self.constant_kinds);
+                                fn_state.facts.guards.extend(guards);
+                            }
+                        }
+
+                        fn_state.facts.reverts.insert(name);
+                    }
+
+                    "log" => {
+                        if let Some(arg) = args.first() {
+                            let value = match project.type_of(arg) {
+                                Type::Unknown => arg.span().as_str().to_string(),
+                                ty => ty.to_string(),
+                            };
+
+                            fn_state.facts.logs.insert(value);
+                        }
+                    }
+
+                    _ if utils::is_asset_fn_name(&name) => {
+                        fn_state.facts.asset_operations.insert(name);
+                    }
+
+                    _ => {
+                        // Ownership helpers from libraries can't be resolved, so recognize them by name
+                        if name.starts_with("only_") {
+                            fn_state.facts.guards.insert(Guard { kind: GuardKind::Helper, principal: name });
+                        }
+                    }
+                }
+            }
+
+            Expr::If(if_expr) => {
+                if let IfCondition::Expr(condition) = &if_expr.condition {
+                    if fn_state.mentions_msg_sender(condition) {
+                        fn_state.facts.checks_msg_sender = true;
+
+                        let guards = fn_state.collect_guards(condition, project, &self.constant_kinds);
+                        fn_state.facts.guards.extend(guards);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        project::{DisplayFormat, Project},
+        Options,
+    };
+
+    #[test]
+    fn test_function_summary() {
+        let source = r#"contract;
+
+abi Token {
+    fn transfer_to(recipient: Identity, amount: u64);
+}
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+
+    #[storage(read)]
+    fn balance() -> u64;
+}
+
+struct Withdrawn {
+    amount: u64,
+}
+
+storage {
+    owner: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+    total: u64 = 0,
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == storage.owner.read(), "not owner");
+}
+
+impl Vault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        storage.total.write(storage.total.read() + msg_amount());
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64) {
+        only_owner();
+        storage.total.write(storage.total.read() - amount);
+        transfer(msg_sender().unwrap(), AssetId::base(), amount);
+        let token = abi(Token, 0x0000000000000000000000000000000000000000000000000000000000000000);
+        token.transfer_to(msg_sender().unwrap(), amount);
+        log(Withdrawn { amount });
+    }
+
+    #[storage(read)]
+    fn balance() -> u64 {
+        storage.total.read()
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let summaries = super::function_summaries(&mut project).unwrap();
+        assert_eq!(summaries.len(), 3);
+
+        let deposit = &summaries[0];
+        assert_eq!(deposit.name, "deposit");
+        assert!(deposit.payable);
+        assert_eq!(deposit.storage, vec!["read", "write"]);
+        assert!(deposit.writes.contains("total"));
+        assert!(!deposit.checks_msg_sender);
+        assert!(deposit.reverts.is_empty());
+
+        let withdraw = &summaries[1];
+        assert_eq!(withdraw.name, "withdraw");
+        assert!(!withdraw.payable);
+        assert!(withdraw.checks_msg_sender);
+        assert_eq!(withdraw.guards.iter().map(|x| x.to_string()).collect::<Vec<_>>(), vec!["storage.owner", "only_owner()"]);
+        assert!(withdraw.reads.contains("owner"));
+        assert!(withdraw.external_calls.contains("Token::transfer_to"));
+        assert!(withdraw.asset_operations.contains("transfer"));
+        assert!(withdraw.logs.contains("Withdrawn"));
+        assert!(withdraw.reverts.contains("require"));
+
+        let balance = &summaries[2];
+        assert_eq!(balance.name, "balance");
+        assert!(balance.writes.is_empty());
+
+        let options = Options {
+            display_format: Some(DisplayFormat::Markdown),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.parse_source("main.sw", source).unwrap();
+
+        let markdown = project.print("function-summary").unwrap();
+        assert!(markdown.contains("| Vault::withdraw | main.sw:41 | no | read, write | owner, total | total | yes | Token::transfer_to | transfer | Withdrawn | require |"));
+    }
+}
@@ -0,0 +1,130 @@
+use super::{join_cell, location_cell, write_markdown_table, write_text_table};
+use crate::{
+    error::Error,
+    metrics::{FunctionMetrics, MetricsCollector},
+    project::{DisplayFormat, Project},
+};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// The metrics of a single function along with the thresholds it exceeds.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FunctionMetricsEntry {
+    #[serde(flatten)]
+    pub metrics: FunctionMetrics,
+    pub exceeded_thresholds: Vec<String>,
+}
+
+const HEADERS: &[&str] = &[
+    "Function",
+    "Location",
+    "Entry Point",
+    "Complexity",
+    "Nesting",
+    "Statements",
+    "Storage Accesses",
+    "External Calls",
+    "Over Threshold",
+];
+
+/// Prints the size and complexity metrics of each function in the supplied `project` in the supplied `display_format`.
+pub fn print_metrics(project: &mut Project, display_format: DisplayFormat) -> Result<String, Error> {
+    let entries = function_metrics(project)?;
+    let mut result = String::new();
+
+    match display_format {
+        DisplayFormat::Text => write_text(&mut result, &entries).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Markdown => write_markdown(&mut result, &entries).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Json => result = serde_json::to_string(&entries).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        _ => return Err(Error::UnsupportedDisplayFormat("metrics".into())),
+    }
+
+    Ok(result)
+}
+
+fn rows(entries: &[FunctionMetricsEntry]) -> Vec<Vec<String>> {
+    entries.iter()
+        .map(|entry| {
+            let metrics = &entry.metrics;
+
+            vec![
+                metrics.name.clone(),
+                location_cell(&metrics.path, metrics.line),
+                if metrics.entry_point { "yes" } else { "no" }.to_string(),
+                metrics.complexity.to_string(),
+                metrics.max_nesting.to_string(),
+                metrics.statements.to_string(),
+                metrics.storage_accesses.to_string(),
+                metrics.external_calls.to_string(),
+                join_cell(&entry.exceeded_thresholds),
+            ]
+        })
+        .collect()
+}
+
+fn write_text<W: Write>(f: &mut W, entries: &[FunctionMetricsEntry]) -> std::fmt::Result {
+    write_text_table(f, HEADERS, &rows(entries))
+}
+
+fn write_markdown<W: Write>(f: &mut W, entries: &[FunctionMetricsEntry]) -> std::fmt::Result {
+    writeln!(f, "## Function metrics")?;
+    writeln!(f)?;
+    write_markdown_table(f, HEADERS, &rows(entries))
+}
+
+/// Computes the metrics of each function in the supplied `project`, ordered by location.
+pub fn function_metrics(project: &mut Project) -> Result<Vec<FunctionMetricsEntry>, Error> {
+    let metrics = MetricsCollector::collect(project)?;
+    let thresholds = *project.metrics_thresholds();
+
+    Ok(
+        metrics.into_iter()
+            .map(|metrics| FunctionMetricsEntry {
+                exceeded_thresholds: metrics.exceeded_thresholds(&thresholds),
+                metrics,
+            })
+            .collect()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metrics::MetricsThresholds;
+
+    #[test]
+    fn test_function_metrics() {
+        let source = r#"contract;
+
+abi Counter {
+    fn increment(by: u64) -> u64;
+}
+
+fn helper(a: u64) -> u64 {
+    if a > 1 { a } else { 1 }
+}
+
+impl Counter for Contract {
+    fn increment(by: u64) -> u64 {
+        if by == 0 || by > 100 {
+            revert(0);
+        }
+
+        helper(by)
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+        project.set_metrics_thresholds(MetricsThresholds { max_complexity: 2, ..Default::default() });
+
+        let entries = super::function_metrics(&mut project).unwrap();
+        let summary = entries.iter()
+            .map(|entry| (entry.metrics.name.as_str(), entry.metrics.entry_point, entry.metrics.complexity, entry.exceeded_thresholds.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(summary, vec![
+            ("helper", false, 2, vec![]),
+            ("Counter::increment", true, 3, vec!["complexity 3 > 2".to_string()]),
+        ]);
+    }
+}
@@ -0,0 +1,85 @@
+mod access_control;
+mod call_graph;
+mod function_summary;
+mod metrics;
+mod storage_matrix;
+
+pub use self::{access_control::*, call_graph::*, function_summary::*, metrics::*, storage_matrix::*};
+
+use crate::{error::Error, project::{DisplayFormat, Project}};
+use std::{fmt::Write, path::Path};
+
+/// A function printing an overview of a project in the supplied display format.
+pub type PrintFn = fn(&mut Project, DisplayFormat) -> Result<String, Error>;
+
+pub const PRINTER_TYPES: &[(&str, PrintFn)] = &[
+    ("access-control", print_access_control),
+    ("call-graph", print_call_graph),
+    ("function-summary", print_function_summary),
+    ("metrics", print_metrics),
+    ("storage-matrix", print_storage_matrix),
+];
+
+/// Formats the location of a function as a table cell, i.e: `src/main.sw:12`.
+pub fn location_cell(path: &Path, line: Option<usize>) -> String {
+    match line {
+        Some(line) => format!("{}:{line}", path.to_string_lossy()),
+        None => path.to_string_lossy().to_string(),
+    }
+}
+
+/// Joins the supplied `values` into a single table cell, or `-` if there are none.
+pub fn join_cell<I: IntoIterator<Item = S>, S: AsRef<str>>(values: I) -> String {
+    let values = values.into_iter().map(|x| x.as_ref().to_string()).collect::<Vec<_>>();
+
+    if values.is_empty() {
+        "-".into()
+    } else {
+        values.join(", ")
+    }
+}
+
+/// Writes the supplied `rows` as a plain text table with each column padded to its widest cell.
+pub fn write_text_table<W: Write>(f: &mut W, headers: &[&str], rows: &[Vec<String>]) -> std::fmt::Result {
+    let mut widths = headers.iter().map(|header| header.chars().count()).collect::<Vec<_>>();
+
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let write_row = |f: &mut W, cells: &[&str]| -> std::fmt::Result {
+        let line = cells.iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        writeln!(f, "{}", line.trim_end())
+    };
+
+    let separators = widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<_>>();
+
+    write_row(f, headers)?;
+    write_row(f, &separators.iter().map(String::as_str).collect::<Vec<_>>())?;
+
+    for row in rows.iter() {
+        write_row(f, &row.iter().map(String::as_str).collect::<Vec<_>>())?;
+    }
+
+    Ok(())
+}
+
+/// Writes the supplied `rows` as a Markdown table, escaping pipes in each cell.
+pub fn write_markdown_table<W: Write>(f: &mut W, headers: &[&str], rows: &[Vec<String>]) -> std::fmt::Result {
+    writeln!(f, "| {} |", headers.join(" | "))?;
+    writeln!(f, "|{}|", headers.iter().map(|_| "-").collect::<Vec<_>>().join("|"))?;
+
+    for row in rows.iter() {
+        let cells = row.iter().map(|cell| cell.replace('|', "\\|")).collect::<Vec<_>>();
+        writeln!(f, "| {} |", cells.join(" | "))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use super::{join_cell, write_markdown_table, write_text_table};
+use crate::{
+    error::Error,
+    project::{DisplayFormat, Project},
+    visitor::AstVisitorRecursive,
+};
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    path::PathBuf,
+};
+use sway_types::Spanned;
+
+/// The storage fields declared in a single file and the functions accessing them.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StorageMatrix {
+    pub path: PathBuf,
+    pub fields: Vec<String>,
+    pub functions: Vec<StorageMatrixFunction>,
+    pub never_written: Vec<String>,
+    pub never_read: Vec<String>,
+}
+
+/// A function accessing storage, with the fields it reads and writes, including through the functions it calls.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StorageMatrixFunction {
+    pub name: String,
+    pub line: Option<usize>,
+    pub reads: BTreeSet<String>,
+    pub writes: BTreeSet<String>,
+}
+
+impl StorageMatrixFunction {
+    /// Gets the access of the supplied `field` as `R`, `W`, `RW` or `-`.
+    pub fn access(&self, field: &str) -> &'static str {
+        match (self.reads.contains(field), self.writes.contains(field)) {
+            (true, true) => "RW",
+            (true, false) => "R",
+            (false, true) => "W",
+            (false, false) => "-",
+        }
+    }
+}
+
+impl StorageMatrix {
+    fn headers(&self) -> Vec<&str> {
+        std::iter::once("Function").chain(self.fields.iter().map(String::as_str)).collect()
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.functions.iter()
+            .map(|function| {
+                std::iter::once(function.name.clone())
+                    .chain(self.fields.iter().map(|field| function.access(field).to_string()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Prints a matrix of the storage fields in the supplied `project` versus the functions accessing them in the supplied `display_format`.
+pub fn print_storage_matrix(project: &mut Project, display_format: DisplayFormat) -> Result<String, Error> {
+    let matrices = storage_matrices(project)?;
+    let mut result = String::new();
+
+    match display_format {
+        DisplayFormat::Text => write_text(&mut result, &matrices).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Markdown => write_markdown(&mut result, &matrices).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        DisplayFormat::Json => result = serde_json::to_string(&matrices).map_err(|e| Error::Wrapped(Box::new(e)))?,
+        _ => return Err(Error::UnsupportedDisplayFormat("storage-matrix".into())),
+    }
+
+    Ok(result)
+}
+
+fn write_text<W: Write>(f: &mut W, matrices: &[StorageMatrix]) -> std::fmt::Result {
+    for (i, matrix) in matrices.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+
+        writeln!(f, "{}:", matrix.path.to_string_lossy())?;
+        write_text_table(f, &matrix.headers(), &matrix.rows())?;
+        writeln!(f)?;
+        writeln!(f, "Never written: {}", join_cell(&matrix.never_written))?;
+        writeln!(f, "Never read: {}", join_cell(&matrix.never_read))?;
+    }
+
+    Ok(())
+}
+
+fn write_markdown<W: Write>(f: &mut W, matrices: &[StorageMatrix]) -> std::fmt::Result {
+    writeln!(f, "## Storage access matrix")?;
+
+    for matrix in matrices.iter() {
+        writeln!(f)?;
+        writeln!(f, "### `{}`", matrix.path.to_string_lossy())?;
+        writeln!(f)?;
+        write_markdown_table(f, &matrix.headers(), &matrix.rows())?;
+        writeln!(f)?;
+        writeln!(f, "- Never written: {}", join_cell(matrix.never_written.iter().map(|x| format!("`{x}`"))))?;
+        writeln!(f, "- Never read: {}", join_cell(matrix.never_read.iter().map(|x| format!("`{x}`"))))?;
+    }
+
+    Ok(())
+}
+
+/// Builds a storage access matrix for each file in the supplied `project` declaring storage fields, ordered by path.
+pub fn storage_matrices(project: &mut Project) -> Result<Vec<StorageMatrix>, Error> {
+    let matrices = RefCell::new(BTreeMap::<PathBuf, StorageMatrix>::new());
+    let mut visitor = AstVisitorRecursive::default();
+
+    visitor.visit_module_hooks.push(Box::new(|context, project| {
+        let fields = project.storage().module_fields(context.module).collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        matrices.borrow_mut().insert(context.path.into(), StorageMatrix {
+            path: context.path.into(),
+            fields: fields.iter().map(|field| field.name.clone()).collect(),
+            never_written: fields.iter().filter(|field| !project.storage().is_written(field)).map(|field| field.name.clone()).collect(),
+            never_read: fields.iter().filter(|field| !project.storage().is_read(field)).map(|field| field.name.clone()).collect(),
+            ..Default::default()
+        });
+
+        Ok(())
+    }));
+
+    visitor.visit_fn_hooks.push(Box::new(|context, project| {
+        let mut matrices = matrices.borrow_mut();
+        let Some(matrix) = matrices.get_mut(context.path) else { return Ok(()) };
+        let Some(summary) = project.storage().fn_summary(context.item_fn) else { return Ok(()) };
+
+        if summary.reads.is_empty() && summary.writes.is_empty() {
+            return Ok(());
+        }
+
+        matrix.functions.push(StorageMatrixFunction {
+            name: project.fn_name(context.item_impl, context.item_fn),
+            line: project.span_to_line(context.path, &context.item_fn.fn_signature.name.span())?,
+            reads: summary.reads.clone(),
+            writes: summary.writes.clone(),
+        });
+
+        Ok(())
+    }));
+
+    project.visit_modules(&mut visitor)?;
+
+    drop(visitor);
+
+    let mut result = matrices.into_inner().into_values().collect::<Vec<_>>();
+
+    for matrix in result.iter_mut() {
+        matrix.functions.sort_by_key(|function| function.line);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_storage_matrix() {
+        let source = r#"contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment();
+
+    #[storage(read)]
+    fn count() -> u64;
+}
+
+storage {
+    count: u64 = 0,
+    owner: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000,
+    unused: u64 = 0,
+    history: StorageMap<u64, u64> = StorageMap {},
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(storage.owner.read() == 0x0000000000000000000000000000000000000000000000000000000000000000, "not owner");
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() {
+        only_owner();
+        let count = storage.count.read() + 1;
+        storage.count.write(count);
+        storage.history.insert(count, count);
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.read()
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let matrices = super::storage_matrices(&mut project).unwrap();
+        assert_eq!(matrices.len(), 1);
+
+        let matrix = &matrices[0];
+        assert_eq!(matrix.fields, vec!["count", "owner", "unused", "history"]);
+        assert_eq!(matrix.never_written, vec!["owner", "unused"]);
+        assert_eq!(matrix.never_read, vec!["unused", "history"]);
+
+        let accesses = matrix.functions.iter()
+            .map(|function| (function.name.as_str(), matrix.fields.iter().map(|field| function.access(field)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(accesses, vec![
+            ("only_owner", vec!["-", "R", "-", "-"]),
+            ("Counter::increment", vec!["RW", "R", "-", "W"]),
+            ("Counter::count", vec!["R", "-", "-", "-"]),
+        ]);
+    }
+}
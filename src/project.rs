@@ -1,4 +1,25 @@
-use crate::{detectors::*, error::Error, report::Report, visitor::*, Options};
+use crate::{
+    abi::AbiModel,
+    constants::{ConstEvaluator, ConstValue},
+    detectors::*,
+    diff::DiffScope,
+    error::Error,
+    formats,
+    intervals::{Interval, IntervalAnalysis},
+    metrics::MetricsThresholds,
+    plugin::Plugin,
+    printers::PRINTER_TYPES,
+    query::{Query, QueryMatch},
+    report::Report,
+    rules::{RuleFile, RuleVisitor},
+    storage::StorageModel,
+    summary::Summary,
+    triage::{TriageDatabase, DEFAULT_TRIAGE_PATH},
+    types::{Type, TypeInference},
+    utils,
+    visitor::*,
+    Options,
+};
 use std::{
     cell::RefCell,
     collections::HashMap,
@@ -8,14 +29,18 @@ use std::{
     str::FromStr,
     sync::Arc,
 };
-use sway_ast::Module;
-use sway_types::Span;
+use sway_ast::{Expr, ItemFn, ItemImpl, Module};
+use sway_types::{Span, Spanned};
 
 #[derive(Clone, Copy, Default)]
 pub enum DisplayFormat {
     #[default]
     Text,
     Json,
+    Markdown,
+    Checkstyle,
+    JUnit,
+    GitLab,
 }
 
 impl FromStr for DisplayFormat {
@@ -25,6 +50,10 @@ impl FromStr for DisplayFormat {
         match s.to_ascii_lowercase().as_str() {
             "text" => Ok(Self::Text),
             "json" => Ok(Self::Json),
+            "markdown" => Ok(Self::Markdown),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "junit" => Ok(Self::JUnit),
+            "gitlab" => Ok(Self::GitLab),
             _ => Err(Error::InvalidDisplayFormat(s.to_string())),
         }
     }
@@ -33,23 +62,66 @@ impl FromStr for DisplayFormat {
 #[derive(Default)]
 pub struct Project<'a> {
     display_format: DisplayFormat,
+    source_url: Option<String>,
     line_ranges: HashMap<PathBuf, Vec<(usize, usize)>>,
+    sources: HashMap<PathBuf, String>,
     modules: Rc<RefCell<HashMap<PathBuf, Module>>>,
     detectors: Rc<RefCell<AstVisitorRecursive<'a>>>,
+    detector_names: Vec<String>,
+    diff: Option<DiffScope>,
+    diff_functions: bool,
+    package: Option<String>,
+    package_names: HashMap<PathBuf, Option<String>>,
+    triage: TriageDatabase,
+    triage_path: PathBuf,
+    show_triaged: bool,
+    types: TypeInference,
+    constants: ConstEvaluator,
+    intervals: IntervalAnalysis,
+    storage: StorageModel,
+    abi: AbiModel,
+    metrics_thresholds: MetricsThresholds,
     pub report: Rc<RefCell<Report>>,
+
+    // NOTE: Plugins must be dropped after the detectors created from them
+    plugins: Vec<Plugin>,
 }
 
 impl Display for Project<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.display_format {
             DisplayFormat::Text => {
-                write!(f, "{}", self.report.borrow())?;
+                let report = self.report.borrow();
+                write!(f, "{report}")?;
+
+                if !report.entries.is_empty() {
+                    writeln!(f)?;
+                }
+
+                write!(f, "{}", self.summary())?;
             }
 
             DisplayFormat::Json => {
-                let value = serde_json::to_value(self.report.borrow().clone()).unwrap();
+                let mut value = serde_json::to_value(self.report.borrow().clone()).unwrap();
+                value["summary"] = serde_json::to_value(self.summary()).unwrap();
                 write!(f, "{}", value.to_string())?;
             }
+
+            DisplayFormat::Markdown => {
+                formats::write_markdown(f, self)?;
+            }
+
+            DisplayFormat::Checkstyle => {
+                formats::write_checkstyle(f, self)?;
+            }
+
+            DisplayFormat::JUnit => {
+                formats::write_junit(f, self)?;
+            }
+
+            DisplayFormat::GitLab => {
+                formats::write_gitlab(f, self)?;
+            }
         }
 
         Ok(())
@@ -62,12 +134,40 @@ impl TryFrom<&Options> for Project<'_> {
     fn try_from(options: &Options) -> Result<Self, Self::Error> {
         let mut project = Project {
             display_format: options.display_format.unwrap_or_default(),
+            source_url: options.source_url.clone(),
             report: Rc::new(RefCell::new(Report {
                 sorting: options.sorting.unwrap_or_default(),
                 ..Default::default()
             })),
+            diff_functions: options.diff_functions,
+            package: options.package.clone(),
+            show_triaged: options.show_triaged,
             ..Default::default()
         };
+
+        let default_thresholds = MetricsThresholds::default();
+
+        project.metrics_thresholds = MetricsThresholds {
+            max_complexity: options.max_complexity.unwrap_or(default_thresholds.max_complexity),
+            max_nesting: options.max_nesting.unwrap_or(default_thresholds.max_nesting),
+            max_statements: options.max_statements.unwrap_or(default_thresholds.max_statements),
+        };
+
+        project.triage_path = match options.triage_file.as_ref() {
+            Some(path) => path.clone(),
+            None => options.directory.clone().unwrap_or_default().join(DEFAULT_TRIAGE_PATH),
+        };
+
+        project.triage = TriageDatabase::load(&project.triage_path)?;
+
+        if options.timings {
+            project.detectors.borrow_mut().timings = Some(vec![]);
+        }
+
+        if let Some(base_ref) = options.diff.as_ref() {
+            let directory = options.directory.clone().unwrap_or_else(|| PathBuf::from("."));
+            project.diff = Some(DiffScope::from_git(directory, base_ref)?);
+        }
     
         if let Some(path) = options.directory.as_ref() {
             if !path.is_dir() || !path.exists() {
@@ -92,7 +192,7 @@ impl TryFrom<&Options> for Project<'_> {
             
                     if path.is_dir() {
                         parse_dir(project, path)?;
-                    } else if path.is_file() && path.extension().map(|x| x == "sw").unwrap_or(false) {
+                    } else if path.is_file() && path.extension().map(|x| x == "sw").unwrap_or(false) && project.is_in_package_scope(&path) && project.is_in_diff_scope(&path) {
                         project.parse_file(path)?;
                     }
                 }
@@ -104,12 +204,43 @@ impl TryFrom<&Options> for Project<'_> {
         }
     
         for path in options.files.iter() {
-            project.parse_file(path)?;
+            if project.is_in_package_scope(path) && project.is_in_diff_scope(path) {
+                project.parse_file(path)?;
+            }
+        }
+
+        if let Some(package) = project.package.as_ref() {
+            if !project.package_names.values().any(|name| name.as_ref() == Some(package)) {
+                return Err(Error::PackageNotFound(package.clone()));
+            }
         }
     
         for &(detector_name, create_detector) in DETECTOR_TYPES {
             if options.detectors.is_empty() || options.detectors.iter().any(|v| v == detector_name) {
-                project.detectors.borrow_mut().visitors.push(create_detector());
+                project.detectors.borrow_mut().visitors.push((detector_name.into(), create_detector()));
+                project.detector_names.push(detector_name.to_string());
+            }
+        }
+
+        for path in options.plugins.iter() {
+            let plugin = Plugin::load(path)?;
+
+            for (detector_name, create_detector) in plugin.detector_types.iter() {
+                if options.detectors.is_empty() || options.detectors.contains(detector_name) {
+                    project.detectors.borrow_mut().visitors.push((detector_name.as_str().into(), create_detector()));
+                    project.detector_names.push(detector_name.clone());
+                }
+            }
+
+            project.plugins.push(plugin);
+        }
+
+        for path in options.rules.iter() {
+            for rule in RuleFile::load(path)?.rules {
+                if options.detectors.is_empty() || options.detectors.contains(&rule.name) {
+                    project.detector_names.push(rule.name.clone());
+                    project.detectors.borrow_mut().visitors.push((rule.name.as_str().into(), Box::new(RuleVisitor::new(rule))));
+                }
             }
         }
     
@@ -122,8 +253,16 @@ impl Project<'_> {
     pub fn parse_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         let path = PathBuf::from(path.as_ref().to_string_lossy().replace("\\\\", "\\").replace("//", "/"));
         let source = std::fs::read_to_string(path.clone()).map_err(|e| Error::Wrapped(Box::new(e)))?;
-        
+        self.parse_source(path, source)
+    }
+
+    /// Attempts to parse the supplied `source` as the contents of the file at `path`.
+    pub fn parse_source<P: Into<PathBuf>, S: Into<String>>(&mut self, path: P, source: S) -> Result<(), Error> {
+        let path = path.into();
+        let source = source.into();
+
         self.load_line_ranges(path.clone(), source.as_str());
+        self.sources.insert(path.clone(), source.clone());
 
         let handler = sway_error::handler::Handler::default();
         let source = Arc::from(source.as_str());
@@ -169,10 +308,147 @@ impl Project<'_> {
         Err(Error::LineNotFound(path.into(), offset))
     }
 
+    /// Gets the summary statistics of the parsed files and the current report.
+    pub fn summary(&self) -> Summary {
+        let modules = self.modules.borrow();
+
+        let mut summary = Summary {
+            files: modules.len(),
+            lines: self.line_ranges.values().map(Vec::len).sum(),
+            items: modules.values().map(|module| module.items.len()).sum(),
+            ..Default::default()
+        };
+
+        summary.add_report_findings(&self.report.borrow());
+
+        if let Some(timings) = self.detectors.borrow().timings.as_ref() {
+            summary.add_timings(&self.detector_names, timings);
+        }
+
+        summary
+    }
+
+    /// Attempts to get the line number in the supplied file `path` for the end of the provided `span`.
+    pub fn span_end_to_line(&self, path: &Path, span: &Span) -> Result<Option<usize>, Error> {
+        let end = span.end().max(span.start() + 1) - 1;
+        let span = Span::new(span.src().clone(), end, end, span.source_id().cloned()).unwrap();
+        self.span_to_line(path, &span)
+    }
+
+    /// Gets the base URL that source file links are relative to, if known.
+    pub fn source_url(&self) -> Option<&str> {
+        self.source_url.as_deref()
+    }
+
+    /// Gets the names of the detectors utilized by the project.
+    pub fn detector_names(&self) -> &[String] {
+        self.detector_names.as_slice()
+    }
+
+    /// Gets the paths of the parsed files.
+    pub fn source_paths(&self) -> impl Iterator<Item = &Path> {
+        self.sources.keys().map(PathBuf::as_path)
+    }
+
+    /// Attempts to get the source text of the supplied file `path`.
+    pub fn get_source(&self, path: &Path) -> Option<&str> {
+        self.sources.get(path).map(String::as_str)
+    }
+
+    /// Attempts to get the text of the line numbered `line` in the supplied file `path`.
+    pub fn get_source_line(&self, path: &Path, line: usize) -> Option<&str> {
+        self.sources.get(path)?.lines().nth(line.checked_sub(1)?)
+    }
+
+    /// Gets the inferred type of the supplied `expr` in the parsed files.
+    pub fn type_of(&self, expr: &Expr) -> Type {
+        self.types.type_of(expr)
+    }
+
+    /// Gets the value of the supplied `expr` in the parsed files if it is fixed at compile time, treating `configurable` values as unknown.
+    pub fn const_value(&self, expr: &Expr) -> Option<ConstValue> {
+        self.constants.value_of(expr)
+    }
+
+    /// Gets the range of values the supplied integer `expr` in the parsed files can hold.
+    pub fn interval_of(&self, expr: &Expr) -> Option<Interval> {
+        self.intervals.interval_of(expr)
+    }
+
+    /// Checks if the supplied arithmetic `expr` in the parsed files always reverts.
+    pub fn always_reverts(&self, expr: &Expr) -> bool {
+        self.intervals.always_reverts(expr)
+    }
+
+    /// Gets the result of the supplied comparison `expr` in the parsed files if the ranges of its operands decide it.
+    pub fn comparison_result(&self, expr: &Expr) -> Option<bool> {
+        self.intervals.comparison_result(expr)
+    }
+
+    /// Gets the model of the storage fields declared in the parsed files and the accesses made to them.
+    pub fn storage(&self) -> &StorageModel {
+        &self.storage
+    }
+
+    /// Gets the model of the ABIs declared in the parsed files and their implementations for `Contract`.
+    pub fn abi(&self) -> &AbiModel {
+        &self.abi
+    }
+
+    /// Gets the name printers show for the supplied `item_fn`, i.e: `Abi::f` for ABI methods, `Type::f` for other impl methods or `f` for free functions.
+    pub fn fn_name(&self, item_impl: Option<&ItemImpl>, item_fn: &ItemFn) -> String {
+        let name = item_fn.fn_signature.name.as_str();
+
+        match (item_impl, self.abi.entry_point(&item_fn.fn_signature)) {
+            (_, Some(method)) => format!("{}::{name}", method.abi_name),
+            (Some(item_impl), None) => format!("{}::{name}", utils::base_type_name(item_impl.ty.span().as_str())),
+            (None, None) => name.to_string(),
+        }
+    }
+
+    /// Gets the limits above which functions are considered overly complex.
+    pub fn metrics_thresholds(&self) -> &MetricsThresholds {
+        &self.metrics_thresholds
+    }
+
+    /// Sets the limits above which functions are considered overly complex.
+    pub fn set_metrics_thresholds(&mut self, metrics_thresholds: MetricsThresholds) {
+        self.metrics_thresholds = metrics_thresholds;
+    }
+
+    /// Rebuilds the models of the parsed files which are queried by detectors and printers.
+    fn build_models(&mut self) {
+        let modules = self.modules.borrow();
+        self.types = TypeInference::new(modules.values());
+        self.constants = ConstEvaluator::new(modules.values());
+        self.intervals = IntervalAnalysis::new(modules.values(), &self.types, &self.constants);
+        self.storage = StorageModel::new(modules.values(), &self.types);
+        self.abi = AbiModel::new(modules.values());
+    }
+
+    /// Attempts to traverse all of the parsed files with the supplied `visitor`.
+    pub fn visit_modules(&mut self, visitor: &mut dyn AstVisitor) -> Result<(), Error> {
+        let modules = self.modules.clone();
+        self.build_models();
+
+        for (path, module) in modules.borrow().iter() {
+            let context = ModuleContext {
+                path,
+                module,
+            };
+
+            visitor.visit_module(&context, self)?;
+            visitor.leave_module(&context, self)?;
+        }
+
+        Ok(())
+    }
+
     /// Attempts to analyze all of the parsed files.
     pub fn analyze_modules(&mut self) -> Result<(), Error> {
         let modules = self.modules.clone();
         let detectors = self.detectors.clone();
+        self.build_models();
 
         for (path, module) in modules.borrow().iter() {
             let context = ModuleContext {
@@ -184,6 +460,149 @@ impl Project<'_> {
             detectors.borrow_mut().leave_module(&context, self)?;
         }
 
+        if self.diff.is_some() {
+            self.retain_diff_entries()?;
+        }
+
+        self.report.borrow_mut().assign_fingerprints(
+            |path| formats::repository_relative_path(path),
+            |path, line| self.get_source_line(path, line).map(String::from),
+        );
+
+        self.apply_triage();
+
+        Ok(())
+    }
+
+    /// Annotates report entries with their triage status, removing entries triaged as hidden unless `show_triaged` is set.
+    fn apply_triage(&mut self) {
+        let mut report = self.report.borrow_mut();
+
+        for (_, entries) in report.entries.iter_mut() {
+            for entry in entries.iter_mut() {
+                entry.triage = self.triage.get(&entry.fingerprint).cloned();
+            }
+        }
+
+        if !self.show_triaged {
+            report.retain(|_, entry| !entry.triage.as_ref().map(|x| x.status.is_hidden()).unwrap_or(false));
+        }
+    }
+
+    /// Attempts to run the printer called `printer_name`, returning its output in the display format of the project.
+    pub fn print(&mut self, printer_name: &str) -> Result<String, Error> {
+        let Some(&(_, print)) = PRINTER_TYPES.iter().find(|(name, _)| *name == printer_name) else {
+            return Err(Error::InvalidPrinter(printer_name.to_string()));
+        };
+
+        print(self, self.display_format)
+    }
+
+    /// Attempts to find every expression in the parsed files matching the supplied `query`, ordered by location.
+    pub fn query_matches(&mut self, query: &Query) -> Result<Vec<QueryMatch>, Error> {
+        let matches = RefCell::new(vec![]);
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_expr_hooks.push(Box::new(|context, project| {
+            let Some(bindings) = query.match_expr(context.expr) else { return Ok(()) };
+
+            let span = context.expr.span();
+            let line_ranges = project.line_ranges.get(context.path).ok_or_else(|| Error::FileNotFound(context.path.into()))?;
+            let Some(line) = project.span_to_line(context.path, &span)? else { return Ok(()) };
+            let end_line = project.span_end_to_line(context.path, &span)?.unwrap_or(line);
+
+            matches.borrow_mut().push(QueryMatch {
+                path: context.path.into(),
+                line,
+                column: span.start() - line_ranges[line - 1].0 + 1,
+                end_line,
+                bindings,
+            });
+
+            Ok(())
+        }));
+
+        self.visit_modules(&mut visitor)?;
+
+        drop(visitor);
+
+        let mut matches = matches.into_inner();
+        matches.sort_by(|a, b| (&a.path, a.line, a.column).cmp(&(&b.path, b.line, b.column)));
+
+        Ok(matches)
+    }
+
+    /// Prints every expression in the parsed files matching the supplied query `pattern` with `context_lines` lines of surrounding source code.
+    pub fn query(&mut self, pattern: &str, context_lines: usize) -> Result<(), Error> {
+        let query = Query::parse(pattern)?;
+        let matches = self.query_matches(&query)?;
+
+        crate::query::write_query_matches(self, &matches, context_lines, &mut std::io::stdout())
+    }
+
+    /// Interactively walks through each untriaged report entry, saving decisions to the triage database.
+    pub fn triage_interactive(&mut self) -> Result<(), Error> {
+        let mut triage = std::mem::take(&mut self.triage);
+
+        let result = crate::triage::triage_interactive(
+            self,
+            &mut triage,
+            &self.triage_path,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+        );
+
+        self.triage = triage;
+        result
+    }
+
+    /// Checks if the file at the supplied `path` should be parsed when analyzing a diff.
+    fn is_in_diff_scope<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.diff.as_ref().map(|diff| diff.is_package_affected(path)).unwrap_or(true)
+    }
+
+    /// Checks if the file at the supplied `path` belongs to the package selected with `--package`, if any.
+    fn is_in_package_scope<P: AsRef<Path>>(&mut self, path: P) -> bool {
+        let Some(package) = self.package.as_ref() else { return true };
+        let Some(package_root) = crate::diff::find_package_root(path.as_ref()) else { return false };
+
+        let name = self.package_names.entry(package_root).or_insert_with_key(|package_root| crate::diff::read_package_name(package_root));
+        name.as_ref() == Some(package)
+    }
+
+    /// Removes all report entries which are not located on lines changed in the diff.
+    fn retain_diff_entries(&mut self) -> Result<(), Error> {
+        let Some(diff) = self.diff.as_ref() else { return Ok(()) };
+
+        // Collect the line ranges of functions containing changed lines
+        let mut changed_fn_lines: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+
+        if self.diff_functions {
+            for (path, module) in self.modules.borrow().iter() {
+                let fn_spans = crate::diff::module_fn_spans(module);
+
+                for span in fn_spans {
+                    let (Some(start), Some(end)) = (self.span_to_line(path, &span)?, self.span_end_to_line(path, &span)?) else { continue };
+
+                    if diff.intersects(path, (start, end)) {
+                        changed_fn_lines.entry(path.clone()).or_default().push((start, end));
+                    }
+                }
+            }
+        }
+
+        self.report.borrow_mut().retain(|path, entry| {
+            let Some(line) = entry.line else { return diff.is_file_changed(path) };
+
+            if let Some(fn_lines) = changed_fn_lines.get(path) {
+                if fn_lines.iter().any(|&(start, end)| start <= line && line <= end) {
+                    return true;
+                }
+            }
+
+            diff.intersects(path, (line, line))
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,732 @@
+use crate::{error::Error, project::Project};
+use std::{
+    io::Write,
+    mem::Discriminant,
+    path::PathBuf,
+    sync::Arc,
+};
+use sway_ast::{
+    expr::{asm::AsmRegisterDeclaration, LoopControlFlow},
+    keywords::SemicolonToken,
+    AsmBlock, Assignable, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, IfCondition,
+    IfExpr, Instruction, MatchBranch, MatchBranchKind, PathExpr, PathExprSegment, Pattern, Punctuated, Statement,
+};
+use sway_types::{Ident, Span, Spanned};
+
+/// The identifier prefix that `$name` metavariables are rewritten to before parsing a query.
+const VARIABLE_PREFIX: &str = "_sway_analyzer_var_";
+
+/// The identifier that `...` wildcards are rewritten to before parsing a query.
+const ELLIPSIS: &str = "_sway_analyzer_ellipsis";
+
+/// A structural search pattern over Sway expressions, i.e:
+///
+/// * `storage.owner.write($value)` - every write to `storage.owner`, binding the written value to `$value`.
+/// * `asm(...) { ... ldc $a $b $c; ... }` - every `asm` block using the `ldc` instruction.
+/// * `transfer(..., $amount)` - every call to `transfer`, binding its last argument to `$amount`.
+///
+/// `...` matches any number of arguments, statements or instructions. `$_` matches any expression or identifier without binding it. A metavariable used more than once must match identical code.
+pub struct Query {
+    pattern: Expr,
+    discriminant: Option<Discriminant<Expr>>,
+    variables: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct QueryMatch {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub bindings: Vec<(String, String)>,
+}
+
+impl Query {
+    /// Attempts to parse the supplied query `pattern` as a Sway expression containing metavariables and wildcards.
+    pub fn parse(pattern: &str) -> Result<Self, Error> {
+        let (source, variables) = rewrite_pattern(pattern);
+        let source: Arc<str> = Arc::from(source.as_str());
+        let handler = sway_error::handler::Handler::default();
+
+        let expr = sway_parse::lex(&handler, &source, 0, source.len(), None)
+            .ok()
+            .and_then(|token_stream| {
+                sway_parse::Parser::new(&handler, &token_stream)
+                    .try_parse_to_end::<Expr>()
+                    .ok()
+                    .flatten()
+                    .map(|(expr, _)| expr)
+            })
+            .filter(|_| !handler.has_errors())
+            .ok_or_else(|| Error::InvalidQuery(pattern.to_string()))?;
+
+        let discriminant = if expr_variable_name(&expr).is_some() || is_ellipsis_expr(&expr) {
+            None
+        } else {
+            Some(std::mem::discriminant(&expr))
+        };
+
+        Ok(Self {
+            pattern: expr,
+            discriminant,
+            variables,
+        })
+    }
+
+    /// Attempts to match the supplied `expr`, returning the source text bound to each metavariable if successful.
+    pub fn match_expr(&self, expr: &Expr) -> Option<Vec<(String, String)>> {
+        if let Some(discriminant) = self.discriminant {
+            if discriminant != std::mem::discriminant(expr) {
+                return None;
+            }
+        }
+
+        let mut matcher = Matcher::default();
+
+        if !matcher.match_expr(&self.pattern, expr) {
+            return None;
+        }
+
+        // Order the bindings by the appearance of their metavariables in the query
+        let mut bindings = matcher.bindings;
+        bindings.sort_by_key(|(name, _)| self.variables.iter().position(|x| x == name));
+
+        Some(bindings.into_iter().map(|(name, span)| (name, span.as_str().to_string())).collect())
+    }
+}
+
+/// Rewrites `$name` metavariables and `...` wildcards in the supplied `pattern` into identifiers that can be parsed as Sway.
+///
+/// Wildcards inside blocks are rewritten into statements, and wildcards inside `asm` blocks are rewritten into a placeholder
+/// instruction, since instructions must start with an opcode. Returns the rewritten pattern along with the names of its
+/// metavariables in order of appearance.
+fn rewrite_pattern(pattern: &str) -> (String, Vec<String>) {
+    #[derive(PartialEq)]
+    enum Delimiter {
+        Group,
+        Block,
+        AsmRegisters,
+        AsmBlock,
+    }
+
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(pattern.len());
+    let mut variables = vec![];
+    let mut delimiters = vec![];
+    let mut asm_pending = false;
+    let mut asm_block_pending = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '$' {
+            let name = chars[i + 1..].iter().take_while(|c| c.is_alphanumeric() || **c == '_').collect::<String>();
+            result.push_str(VARIABLE_PREFIX);
+            result.push_str(&name);
+            i += 1 + name.chars().count();
+
+            if name != "_" && !variables.contains(&name) {
+                variables.push(name);
+            }
+
+            continue;
+        }
+
+        if chars[i..].starts_with(&['.', '.', '.']) {
+            i += 3;
+
+            let next = chars[i..].iter().find(|c| !c.is_whitespace()).copied();
+            let delimiter = delimiters.last();
+
+            if !matches!(delimiter, Some(Delimiter::Block | Delimiter::AsmBlock)) {
+                result.push_str(ELLIPSIS);
+                continue;
+            }
+
+            // Rewrite the wildcard into a statement or instruction, followed by a wildcard final expression at the end of the block
+            if delimiter == Some(&Delimiter::AsmBlock) {
+                result.push_str("jmp ");
+            }
+
+            result.push_str(ELLIPSIS);
+
+            if next != Some(';') {
+                result.push(';');
+            }
+
+            if next == Some('}') {
+                result.push(' ');
+                result.push_str(ELLIPSIS);
+            }
+
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let word = chars[i..].iter().take_while(|c| c.is_alphanumeric() || **c == '_').collect::<String>();
+            asm_pending = word == "asm";
+            asm_block_pending = false;
+            result.push_str(&word);
+            i += word.chars().count();
+            continue;
+        }
+
+        match c {
+            '(' if asm_pending => delimiters.push(Delimiter::AsmRegisters),
+            '(' | '[' => delimiters.push(Delimiter::Group),
+            '{' if asm_block_pending => delimiters.push(Delimiter::AsmBlock),
+            '{' => delimiters.push(Delimiter::Block),
+
+            ')' | ']' | '}' => {
+                asm_block_pending = delimiters.pop() == Some(Delimiter::AsmRegisters);
+                asm_pending = false;
+                result.push(c);
+                i += 1;
+                continue;
+            }
+
+            _ => {}
+        }
+
+        if !c.is_whitespace() {
+            asm_pending = false;
+            asm_block_pending = false;
+        }
+
+        result.push(c);
+        i += 1;
+    }
+
+    (result, variables)
+}
+
+/// Gets the name of the metavariable if the supplied pattern `ident` is one.
+fn ident_variable_name(ident: &Ident) -> Option<&str> {
+    ident.as_str().strip_prefix(VARIABLE_PREFIX)
+}
+
+/// Gets the identifier of the supplied `expr` if it is a path expression consisting of a single identifier.
+fn single_ident(expr: &Expr) -> Option<&Ident> {
+    let Expr::Path(path) = expr else { return None };
+
+    if path.root_opt.is_some() || path.prefix.generics_opt.is_some() || !path.suffix.is_empty() {
+        return None;
+    }
+
+    Some(&path.prefix.name)
+}
+
+/// Gets the name of the metavariable if the supplied pattern `expr` is one.
+fn expr_variable_name(expr: &Expr) -> Option<&str> {
+    single_ident(expr).and_then(ident_variable_name)
+}
+
+/// Checks if the supplied pattern `expr` is a `...` wildcard.
+fn is_ellipsis_expr(expr: &Expr) -> bool {
+    single_ident(expr).map(|ident| ident.as_str() == ELLIPSIS).unwrap_or(false)
+}
+
+/// Checks if the supplied pattern `statement` is a `...` wildcard.
+fn is_ellipsis_statement(statement: &Statement) -> bool {
+    matches!(statement, Statement::Expr { expr, .. } if is_ellipsis_expr(expr))
+}
+
+/// Checks if the supplied pattern `field` is a `...` wildcard.
+fn is_ellipsis_field(field: &ExprStructField) -> bool {
+    field.field_name.as_str() == ELLIPSIS && field.expr_opt.is_none()
+}
+
+/// Checks if the supplied pattern `register` is a `...` wildcard.
+fn is_ellipsis_register(register: &AsmRegisterDeclaration) -> bool {
+    register.register.as_str() == ELLIPSIS && register.value_opt.is_none()
+}
+
+/// Checks if the supplied pattern `instruction` is a `...` wildcard, which is rewritten into a placeholder `jmp` instruction.
+fn is_ellipsis_instruction(instruction: &(Instruction, SemicolonToken)) -> bool {
+    let registers = instruction.0.register_arg_idents();
+    registers.len() == 1 && registers[0].as_str() == ELLIPSIS
+}
+
+/// Checks if the supplied source texts consist of the same tokens, ignoring whitespace.
+fn tokens_eq(a: &str, b: &str) -> bool {
+    a.chars().filter(|c| !c.is_whitespace()).eq(b.chars().filter(|c| !c.is_whitespace()))
+}
+
+/// Gets the operands of the supplied `expr` if it is a binary operation.
+fn binary_operands(expr: &Expr) -> Option<(&Expr, &Expr)> {
+    match expr {
+        Expr::Mul { lhs, rhs, .. } |
+        Expr::Div { lhs, rhs, .. } |
+        Expr::Pow { lhs, rhs, .. } |
+        Expr::Modulo { lhs, rhs, .. } |
+        Expr::Add { lhs, rhs, .. } |
+        Expr::Sub { lhs, rhs, .. } |
+        Expr::Shl { lhs, rhs, .. } |
+        Expr::Shr { lhs, rhs, .. } |
+        Expr::BitAnd { lhs, rhs, .. } |
+        Expr::BitXor { lhs, rhs, .. } |
+        Expr::BitOr { lhs, rhs, .. } |
+        Expr::Equal { lhs, rhs, .. } |
+        Expr::NotEqual { lhs, rhs, .. } |
+        Expr::LessThan { lhs, rhs, .. } |
+        Expr::GreaterThan { lhs, rhs, .. } |
+        Expr::LessThanEq { lhs, rhs, .. } |
+        Expr::GreaterThanEq { lhs, rhs, .. } |
+        Expr::LogicalAnd { lhs, rhs, .. } |
+        Expr::LogicalOr { lhs, rhs, .. } => Some((lhs.as_ref(), rhs.as_ref())),
+
+        _ => None,
+    }
+}
+
+/// Gets the elements of the supplied tuple `descriptor`.
+fn tuple_elements(descriptor: &ExprTupleDescriptor) -> Vec<&Expr> {
+    match descriptor {
+        ExprTupleDescriptor::Nil => vec![],
+        ExprTupleDescriptor::Cons { head, tail, .. } => std::iter::once(head.as_ref()).chain(tail).collect(),
+    }
+}
+
+/// Matches the nodes of a query pattern against the nodes of the code being searched, collecting the spans bound to metavariables.
+#[derive(Default)]
+struct Matcher {
+    bindings: Vec<(String, Span)>,
+}
+
+impl Matcher {
+    /// Calls `f`, discarding the bindings it collected if it fails.
+    fn attempt<F: FnOnce(&mut Self) -> bool>(&mut self, f: F) -> bool {
+        let binding_count = self.bindings.len();
+
+        if f(self) {
+            return true;
+        }
+
+        self.bindings.truncate(binding_count);
+        false
+    }
+
+    /// Binds the metavariable called `name` to the supplied `span`, or checks that it matches identical code if it is already bound.
+    fn bind(&mut self, name: &str, span: Span) -> bool {
+        if name == "_" {
+            return true;
+        }
+
+        if let Some((_, bound)) = self.bindings.iter().find(|(x, _)| x == name) {
+            return tokens_eq(bound.as_str(), span.as_str());
+        }
+
+        self.bindings.push((name.to_string(), span));
+        true
+    }
+
+    /// Checks if the `targets` sequence matches the `patterns` sequence, where elements satisfying `is_ellipsis` match any number of elements.
+    fn match_sequence<T>(
+        &mut self,
+        patterns: &[&T],
+        targets: &[&T],
+        is_ellipsis: fn(&T) -> bool,
+        match_element: fn(&mut Self, &T, &T) -> bool,
+    ) -> bool {
+        let Some((pattern, patterns_rest)) = patterns.split_first() else { return targets.is_empty() };
+
+        if is_ellipsis(pattern) {
+            return (0..=targets.len()).any(|i| self.attempt(|m| m.match_sequence(patterns_rest, &targets[i..], is_ellipsis, match_element)));
+        }
+
+        let Some((target, targets_rest)) = targets.split_first() else { return false };
+
+        self.attempt(|m| match_element(m, pattern, target) && m.match_sequence(patterns_rest, targets_rest, is_ellipsis, match_element))
+    }
+
+    fn match_punctuated<T, P>(
+        &mut self,
+        patterns: &Punctuated<T, P>,
+        targets: &Punctuated<T, P>,
+        is_ellipsis: fn(&T) -> bool,
+        match_element: fn(&mut Self, &T, &T) -> bool,
+    ) -> bool {
+        let patterns = patterns.into_iter().collect::<Vec<_>>();
+        let targets = targets.into_iter().collect::<Vec<_>>();
+        self.match_sequence(&patterns, &targets, is_ellipsis, match_element)
+    }
+
+    fn match_ident(&mut self, pattern: &Ident, target: &Ident) -> bool {
+        match ident_variable_name(pattern) {
+            Some(name) => self.bind(name, target.span()),
+            None => pattern.as_str() == target.as_str(),
+        }
+    }
+
+    fn match_path_segment(&mut self, pattern: &PathExprSegment, target: &PathExprSegment) -> bool {
+        let generics_match = match (pattern.generics_opt.as_ref(), target.generics_opt.as_ref()) {
+            (None, None) => true,
+            (Some((_, pattern)), Some((_, target))) => tokens_eq(pattern.span().as_str(), target.span().as_str()),
+            _ => false,
+        };
+
+        generics_match && self.match_ident(&pattern.name, &target.name)
+    }
+
+    fn match_path(&mut self, pattern: &PathExpr, target: &PathExpr) -> bool {
+        let root_matches = match (pattern.root_opt.as_ref(), target.root_opt.as_ref()) {
+            (None, None) => true,
+            (Some((None, _)), Some((None, _))) => true,
+            (Some((Some(pattern), _)), Some((Some(target), _))) => tokens_eq(pattern.span().as_str(), target.span().as_str()),
+            _ => false,
+        };
+
+        root_matches
+            && pattern.suffix.len() == target.suffix.len()
+            && self.match_path_segment(&pattern.prefix, &target.prefix)
+            && pattern.suffix.iter().zip(target.suffix.iter()).all(|((_, pattern), (_, target))| self.match_path_segment(pattern, target))
+    }
+
+    fn match_pattern(&mut self, pattern: &Pattern, target: &Pattern) -> bool {
+        if let Pattern::Var { name, .. } | Pattern::AmbiguousSingleIdent(name) = pattern {
+            if let Some(name) = ident_variable_name(name) {
+                return self.bind(name, target.span());
+            }
+        }
+
+        tokens_eq(pattern.span().as_str(), target.span().as_str())
+    }
+
+    fn match_expr_opt(&mut self, pattern: Option<&Expr>, target: Option<&Expr>) -> bool {
+        match (pattern, target) {
+            (None, None) => true,
+            (Some(pattern), _) if is_ellipsis_expr(pattern) => true,
+            (Some(pattern), Some(target)) => self.match_expr(pattern, target),
+            _ => false,
+        }
+    }
+
+    fn match_statement(&mut self, pattern: &Statement, target: &Statement) -> bool {
+        match (pattern, target) {
+            (Statement::Let(pattern), Statement::Let(target)) => {
+                let ty_matches = match (pattern.ty_opt.as_ref(), target.ty_opt.as_ref()) {
+                    (None, None) => true,
+                    (Some((_, pattern)), Some((_, target))) => tokens_eq(pattern.span().as_str(), target.span().as_str()),
+                    _ => false,
+                };
+
+                ty_matches && self.match_pattern(&pattern.pattern, &target.pattern) && self.match_expr(&pattern.expr, &target.expr)
+            }
+
+            (Statement::Expr { expr: pattern, .. }, Statement::Expr { expr: target, .. }) => self.match_expr(pattern, target),
+            (Statement::Item(pattern), Statement::Item(target)) => tokens_eq(pattern.span().as_str(), target.span().as_str()),
+            _ => false,
+        }
+    }
+
+    fn match_block(&mut self, pattern: &CodeBlockContents, target: &CodeBlockContents) -> bool {
+        let patterns = pattern.statements.iter().collect::<Vec<_>>();
+        let targets = target.statements.iter().collect::<Vec<_>>();
+
+        self.match_sequence(&patterns, &targets, is_ellipsis_statement, Self::match_statement)
+            && self.match_expr_opt(pattern.final_expr_opt.as_deref(), target.final_expr_opt.as_deref())
+    }
+
+    fn match_if(&mut self, pattern: &IfExpr, target: &IfExpr) -> bool {
+        let condition_matches = match (&pattern.condition, &target.condition) {
+            (IfCondition::Expr(pattern), IfCondition::Expr(target)) => self.match_expr(pattern, target),
+
+            (IfCondition::Let { lhs: pattern_lhs, rhs: pattern_rhs, .. }, IfCondition::Let { lhs: target_lhs, rhs: target_rhs, .. }) => {
+                self.match_pattern(pattern_lhs, target_lhs) && self.match_expr(pattern_rhs, target_rhs)
+            }
+
+            _ => false,
+        };
+
+        let else_matches = |m: &mut Self| match (pattern.else_opt.as_ref(), target.else_opt.as_ref()) {
+            (None, None) => true,
+            (Some((_, LoopControlFlow::Break(pattern))), Some((_, LoopControlFlow::Break(target)))) => m.match_block(&pattern.inner, &target.inner),
+            (Some((_, LoopControlFlow::Continue(pattern))), Some((_, LoopControlFlow::Continue(target)))) => m.match_if(pattern, target),
+            _ => false,
+        };
+
+        condition_matches && self.match_block(&pattern.then_block.inner, &target.then_block.inner) && else_matches(self)
+    }
+
+    fn match_match_branch(&mut self, pattern: &MatchBranch, target: &MatchBranch) -> bool {
+        let kind_matches = |m: &mut Self| match (&pattern.kind, &target.kind) {
+            (MatchBranchKind::Block { block: pattern, .. }, MatchBranchKind::Block { block: target, .. }) => m.match_block(&pattern.inner, &target.inner),
+            (MatchBranchKind::Expr { expr: pattern, .. }, MatchBranchKind::Expr { expr: target, .. }) => m.match_expr(pattern, target),
+            _ => false,
+        };
+
+        self.match_pattern(&pattern.pattern, &target.pattern) && kind_matches(self)
+    }
+
+    fn match_struct_field(&mut self, pattern: &ExprStructField, target: &ExprStructField) -> bool {
+        let pattern_expr = pattern.expr_opt.as_ref().map(|(_, expr)| expr.as_ref());
+        let target_expr = target.expr_opt.as_ref().map(|(_, expr)| expr.as_ref());
+
+        self.match_ident(&pattern.field_name, &target.field_name) && self.match_expr_opt(pattern_expr, target_expr)
+    }
+
+    fn match_asm_register(&mut self, pattern: &AsmRegisterDeclaration, target: &AsmRegisterDeclaration) -> bool {
+        let pattern_value = pattern.value_opt.as_ref().map(|(_, expr)| expr.as_ref());
+        let target_value = target.value_opt.as_ref().map(|(_, expr)| expr.as_ref());
+
+        self.match_ident(&pattern.register, &target.register) && self.match_expr_opt(pattern_value, target_value)
+    }
+
+    fn match_asm_instruction(&mut self, pattern: &(Instruction, SemicolonToken), target: &(Instruction, SemicolonToken)) -> bool {
+        let (pattern, target) = (&pattern.0, &target.0);
+
+        if pattern.op_code_ident().as_str() != target.op_code_ident().as_str() {
+            return false;
+        }
+
+        let pattern_registers = pattern.register_arg_idents();
+        let target_registers = target.register_arg_idents();
+
+        let immediate_matches = |m: &mut Self| match (pattern.immediate_ident_opt(), target.immediate_ident_opt()) {
+            (None, None) => true,
+            (Some(pattern), Some(target)) => m.match_ident(&pattern, &target),
+            _ => false,
+        };
+
+        pattern_registers.len() == target_registers.len()
+            && pattern_registers.iter().zip(target_registers.iter()).all(|(pattern, target)| self.match_ident(pattern, target))
+            && immediate_matches(self)
+    }
+
+    fn match_asm_block(&mut self, pattern: &AsmBlock, target: &AsmBlock) -> bool {
+        fn instructions(block: &AsmBlock) -> Vec<&(Instruction, SemicolonToken)> {
+            block.contents.inner.instructions.iter().collect()
+        }
+
+        let final_expr_matches = |m: &mut Self| match (pattern.contents.inner.final_expr_opt.as_ref(), target.contents.inner.final_expr_opt.as_ref()) {
+            (None, None) => true,
+            (Some(pattern), _) if pattern.register.as_str() == ELLIPSIS => true,
+            (Some(pattern), Some(target)) => m.match_ident(&pattern.register, &target.register),
+            _ => false,
+        };
+
+        self.match_punctuated(&pattern.registers.inner, &target.registers.inner, is_ellipsis_register, Self::match_asm_register)
+            && self.match_sequence(&instructions(pattern), &instructions(target), is_ellipsis_instruction, Self::match_asm_instruction)
+            && final_expr_matches(self)
+    }
+
+    fn match_assignable(&mut self, pattern: &Assignable, target: &Assignable) -> bool {
+        match (pattern, target) {
+            (Assignable::Var(pattern), Assignable::Var(target)) => self.match_ident(pattern, target),
+
+            (Assignable::Index { target: pattern_target, arg: pattern_arg }, Assignable::Index { target: target_target, arg: target_arg }) => {
+                self.match_assignable(pattern_target, target_target) && self.match_expr(&pattern_arg.inner, &target_arg.inner)
+            }
+
+            (Assignable::FieldProjection { target: pattern_target, name: pattern_name, .. }, Assignable::FieldProjection { target: target_target, name: target_name, .. }) => {
+                self.match_assignable(pattern_target, target_target) && self.match_ident(pattern_name, target_name)
+            }
+
+            (Assignable::TupleFieldProjection { target: pattern_target, field: pattern_field, .. }, Assignable::TupleFieldProjection { target: target_target, field: target_field, .. }) => {
+                pattern_field == target_field && self.match_assignable(pattern_target, target_target)
+            }
+
+            _ => false,
+        }
+    }
+
+    fn match_expr(&mut self, pattern: &Expr, target: &Expr) -> bool {
+        // Wildcards outside of sequences match anything, i.e: the final expression of a block
+        if is_ellipsis_expr(pattern) {
+            return true;
+        }
+
+        if let Some(name) = expr_variable_name(pattern) {
+            return self.bind(name, target.span());
+        }
+
+        if std::mem::discriminant(pattern) != std::mem::discriminant(target) {
+            return false;
+        }
+
+        if let (Some((pattern_lhs, pattern_rhs)), Some((target_lhs, target_rhs))) = (binary_operands(pattern), binary_operands(target)) {
+            return self.match_expr(pattern_lhs, target_lhs) && self.match_expr(pattern_rhs, target_rhs);
+        }
+
+        match (pattern, target) {
+            (Expr::Path(pattern), Expr::Path(target)) => self.match_path(pattern, target),
+
+            (Expr::Literal(pattern), Expr::Literal(target)) => tokens_eq(pattern.span().as_str(), target.span().as_str()),
+
+            (Expr::AbiCast { args: pattern, .. }, Expr::AbiCast { args: target, .. }) => {
+                tokens_eq(pattern.inner.name.span().as_str(), target.inner.name.span().as_str())
+                    && self.match_expr(&pattern.inner.address, &target.inner.address)
+            }
+
+            (Expr::Struct { path: pattern_path, fields: pattern_fields }, Expr::Struct { path: target_path, fields: target_fields }) => {
+                self.match_path(pattern_path, target_path)
+                    && self.match_punctuated(&pattern_fields.inner, &target_fields.inner, is_ellipsis_field, Self::match_struct_field)
+            }
+
+            (Expr::Tuple(pattern), Expr::Tuple(target)) => {
+                self.match_sequence(&tuple_elements(&pattern.inner), &tuple_elements(&target.inner), is_ellipsis_expr, Self::match_expr)
+            }
+
+            (Expr::Parens(pattern), Expr::Parens(target)) => self.match_expr(&pattern.inner, &target.inner),
+
+            (Expr::Block(pattern), Expr::Block(target)) => self.match_block(&pattern.inner, &target.inner),
+
+            (Expr::Array(pattern), Expr::Array(target)) => match (&pattern.inner, &target.inner) {
+                (ExprArrayDescriptor::Sequence(pattern), ExprArrayDescriptor::Sequence(target)) => {
+                    self.match_punctuated(pattern, target, is_ellipsis_expr, Self::match_expr)
+                }
+
+                (ExprArrayDescriptor::Repeat { value: pattern_value, length: pattern_length, .. }, ExprArrayDescriptor::Repeat { value: target_value, length: target_length, .. }) => {
+                    self.match_expr(pattern_value, target_value) && self.match_expr(pattern_length, target_length)
+                }
+
+                _ => false,
+            },
+
+            (Expr::Asm(pattern), Expr::Asm(target)) => self.match_asm_block(pattern, target),
+
+            (Expr::Return { expr_opt: pattern, .. }, Expr::Return { expr_opt: target, .. }) => self.match_expr_opt(pattern.as_deref(), target.as_deref()),
+
+            (Expr::If(pattern), Expr::If(target)) => self.match_if(pattern, target),
+
+            (Expr::Match { value: pattern_value, branches: pattern_branches, .. }, Expr::Match { value: target_value, branches: target_branches, .. }) => {
+                let pattern_branches = pattern_branches.inner.iter().collect::<Vec<_>>();
+                let target_branches = target_branches.inner.iter().collect::<Vec<_>>();
+
+                self.match_expr(pattern_value, target_value)
+                    && self.match_sequence(&pattern_branches, &target_branches, |_| false, Self::match_match_branch)
+            }
+
+            (Expr::While { condition: pattern_condition, block: pattern_block, .. }, Expr::While { condition: target_condition, block: target_block, .. }) => {
+                self.match_expr(pattern_condition, target_condition) && self.match_block(&pattern_block.inner, &target_block.inner)
+            }
+
+            (Expr::FuncApp { func: pattern_func, args: pattern_args }, Expr::FuncApp { func: target_func, args: target_args }) => {
+                self.match_expr(pattern_func, target_func)
+                    && self.match_punctuated(&pattern_args.inner, &target_args.inner, is_ellipsis_expr, Self::match_expr)
+            }
+
+            (Expr::Index { target: pattern_target, arg: pattern_arg }, Expr::Index { target: target_target, arg: target_arg }) => {
+                self.match_expr(pattern_target, target_target) && self.match_expr(&pattern_arg.inner, &target_arg.inner)
+            }
+
+            (
+                Expr::MethodCall { target: pattern_target, path_seg: pattern_path_seg, contract_args_opt: pattern_contract_args, args: pattern_args, .. },
+                Expr::MethodCall { target: target_target, path_seg: target_path_seg, contract_args_opt: target_contract_args, args: target_args, .. },
+            ) => {
+                let contract_args_match = |m: &mut Self| match (pattern_contract_args.as_ref(), target_contract_args.as_ref()) {
+                    (None, None) => true,
+                    (Some(pattern), Some(target)) => m.match_punctuated(&pattern.inner, &target.inner, is_ellipsis_field, Self::match_struct_field),
+                    _ => false,
+                };
+
+                self.match_expr(pattern_target, target_target)
+                    && self.match_path_segment(pattern_path_seg, target_path_seg)
+                    && contract_args_match(self)
+                    && self.match_punctuated(&pattern_args.inner, &target_args.inner, is_ellipsis_expr, Self::match_expr)
+            }
+
+            (Expr::FieldProjection { target: pattern_target, name: pattern_name, .. }, Expr::FieldProjection { target: target_target, name: target_name, .. }) => {
+                self.match_expr(pattern_target, target_target) && self.match_ident(pattern_name, target_name)
+            }
+
+            (Expr::TupleFieldProjection { target: pattern_target, field: pattern_field, .. }, Expr::TupleFieldProjection { target: target_target, field: target_field, .. }) => {
+                pattern_field == target_field && self.match_expr(pattern_target, target_target)
+            }
+
+            (Expr::Ref { expr: pattern, .. }, Expr::Ref { expr: target, .. }) |
+            (Expr::Deref { expr: pattern, .. }, Expr::Deref { expr: target, .. }) |
+            (Expr::Not { expr: pattern, .. }, Expr::Not { expr: target, .. }) => self.match_expr(pattern, target),
+
+            (
+                Expr::Reassignment { assignable: pattern_assignable, reassignment_op: pattern_op, expr: pattern_expr },
+                Expr::Reassignment { assignable: target_assignable, reassignment_op: target_op, expr: target_expr },
+            ) => {
+                pattern_op.span.as_str() == target_op.span.as_str()
+                    && self.match_assignable(pattern_assignable, target_assignable)
+                    && self.match_expr(pattern_expr, target_expr)
+            }
+
+            (Expr::Break { .. }, Expr::Break { .. }) | (Expr::Continue { .. }, Expr::Continue { .. }) => true,
+
+            _ => false,
+        }
+    }
+}
+
+/// Writes each query match in the supplied `project` with `context_lines` lines of surrounding source code.
+pub fn write_query_matches<W: Write>(project: &Project, matches: &[QueryMatch], context_lines: usize, output: &mut W) -> Result<(), Error> {
+    let io_error = |e: std::io::Error| Error::Wrapped(Box::new(e));
+
+    for query_match in matches.iter() {
+        let path = query_match.path.as_path();
+        let (start_line, end_line) = (query_match.line, query_match.end_line);
+
+        writeln!(output, "{}:{start_line}:{}", path.to_string_lossy(), query_match.column).map_err(io_error)?;
+
+        let first_line = start_line.saturating_sub(context_lines).max(1);
+        let last_line = end_line + context_lines;
+
+        for line in first_line..=last_line {
+            let Some(source_line) = project.get_source_line(path, line) else { break };
+            let marker = if (start_line..=end_line).contains(&line) { '>' } else { ' ' };
+            writeln!(output, "{marker}{line:>5} | {source_line}").map_err(io_error)?;
+        }
+
+        for (name, text) in query_match.bindings.iter() {
+            writeln!(output, "\t${name} = {text}").map_err(io_error)?;
+        }
+
+        writeln!(output).map_err(io_error)?;
+    }
+
+    writeln!(output, "{} {} found.", matches.len(), if matches.len() == 1 { "match" } else { "matches" }).map_err(io_error)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Options;
+
+    fn query(directory: &str, pattern: &str) -> (Project<'static>, Vec<QueryMatch>) {
+        let options = Options {
+            directory: Some(directory.into()),
+            detectors: vec!["none".to_string()],
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        let matches = project.query_matches(&Query::parse(pattern).unwrap()).unwrap();
+
+        (project, matches)
+    }
+
+    #[test]
+    fn test_query() {
+        let (project, matches) = query("test/arbitrary_code_execution/", "asm(...) { ... ldc $a $b $c; ... }");
+        assert_eq!(matches.len(), 7);
+        assert_eq!(matches[0].bindings, vec![("a".to_string(), "r1".to_string()), ("b".to_string(), "r2".to_string()), ("c".to_string(), "r3".to_string())]);
+
+        let mut output = vec![];
+        write_query_matches(&project, &matches[..1], 1, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("test/arbitrary_code_execution/src/main.sw:23:9\n"));
+        assert!(output.contains(">   26 |             ldc r1 r2 r3;"));
+
+        let (_, matches) = query("test/rules/", "storage.$field.write($value)");
+        let bindings = matches.iter().map(|x| x.bindings.clone()).collect::<Vec<_>>();
+        assert_eq!(bindings.len(), 4);
+        assert!(bindings.contains(&vec![("field".to_string(), "owner".to_string()), ("value".to_string(), "owner".to_string())]));
+
+        let (_, matches) = query("test/rules/", "mint_to($_, ...)");
+        assert_eq!(matches.len(), 2);
+
+        let (_, matches) = query("test/rules/", "storage.$x.write(storage.$x.read() + $_)");
+        assert_eq!(matches.len(), 2);
+
+        assert!(Query::parse("storage.owner.write(").is_err());
+    }
+}
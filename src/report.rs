@@ -1,9 +1,11 @@
-use crate::error::Error;
+use crate::{error::Error, triage::TriageEntry};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
+    collections::HashMap,
     path::{Path, PathBuf},
+    rc::Rc,
     str::FromStr,
 };
 
@@ -33,6 +35,30 @@ pub enum Severity {
     Low,
 }
 
+impl FromStr for Severity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "high" => Ok(Self::High),
+            "medium" => Ok(Self::Medium),
+            "low" => Ok(Self::Low),
+            _ => Err(Error::InvalidSeverity(s.to_string())),
+        }
+    }
+}
+
+impl Severity {
+    /// Gets the emoji used to represent the severity in Markdown output.
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Severity::High => "🔴",
+            Severity::Medium => "🟡",
+            Severity::Low => "🟢",
+        }
+    }
+}
+
 impl Display for Severity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -41,21 +67,30 @@ impl Display for Severity {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Entry {
+    pub fingerprint: String,
+    pub detector: String,
     pub line: Option<usize>,
     pub severity: Severity,
     pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage: Option<TriageEntry>,
 }
 
 impl Display for Entry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let line = format!(
-            "{}{}",
+            "{}{}{}",
             if let Some(line) = self.line.as_ref() {
                 format!("L{}: ", line)
             } else {
                 String::new()
             },
             self.text,
+            if let Some(triage) = self.triage.as_ref() {
+                format!(" [{triage}]")
+            } else {
+                String::new()
+            },
         );
 
         let output = match self.severity {
@@ -72,9 +107,13 @@ impl Display for Entry {
 pub struct Report {
     pub sorting: Sorting,
     pub entries: Vec<(PathBuf, Vec<Entry>)>,
+    /// The name of the detector currently being run, which new entries are attributed to.
+    #[serde(skip)]
+    pub detector: Option<Rc<str>>,
 }
 
 impl Report {
+    /// Adds an entry attributed to the detector currently being run.
     pub fn add_entry<P: Into<PathBuf>, S: Into<String>>(
         &mut self,
         file: P,
@@ -95,18 +134,84 @@ impl Report {
             .find(|(path, _)| file.eq(path))
             .unwrap();
 
+        let detector = self.detector.as_deref().unwrap_or_default().to_string();
+        let text = text.into();
+
+        // Skip duplicate entries
+        if file_entry.1.iter().any(|entry| entry.line == line && entry.detector == detector && normalize_whitespace(&entry.text) == normalize_whitespace(&text)) {
+            return;
+        }
+
+        // The fingerprint is assigned once all entries have been added, see `Report::assign_fingerprints`
         file_entry.1.push(Entry {
+            fingerprint: String::new(),
+            detector,
             line,
             severity,
-            text: text.into(),
+            text,
+            triage: None,
         });
 
+        // Break ties by detector and text so the order does not depend on the order entries were added in
         match self.sorting {
-            Sorting::Line => file_entry.1.sort_unstable_by_key(|x| (x.line, x.severity)),
-            Sorting::Severity => file_entry.1.sort_unstable_by_key(|x| (x.severity, x.line)),
+            Sorting::Line => file_entry.1.sort_unstable_by(|a, b| (a.line, a.severity, &a.detector, &a.text).cmp(&(b.line, b.severity, &b.detector, &b.text))),
+            Sorting::Severity => file_entry.1.sort_unstable_by(|a, b| (a.severity, a.line, &a.detector, &a.text).cmp(&(b.severity, b.line, &b.detector, &b.text))),
+        }
+    }
+
+    /// Assigns each entry a fingerprint of its detector, the `relative_path` of its file, its text (which contains the item path and expression)
+    /// and the `source_line` it was reported on, all normalized to ignore whitespace.
+    ///
+    /// Line numbers are left out so the fingerprint remains stable when unrelated code is added or removed,
+    /// and only entries identical in every part are disambiguated by their order.
+    pub fn assign_fingerprints<R, L>(&mut self, mut relative_path: R, mut source_line: L)
+    where
+        R: FnMut(&Path) -> String,
+        L: FnMut(&Path, usize) -> Option<String>,
+    {
+        for (path, entries) in self.entries.iter_mut() {
+            let relative_path = relative_path(path);
+            let mut occurrences: HashMap<String, usize> = HashMap::new();
+
+            let mut indices = (0..entries.len()).collect::<Vec<_>>();
+            indices.sort_by_key(|&i| entries[i].line);
+
+            for i in indices {
+                let entry = &mut entries[i];
+
+                let source_line = entry.line
+                    .and_then(|line| source_line(path, line))
+                    .map(|x| normalize_whitespace(&x))
+                    .unwrap_or_default();
+
+                let base_fingerprint = fingerprint(&[
+                    entry.detector.as_str(),
+                    relative_path.as_str(),
+                    normalize_whitespace(&entry.text).as_str(),
+                    source_line.as_str(),
+                ]);
+
+                let occurrence = occurrences.entry(base_fingerprint.clone()).or_default();
+                *occurrence += 1;
+
+                entry.fingerprint = if *occurrence == 1 {
+                    base_fingerprint
+                } else {
+                    format!("{base_fingerprint}-{occurrence}")
+                };
+            }
         }
     }
 
+    /// Retains only the entries for which the supplied predicate `f` returns `true`, removing any files left without entries.
+    pub fn retain<F: FnMut(&Path, &Entry) -> bool>(&mut self, mut f: F) {
+        for (path, entries) in self.entries.iter_mut() {
+            entries.retain(|entry| f(path, entry));
+        }
+
+        self.entries.retain(|(_, entries)| !entries.is_empty());
+    }
+
     pub fn entry_count<P: AsRef<Path>>(&self, path: P) -> usize {
         self.entries.iter()
             .find(|(entry_path, _)| entry_path == path.as_ref())
@@ -115,6 +220,32 @@ impl Report {
     }
 }
 
+/// Computes a stable hexadecimal fingerprint of the supplied `parts` using the 64-bit FNV-1a hash.
+///
+/// Unlike `std::hash::DefaultHasher`, the result is guaranteed to be identical across runs and Rust versions.
+pub fn fingerprint(parts: &[&str]) -> String {
+    let mut hash = 0xcbf29ce484222325u64;
+
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            hash ^= 0xff;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        for byte in part.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    format!("{hash:016x}")
+}
+
+/// Collapses each run of whitespace in the supplied `text` into a single space.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 impl Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (i, (path, entries)) in self.entries.iter().enumerate() {
@@ -132,3 +263,47 @@ impl Display for Report {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_fingerprints() {
+        let mut report = Report {
+            detector: Some("magic_number".into()),
+            ..Default::default()
+        };
+
+        report.add_entry("src/main.sw", Some(10), Severity::Low, "The `foo` function contains magic number usage: `x * 2`.");
+        report.add_entry("src/main.sw", Some(10), Severity::Low, "The `foo` function contains  magic number usage: `x * 2`.");
+        report.add_entry("src/main.sw", Some(20), Severity::Low, "The `foo` function contains magic number usage: `x * 2`.");
+        report.add_entry("src/main.sw", Some(30), Severity::Low, "The `foo` function contains magic number usage: `x * 2`.");
+
+        report.detector = Some("large_literal".into());
+        report.add_entry("src/main.sw", Some(10), Severity::Low, "The `foo` function contains magic number usage: `x * 2`.");
+
+        let mut source_lines = vec!["", "let y = x * 2;", "let y  =  x * 2;", "let z = x * 2;"];
+        let assign_fingerprints = |report: &mut Report, source_lines: &[&str]| {
+            report.assign_fingerprints(|path| path.to_string_lossy().to_string(), |_, line| source_lines.get(line / 10).map(|x| x.to_string()));
+            report.entries[0].1.iter().map(|x| (x.detector.clone(), x.line, x.fingerprint.clone())).collect::<Vec<_>>()
+        };
+
+        let fingerprints = assign_fingerprints(&mut report, &source_lines);
+        assert_eq!(fingerprints.len(), 4);
+
+        // Identical entries on identical source lines are disambiguated by their order
+        assert_eq!(fingerprints[2].2, format!("{}-2", fingerprints[1].2));
+        assert_ne!(fingerprints[0].2, fingerprints[1].2);
+
+        // Entries on different source lines do not depend on each other
+        assert!(!fingerprints[3].2.contains('-'));
+        assert_eq!(fingerprints[0].0, "large_literal");
+
+        // Changing one source line does not change the fingerprints of entries on other lines
+        source_lines[1] = "let y = x * 3;";
+        let changed_fingerprints = assign_fingerprints(&mut report, &source_lines);
+        assert_ne!(changed_fingerprints[1].2, fingerprints[1].2);
+        assert_eq!(changed_fingerprints[3].2, fingerprints[3].2);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, UseContext},
+};
+use serde::{Deserialize, Deserializer};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_ast::{Expr, ItemImpl};
+use sway_types::{Span, Spanned};
+
+/// A file containing declarative rules, i.e:
+///
+/// ```toml
+/// [[rule]]
+/// name = "mint_outside_mint_fn"
+/// severity = "high"
+/// message = "{location} calls `std::asset::mint_to` outside of `fn mint`."
+///
+/// [rule.match]
+/// kind = "call"
+/// path = "std::asset::mint_to"
+///
+/// [rule.within]
+/// not_fn_names = ["mint"]
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleFile {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<Rule>,
+}
+
+impl RuleFile {
+    /// Attempts to load the rules from the TOML file at the supplied `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        toml::from_str(&source).map_err(|e| Error::InvalidRule(path.into(), e.message().to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    /// The name of the rule, which is used as the detector name of its report entries.
+    pub name: String,
+
+    #[serde(deserialize_with = "deserialize_severity")]
+    pub severity: Severity,
+
+    /// The report entry text. Can contain `{location}`, `{fn}`, `{expr}` and `{field}` placeholders.
+    pub message: String,
+
+    /// The AST pattern to report.
+    #[serde(rename = "match")]
+    pub pattern: RulePattern,
+
+    /// The constraints on the function containing the pattern.
+    #[serde(default)]
+    pub within: RuleConstraints,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RulePattern {
+    /// A call to the function with the resolved `path`.
+    Call { path: String },
+
+    /// A read from the storage field named `field`, or any storage field.
+    StorageRead { field: Option<String> },
+
+    /// A write to the storage field named `field`, or any storage field.
+    StorageWrite { field: Option<String> },
+
+    /// The function itself.
+    Fn,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleConstraints {
+    /// The kind of item containing the function.
+    pub item: Option<RuleItemKind>,
+
+    /// The function must have one of the names.
+    #[serde(default)]
+    pub fn_names: Vec<String>,
+
+    /// The function must not have any of the names.
+    #[serde(default)]
+    pub not_fn_names: Vec<String>,
+
+    /// The function must have the attribute, i.e: `payable` or `storage(write)`.
+    pub attribute: Option<String>,
+
+    /// The function must not have the attribute, i.e: `payable` or `storage(write)`.
+    pub not_attribute: Option<String>,
+
+    /// The function must call the function with the resolved path.
+    pub calls: Option<String>,
+
+    /// The function must not call the function with the resolved path.
+    pub not_calls: Option<String>,
+
+    /// Whether the function must (or must not) write to storage.
+    pub writes_storage: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleItemKind {
+    /// A function in an `impl ... for Contract` block.
+    AbiImpl,
+
+    /// A function in any `impl` block.
+    Impl,
+
+    /// A free function.
+    Fn,
+}
+
+fn deserialize_severity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Severity, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    Severity::from_str(&s).map_err(serde::de::Error::custom)
+}
+
+pub struct RuleVisitor {
+    rule: Rule,
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    imports: Vec<(String, String)>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    calls: Vec<(String, Span)>,
+    storage_reads: Vec<(String, Span)>,
+    storage_writes: Vec<(String, Span)>,
+}
+
+impl RuleVisitor {
+    pub fn new(rule: Rule) -> Self {
+        Self {
+            rule,
+            module_states: HashMap::new(),
+        }
+    }
+}
+
+impl AstVisitor for RuleVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        self.module_states.entry(context.path.into()).or_default();
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Collect the imported symbols in order to resolve paths
+        module_state.imports.extend(utils::fold_use_tree_imports(&context.item_use.tree, ""));
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        module_state.fn_states.entry(context.item_fn.fn_signature.span()).or_default();
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let Some(fn_state) = module_state.fn_states.get_mut(&item_fn.fn_signature.span()) else { return Ok(()) };
+
+        match context.expr {
+            // Collect function calls by their resolved path
+            Expr::FuncApp { func, .. } => {
+                let Expr::Path(path) = func.as_ref() else { return Ok(()) };
+                let resolved_path = utils::resolve_path_expr(path, &module_state.imports);
+                fn_state.calls.push((resolved_path, context.expr.span()));
+            }
+
+            // Collect storage accesses
+            Expr::MethodCall { .. } => {
+                let idents = utils::fold_expr_idents(context.expr);
+
+                if idents.len() < 3 || idents[0].as_str() != "storage" {
+                    return Ok(());
+                }
+
+                let span = context.expr.span();
+
+                // Only record the outermost method call of a storage access chain
+                if fn_state.storage_reads.iter().chain(fn_state.storage_writes.iter()).any(|(_, x)| x.start() == span.start()) {
+                    return Ok(());
+                }
+
+                let field = idents[1].as_str().to_string();
+
+                if idents[2..].iter().any(|x| utils::is_storage_write_fn_name(x.as_str())) {
+                    fn_state.storage_writes.push((field, span));
+                } else {
+                    fn_state.storage_reads.push((field, span));
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_state = module_state.fn_states.get(&context.item_fn.fn_signature.span()).unwrap();
+
+        if !self.check_constraints(context, fn_state) {
+            return Ok(());
+        }
+
+        let fn_name = context.item_fn.fn_signature.name.as_str();
+
+        // Collect the spans of the matching pattern along with their `{expr}` and `{field}` values
+        let matches: Vec<(Span, String, String)> = match &self.rule.pattern {
+            RulePattern::Call { path } => fn_state.calls.iter()
+                .filter(|(resolved_path, _)| is_path_match(resolved_path, path))
+                .map(|(_, span)| (span.clone(), span.as_str().to_string(), String::new()))
+                .collect(),
+
+            RulePattern::StorageRead { field } => fn_state.storage_reads.iter()
+                .filter(|(name, _)| field.as_ref().map(|x| x == name).unwrap_or(true))
+                .map(|(name, span)| (span.clone(), span.as_str().to_string(), name.clone()))
+                .collect(),
+
+            RulePattern::StorageWrite { field } => fn_state.storage_writes.iter()
+                .filter(|(name, _)| field.as_ref().map(|x| x == name).unwrap_or(true))
+                .map(|(name, span)| (span.clone(), span.as_str().to_string(), name.clone()))
+                .collect(),
+
+            RulePattern::Fn => vec![(context.item_fn.fn_signature.name.span(), fn_name.to_string(), String::new())],
+        };
+
+        let location = utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn));
+
+        for (span, expr, field) in matches {
+            let text = self.rule.message
+                .replace("{location}", &location)
+                .replace("{fn}", fn_name)
+                .replace("{expr}", &expr)
+                .replace("{field}", &field);
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &span)?,
+                self.rule.severity,
+                text,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl RuleVisitor {
+    /// Checks if the function in the supplied `context` satisfies the constraints of the rule.
+    fn check_constraints(&self, context: &FnContext, fn_state: &FnState) -> bool {
+        let constraints = &self.rule.within;
+        let fn_name = context.item_fn.fn_signature.name.as_str();
+
+        if let Some(item) = constraints.item {
+            let is_match = match item {
+                RuleItemKind::AbiImpl => context.item_impl.map(is_contract_impl).unwrap_or(false),
+                RuleItemKind::Impl => context.item_impl.is_some(),
+                RuleItemKind::Fn => context.item_impl.is_none(),
+            };
+
+            if !is_match {
+                return false;
+            }
+        }
+
+        if !constraints.fn_names.is_empty() && !constraints.fn_names.iter().any(|x| x == fn_name) {
+            return false;
+        }
+
+        if constraints.not_fn_names.iter().any(|x| x == fn_name) {
+            return false;
+        }
+
+        if let Some(attribute) = constraints.attribute.as_ref() {
+            if !has_attribute(context, attribute) {
+                return false;
+            }
+        }
+
+        if let Some(attribute) = constraints.not_attribute.as_ref() {
+            if has_attribute(context, attribute) {
+                return false;
+            }
+        }
+
+        if let Some(path) = constraints.calls.as_ref() {
+            if !fn_state.calls.iter().any(|(x, _)| is_path_match(x, path)) {
+                return false;
+            }
+        }
+
+        if let Some(path) = constraints.not_calls.as_ref() {
+            if fn_state.calls.iter().any(|(x, _)| is_path_match(x, path)) {
+                return false;
+            }
+        }
+
+        if let Some(writes_storage) = constraints.writes_storage {
+            if writes_storage == fn_state.storage_writes.is_empty() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Checks if the supplied `item_impl` is an ABI implementation for `Contract`.
+fn is_contract_impl(item_impl: &ItemImpl) -> bool {
+    item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() == "Contract"
+}
+
+/// Checks if the function in the supplied `context` has the `attribute`, i.e: `payable` or `storage(write)`.
+fn has_attribute(context: &FnContext, attribute: &str) -> bool {
+    let (name, args) = match attribute.split_once('(') {
+        Some((name, args)) => (name.trim(), args.trim_end_matches(')').split(',').map(str::trim).filter(|x| !x.is_empty()).collect()),
+        None => (attribute.trim(), vec![]),
+    };
+
+    utils::check_attribute_decls(context.fn_attributes, name, args.as_slice())
+}
+
+/// Checks if the `resolved_path` of a call matches the `path` of a rule.
+///
+/// Paths without a module prefix (i.e: `only_owner`) match calls to a function with the same name in any module.
+fn is_path_match(resolved_path: &str, path: &str) -> bool {
+    if resolved_path == path {
+        return true;
+    }
+
+    !path.contains("::") && resolved_path.rsplit("::").next() == Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{project::Project, Options};
+
+    #[test]
+    fn test_rules() {
+        let options = Options {
+            directory: Some("test/rules/".into()),
+            detectors: vec!["mint_outside_mint_fn".to_string(), "abi_storage_write_without_only_owner".to_string()],
+            rules: vec!["test/rules/rules.toml".into()],
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        let report = project.report.borrow();
+        let entries = report.entries.iter().flat_map(|(_, entries)| entries.iter()).collect::<Vec<_>>();
+
+        let lines = |detector: &str| entries.iter().filter(|x| x.detector == detector).filter_map(|x| x.line).collect::<Vec<_>>();
+        assert_eq!(lines("mint_outside_mint_fn"), vec![27, 31]);
+        assert_eq!(lines("abi_storage_write_without_only_owner"), vec![57]);
+    }
+}
@@ -0,0 +1,754 @@
+use crate::{
+    types::{Type, TypeInference},
+    utils::{self, expr_key, span_key, ExprKey},
+};
+use std::collections::{BTreeSet, HashMap};
+use sway_ast::{
+    expr::LoopControlFlow, CodeBlockContents, Expr, ExprArrayDescriptor, ExprTupleDescriptor,
+    IfCondition, IfExpr, ItemFn, ItemImplItem, ItemKind, MatchBranchKind, Module, Pattern,
+    Statement,
+};
+use sway_types::{Span, Spanned};
+
+/// The kind of a storage field, derived from its declared type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageKind {
+    Value,
+    Map,
+    Vec,
+    String,
+    Bytes,
+}
+
+impl StorageKind {
+    fn from_type(ty: &Type) -> Self {
+        match ty.name() {
+            Some("StorageMap") => StorageKind::Map,
+            Some("StorageVec") => StorageKind::Vec,
+            Some("StorageString") => StorageKind::String,
+            Some("StorageBytes") => StorageKind::Bytes,
+            _ => StorageKind::Value,
+        }
+    }
+}
+
+/// A field declared in a `storage` block.
+#[derive(Clone, Debug)]
+pub struct StorageField {
+    /// The name of the field, prefixed with its namespaces, i.e: `balances` or `ns::balances`.
+    pub name: String,
+    pub ty: Type,
+    pub span: Span,
+}
+
+impl StorageField {
+    pub fn kind(&self) -> StorageKind {
+        StorageKind::from_type(&self.ty)
+    }
+
+    /// Gets the types of the keys needed to reach a value of the field, unwrapping nested maps and vectors.
+    pub fn key_types(&self) -> Vec<Type> {
+        let mut result = vec![];
+        let mut ty = &self.ty;
+
+        loop {
+            match (StorageKind::from_type(ty), ty.generic_args()) {
+                (StorageKind::Map, [key, value]) => {
+                    result.push(key.clone());
+                    ty = value;
+                }
+
+                (StorageKind::Vec, [value]) => {
+                    result.push(Type::named("u64"));
+                    ty = value;
+                }
+
+                _ => break result,
+            }
+        }
+    }
+
+    /// Gets the type of the values stored in the field, unwrapping nested maps and vectors.
+    pub fn value_type(&self) -> Type {
+        let mut ty = &self.ty;
+
+        loop {
+            match (StorageKind::from_type(ty), ty.generic_args()) {
+                (StorageKind::Map, [_, value]) | (StorageKind::Vec, [value]) => ty = value,
+                _ => break ty.clone(),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
+/// An access of a storage field through a method call, i.e: `storage.balances.get(id).read()`.
+#[derive(Clone, Debug)]
+pub struct StorageAccess {
+    pub field: String,
+    pub kind: StorageAccessKind,
+    pub method: String,
+    pub span: Span,
+}
+
+/// The storage fields a function reads and writes, including through the functions it calls.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageSummary {
+    pub reads: BTreeSet<String>,
+    pub writes: BTreeSet<String>,
+}
+
+fn is_storage_read_fn_name(s: &str) -> bool {
+    matches!(s, "read" | "try_read" | "len" | "is_empty" | "first" | "last" | "read_slice" | "load_vec")
+}
+
+/// Methods which derive a storage key or unwrap one without accessing storage.
+fn is_storage_key_fn_name(s: &str) -> bool {
+    matches!(s, "get" | "unwrap")
+}
+
+/// A function called by another function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Callee {
+    /// A function declared in the analyzed modules, with its name.
+    Internal(ExprKey, String),
+    /// A method of another contract called through its ABI, with the names of the ABI and the method, i.e: `abi(Token, id).transfer()`.
+    External(String, String),
+}
+
+/// A call made by a function, resolved to a callee once every function has been collected.
+enum Call {
+    Fn(String),
+    /// A method in the same `impl` block as the caller, i.e: `Self::f()` or `self.f()`.
+    Method(String),
+    /// A method of a type declared in the same file, i.e: `Type::f()` or `value.f()`.
+    TypeMethod(String, String),
+    External(String, String),
+}
+
+/// What a local variable is known to be bound to.
+#[derive(Clone, Default)]
+struct Binding {
+    /// The storage key the variable is bound to, i.e: `let balance = storage.balances.get(id);`
+    field: Option<String>,
+    /// The ABI the variable is cast to, i.e: `let token = abi(Token, id);`
+    abi: Option<String>,
+}
+
+struct FnState<'a> {
+    types: &'a TypeInference,
+    /// A stack of block scopes mapping local variables to their bindings.
+    scopes: Vec<HashMap<String, Binding>>,
+    accesses: Vec<StorageAccess>,
+    calls: Vec<Call>,
+}
+
+impl FnState<'_> {
+    /// Gets the binding of the innermost variable called `name`, if any.
+    fn binding(&self, name: &str) -> Option<&Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    /// Binds the variables in the supplied `pattern` in the innermost scope, keeping the `binding` of single variable bindings.
+    fn bind_pattern(&mut self, pattern: &Pattern, binding: Binding) {
+        let scope = self.scopes.last_mut().unwrap();
+
+        match pattern {
+            Pattern::AmbiguousSingleIdent(name) | Pattern::Var { name, .. } => {
+                scope.insert(name.as_str().to_string(), binding);
+            }
+
+            pattern => {
+                for ident in utils::fold_pattern_idents(pattern) {
+                    scope.insert(ident.as_str().to_string(), Binding::default());
+                }
+            }
+        }
+    }
+
+    /// Gets the binding of the supplied `expr` if it is a plain local variable path.
+    fn expr_binding(&self, expr: &Expr) -> Option<&Binding> {
+        match expr {
+            Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() => self.binding(path.prefix.name.as_str()),
+            Expr::Parens(expr) => self.expr_binding(&expr.inner),
+            _ => None,
+        }
+    }
+}
+
+/// A model of the storage fields declared in a set of modules, the accesses made to them by each function and the calls between functions.
+#[derive(Default)]
+pub struct StorageModel {
+    fields: Vec<StorageField>,
+    expr_accesses: HashMap<ExprKey, StorageAccess>,
+    fn_accesses: HashMap<ExprKey, Vec<StorageAccess>>,
+    fn_summaries: HashMap<ExprKey, StorageSummary>,
+    fn_callees: HashMap<ExprKey, Vec<Callee>>,
+}
+
+impl StorageModel {
+    /// Collects the storage fields, accesses and calls in the supplied `modules`.
+    pub fn new<'a, I: IntoIterator<Item = &'a Module>>(modules: I, types: &TypeInference) -> Self {
+        let mut result = Self::default();
+
+        let mut fn_calls = HashMap::new();
+        let mut fn_names = HashMap::new();
+        let mut method_names = HashMap::new();
+        let mut type_method_names = HashMap::new();
+
+        for module in modules {
+            for item in module.items.iter() {
+                match &item.value {
+                    ItemKind::Storage(item_storage) => {
+                        for field in (&item_storage.fields.inner).into_iter() {
+                            result.fields.push(StorageField {
+                                name: field.value.name.as_str().to_string(),
+                                ty: Type::from_ty(&field.value.ty),
+                                span: field.value.name.span(),
+                            });
+                        }
+                    }
+
+                    ItemKind::Fn(item_fn) => {
+                        let key = result.add_fn(item_fn, types, &mut fn_calls);
+                        fn_names.insert((key.0, item_fn.fn_signature.name.as_str().to_string()), key);
+                    }
+
+                    ItemKind::Impl(item_impl) => {
+                        let impl_key = span_key(&item_impl.span());
+                        let type_name = utils::base_type_name(item_impl.ty.span().as_str()).to_string();
+
+                        for item in item_impl.contents.inner.iter() {
+                            if let ItemImplItem::Fn(item_fn) = &item.value {
+                                let key = result.add_fn(item_fn, types, &mut fn_calls);
+                                let name = item_fn.fn_signature.name.as_str().to_string();
+                                method_names.insert((impl_key, name.clone()), key);
+                                type_method_names.insert((key.0, type_name.clone(), name), key);
+                                fn_calls.get_mut(&key).unwrap().1 = Some(impl_key);
+                            }
+                        }
+                    }
+
+                    ItemKind::Abi(item_abi) => {
+                        for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                            result.add_fn(&item.value, types, &mut fn_calls);
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        // Resolve the calls of each function to the functions they call
+        for (key, (calls, impl_key)) in fn_calls {
+            let callees = calls.into_iter()
+                .filter_map(|call| match call {
+                    Call::Fn(name) => fn_names.get(&(key.0, name.clone())).map(|callee| Callee::Internal(*callee, name)),
+                    Call::Method(name) => impl_key.and_then(|impl_key| method_names.get(&(impl_key, name.clone()))).map(|callee| Callee::Internal(*callee, name)),
+                    Call::TypeMethod(type_name, name) => type_method_names.get(&(key.0, type_name, name.clone())).map(|callee| Callee::Internal(*callee, name)),
+                    Call::External(abi_name, name) => Some(Callee::External(abi_name, name)),
+                })
+                .collect();
+
+            result.fn_callees.insert(key, callees);
+        }
+
+        // Include the accesses of called functions in the summaries of their callers
+        let mut fn_summaries = std::mem::take(&mut result.fn_summaries);
+
+        result.propagate_calls(&mut fn_summaries, |summary, callee_summary, _| {
+            summary.reads.extend(callee_summary.reads.iter().cloned());
+            summary.writes.extend(callee_summary.writes.iter().cloned());
+        });
+
+        result.fn_summaries = fn_summaries;
+        result
+    }
+
+    /// Includes the value of each called function in the `values` of its callers with `merge` until no value changes.
+    ///
+    /// The `merge` function receives the caller's value, the callee's value and the name of the callee.
+    pub fn propagate_calls<T, F>(&self, values: &mut HashMap<ExprKey, T>, mut merge: F)
+    where
+        T: Clone + PartialEq,
+        F: FnMut(&mut T, &T, &str),
+    {
+        loop {
+            let mut changed = false;
+
+            for (key, callees) in self.fn_callees.iter() {
+                let Some(mut value) = values.get(key).cloned() else { continue };
+
+                for callee in callees.iter() {
+                    let Callee::Internal(callee_key, name) = callee else { continue };
+
+                    if let Some(callee_value) = values.get(callee_key) {
+                        merge(&mut value, callee_value, name);
+                    }
+                }
+
+                if value != values[key] {
+                    values.insert(*key, value);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Gets the functions called directly in the body of the supplied `item_fn`.
+    pub fn fn_callees(&self, item_fn: &ItemFn) -> &[Callee] {
+        self.fn_callees.get(&span_key(&item_fn.fn_signature.span())).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Gets the declared storage fields.
+    pub fn fields(&self) -> &[StorageField] {
+        self.fields.as_slice()
+    }
+
+    /// Gets the storage field called `name`, prefixed with its namespaces.
+    pub fn field(&self, name: &str) -> Option<&StorageField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Gets the storage fields declared in the supplied `module`.
+    pub fn module_fields<'a>(&'a self, module: &'a Module) -> impl Iterator<Item = &'a StorageField> {
+        let source = span_key(&module.span()).0;
+        self.fields.iter().filter(move |field| span_key(&field.span).0 == source)
+    }
+
+    /// Gets the storage access made by the supplied method call `expr`, if any.
+    pub fn access_of(&self, expr: &Expr) -> Option<&StorageAccess> {
+        self.expr_accesses.get(&expr_key(expr))
+    }
+
+    /// Gets the storage accesses made directly in the body of the supplied `item_fn`.
+    pub fn fn_accesses(&self, item_fn: &ItemFn) -> &[StorageAccess] {
+        self.fn_accesses.get(&span_key(&item_fn.fn_signature.span())).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Gets the storage fields read and written by the supplied `item_fn` and the functions it calls.
+    pub fn fn_summary(&self, item_fn: &ItemFn) -> Option<&StorageSummary> {
+        self.fn_summaries.get(&span_key(&item_fn.fn_signature.span()))
+    }
+
+    /// Checks if the supplied storage `field` is read by any function in the module declaring it.
+    pub fn is_read(&self, field: &StorageField) -> bool {
+        self.module_summaries(field).any(|summary| summary.reads.contains(&field.name))
+    }
+
+    /// Checks if the supplied storage `field` is written by any function in the module declaring it.
+    pub fn is_written(&self, field: &StorageField) -> bool {
+        self.module_summaries(field).any(|summary| summary.writes.contains(&field.name))
+    }
+
+    fn module_summaries<'a>(&'a self, field: &StorageField) -> impl Iterator<Item = &'a StorageSummary> {
+        let source = span_key(&field.span).0;
+        self.fn_summaries.iter().filter(move |(key, _)| key.0 == source).map(|(_, summary)| summary)
+    }
+
+    fn add_fn(&mut self, item_fn: &ItemFn, types: &TypeInference, fn_calls: &mut HashMap<ExprKey, (Vec<Call>, Option<ExprKey>)>) -> ExprKey {
+        let key = span_key(&item_fn.fn_signature.span());
+
+        let mut state = FnState {
+            types,
+            scopes: vec![],
+            accesses: vec![],
+            calls: vec![],
+        };
+
+        self.walk_block(&item_fn.body.inner, &mut state);
+
+        let mut summary = StorageSummary::default();
+
+        for access in state.accesses.iter() {
+            match access.kind {
+                StorageAccessKind::Read => summary.reads.insert(access.field.clone()),
+                StorageAccessKind::Write => summary.writes.insert(access.field.clone()),
+            };
+        }
+
+        self.fn_accesses.insert(key, state.accesses);
+        self.fn_summaries.insert(key, summary);
+        fn_calls.insert(key, (state.calls, None));
+
+        key
+    }
+
+    /// Resolves the storage field referred to by the supplied `expr`, i.e: `storage.x`, `storage::ns.x`, `storage.x.get(k)` or a variable bound to one.
+    fn resolve_field(expr: &Expr, state: &FnState) -> Option<String> {
+        match expr {
+            Expr::FieldProjection { target, name, .. } => {
+                let Expr::Path(path) = target.as_ref() else { return None };
+
+                if path.root_opt.is_some() || path.prefix.name.as_str() != "storage" {
+                    return None;
+                }
+
+                let mut names = path.suffix.iter().map(|(_, segment)| segment.name.as_str()).collect::<Vec<_>>();
+                names.push(name.as_str());
+
+                Some(names.join("::"))
+            }
+
+            Expr::MethodCall { target, path_seg, .. } if is_storage_key_fn_name(path_seg.name.as_str()) => Self::resolve_field(target, state),
+
+            Expr::Parens(expr) => Self::resolve_field(&expr.inner, state),
+
+            Expr::Path(_) => state.expr_binding(expr).and_then(|binding| binding.field.clone()),
+
+            _ => None,
+        }
+    }
+
+    fn walk_block(&mut self, block: &CodeBlockContents, state: &mut FnState) {
+        state.scopes.push(HashMap::new());
+
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Let(statement_let) => {
+                    self.walk_expr(&statement_let.expr, state);
+
+                    let binding = Binding {
+                        field: Self::resolve_field(&statement_let.expr, state),
+                        abi: match &statement_let.expr {
+                            Expr::AbiCast { args, .. } => Some(args.inner.name.span().as_str().to_string()),
+                            _ => None,
+                        },
+                    };
+
+                    state.bind_pattern(&statement_let.pattern, binding);
+                }
+
+                Statement::Expr { expr, .. } => self.walk_expr(expr, state),
+
+                Statement::Item(_) | Statement::Error(..) => {}
+            }
+        }
+
+        if let Some(expr) = block.final_expr_opt.as_ref() {
+            self.walk_expr(expr, state);
+        }
+
+        state.scopes.pop();
+    }
+
+    fn walk_if(&mut self, if_expr: &IfExpr, state: &mut FnState) {
+        state.scopes.push(HashMap::new());
+
+        match &if_expr.condition {
+            IfCondition::Expr(expr) => self.walk_expr(expr, state),
+
+            IfCondition::Let { lhs, rhs, .. } => {
+                self.walk_expr(rhs, state);
+                state.bind_pattern(lhs, Binding::default());
+            }
+        }
+
+        self.walk_block(&if_expr.then_block.inner, state);
+        state.scopes.pop();
+
+        match if_expr.else_opt.as_ref() {
+            Some((_, LoopControlFlow::Continue(if_expr))) => self.walk_if(if_expr, state),
+            Some((_, LoopControlFlow::Break(block))) => self.walk_block(&block.inner, state),
+            None => {}
+        }
+    }
+
+    fn walk_exprs<'e, I: IntoIterator<Item = &'e Expr>>(&mut self, exprs: I, state: &mut FnState) {
+        for expr in exprs {
+            self.walk_expr(expr, state);
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &Expr, state: &mut FnState) {
+        match expr {
+            Expr::MethodCall { target, path_seg, contract_args_opt, args, .. } => {
+                self.walk_expr(target, state);
+
+                if let Some(contract_args) = contract_args_opt.as_ref() {
+                    self.walk_exprs((&contract_args.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), state);
+                }
+
+                self.walk_exprs(&args.inner, state);
+
+                let method = path_seg.name.as_str().to_string();
+
+                let kind = if is_storage_read_fn_name(&method) {
+                    Some(StorageAccessKind::Read)
+                } else if utils::is_storage_write_fn_name(&method) {
+                    Some(StorageAccessKind::Write)
+                } else {
+                    None
+                };
+
+                let (Some(kind), Some(field)) = (kind, Self::resolve_field(target, state)) else {
+                    let call = match target.as_ref() {
+                        Expr::AbiCast { args, .. } => Call::External(args.inner.name.span().as_str().to_string(), method),
+                        Expr::Path(_) if target.span().as_str() == "self" => Call::Method(method),
+
+                        _ => match (state.expr_binding(target).and_then(|binding| binding.abi.clone()), state.types.type_of(target)) {
+                            (Some(abi_name), _) => Call::External(abi_name, method),
+                            (None, Type::Named(type_name, _)) => Call::TypeMethod(utils::base_type_name(&type_name).to_string(), method),
+                            _ => return,
+                        },
+                    };
+
+                    state.calls.push(call);
+                    return;
+                };
+
+                let access = StorageAccess {
+                    field,
+                    kind,
+                    method,
+                    span: expr.span(),
+                };
+
+                self.expr_accesses.insert(expr_key(expr), access.clone());
+                state.accesses.push(access);
+            }
+
+            Expr::FuncApp { func, args } => {
+                if let Expr::Path(path) = func.as_ref() {
+                    match (path.prefix.name.as_str(), path.suffix.as_slice()) {
+                        (name, []) if path.root_opt.is_none() => state.calls.push(Call::Fn(name.to_string())),
+                        ("Self", [(_, segment)]) => state.calls.push(Call::Method(segment.name.as_str().to_string())),
+                        (type_name, [(_, segment)]) if path.root_opt.is_none() => state.calls.push(Call::TypeMethod(type_name.to_string(), segment.name.as_str().to_string())),
+                        _ => {}
+                    }
+                }
+
+                self.walk_expr(func, state);
+                self.walk_exprs(&args.inner, state);
+            }
+
+            Expr::Block(block) => self.walk_block(&block.inner, state),
+
+            Expr::If(if_expr) => self.walk_if(if_expr, state),
+
+            Expr::Match { value, branches, .. } => {
+                self.walk_expr(value, state);
+
+                for branch in branches.inner.iter() {
+                    state.scopes.push(HashMap::new());
+                    state.bind_pattern(&branch.pattern, Binding::default());
+
+                    match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => self.walk_block(&block.inner, state),
+                        MatchBranchKind::Expr { expr, .. } => self.walk_expr(expr, state),
+                    }
+
+                    state.scopes.pop();
+                }
+            }
+
+            Expr::While { condition, block, .. } => {
+                self.walk_expr(condition, state);
+                self.walk_block(&block.inner, state);
+            }
+
+            Expr::Asm(asm) => {
+                self.walk_exprs((&asm.registers.inner).into_iter().filter_map(|register| register.value_opt.as_ref().map(|(_, expr)| expr.as_ref())), state);
+            }
+
+            Expr::Struct { fields, .. } => {
+                self.walk_exprs((&fields.inner).into_iter().filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| expr.as_ref())), state);
+            }
+
+            Expr::Tuple(tuple) => {
+                if let ExprTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                    self.walk_exprs(std::iter::once(head.as_ref()).chain(tail), state);
+                }
+            }
+
+            Expr::Array(array) => match &array.inner {
+                ExprArrayDescriptor::Sequence(exprs) => self.walk_exprs(exprs, state),
+                ExprArrayDescriptor::Repeat { value, length, .. } => self.walk_exprs([value.as_ref(), length.as_ref()], state),
+            },
+
+            Expr::AbiCast { args, .. } => self.walk_expr(&args.inner.address, state),
+
+            Expr::Return { expr_opt, .. } => self.walk_exprs(expr_opt.iter().map(|expr| expr.as_ref()), state),
+
+            Expr::Index { target, arg } => self.walk_exprs([target.as_ref(), arg.inner.as_ref()], state),
+
+            Expr::Parens(expr) => self.walk_expr(&expr.inner, state),
+
+            Expr::FieldProjection { target, .. }
+            | Expr::TupleFieldProjection { target, .. }
+            | Expr::Ref { expr: target, .. }
+            | Expr::Deref { expr: target, .. }
+            | Expr::Not { expr: target, .. } => self.walk_expr(target, state),
+
+            Expr::Reassignment { expr, .. } => self.walk_expr(expr, state),
+
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. }
+            | Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. }
+            | Expr::LogicalAnd { lhs, rhs, .. }
+            | Expr::LogicalOr { lhs, rhs, .. } => self.walk_exprs([lhs.as_ref(), rhs.as_ref()], state),
+
+            Expr::Path(_) | Expr::Literal(_) | Expr::Error(..) | Expr::Break { .. } | Expr::Continue { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StorageAccessKind, StorageKind};
+    use crate::types::Type;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_storage_model() {
+        let source = r#"contract;
+
+storage {
+    owner: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+    balances: StorageMap<Identity, StorageMap<AssetId, u64>> = StorageMap {},
+    holders: StorageVec<Identity> = StorageVec {},
+    name: StorageString = StorageString {},
+}
+
+fn only_owner() {
+    require(storage.owner.read() == msg_sender().unwrap(), "Not the owner");
+}
+
+impl Contract {
+    fn deposit(asset: AssetId) {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).get(asset);
+        balance.write(balance.try_read().unwrap_or(0) + msg_amount());
+        storage.holders.push(sender);
+        storage::accounts.total.write(0);
+    }
+
+    fn clear_name() {
+        only_owner();
+        Self::reset();
+    }
+
+    fn reset() {
+        storage.name.clear();
+        Registry::touch();
+    }
+}
+
+struct Registry {}
+
+impl Registry {
+    fn touch() {
+        storage.holders.clear();
+    }
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let fns = crate::tests::collect_fn_results(&mut project, |context, project| {
+            let storage = project.storage();
+
+            let accesses = storage.fn_accesses(context.item_fn).iter()
+                .map(|x| (x.field.clone(), x.kind, x.method.clone()))
+                .collect::<Vec<_>>();
+
+            (accesses, storage.fn_summary(context.item_fn).cloned().unwrap())
+        });
+
+        let storage = project.storage();
+
+        let balances = storage.field("balances").unwrap();
+        assert_eq!(balances.kind(), StorageKind::Map);
+        assert_eq!(balances.key_types(), vec![Type::named("Identity"), Type::named("AssetId")]);
+        assert_eq!(balances.value_type(), Type::named("u64"));
+        assert_eq!(storage.field("holders").unwrap().kind(), StorageKind::Vec);
+        assert_eq!(storage.field("name").unwrap().kind(), StorageKind::String);
+        let owner = storage.field("owner").unwrap();
+        assert!(storage.is_read(owner) && !storage.is_written(owner));
+
+        let accesses = fns["deposit"].0.iter().map(|(field, kind, method)| (field.as_str(), *kind, method.as_str())).collect::<Vec<_>>();
+
+        assert_eq!(accesses, vec![
+            ("balances", StorageAccessKind::Read, "try_read"),
+            ("balances", StorageAccessKind::Write, "write"),
+            ("holders", StorageAccessKind::Write, "push"),
+            ("accounts::total", StorageAccessKind::Write, "write"),
+        ]);
+
+        for (name, reads, writes) in [
+            ("deposit", vec!["balances"], vec!["accounts::total", "balances", "holders"]),
+            ("clear_name", vec!["owner"], vec!["holders", "name"]),
+            ("reset", vec![], vec!["holders", "name"]),
+        ] {
+            let summary = &fns[name].1;
+            assert_eq!(summary.reads, reads.into_iter().map(String::from).collect::<BTreeSet<_>>(), "Unexpected reads for `{name}`");
+            assert_eq!(summary.writes, writes.into_iter().map(String::from).collect::<BTreeSet<_>>(), "Unexpected writes for `{name}`");
+        }
+    }
+
+    #[test]
+    fn test_storage_bindings_scoped() {
+        let source = r#"contract;
+
+storage {
+    owner: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+    name: StorageString = StorageString {},
+}
+
+fn shadowed() {
+    let key = storage.owner;
+
+    if true {
+        let key = storage.name;
+        key.clear();
+    }
+
+    key.read();
+}
+
+fn leaked(key: StorageKey<u64>) {
+    {
+        let key = storage.owner;
+        key.write(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)));
+    }
+
+    key.write(0);
+}
+"#;
+
+        let mut project = crate::tests::parse_project(source);
+
+        let fns = crate::tests::collect_fn_results(&mut project, |context, project| {
+            project.storage().fn_accesses(context.item_fn).iter()
+                .map(|x| (x.field.clone(), x.kind))
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(fns["shadowed"], vec![("name".to_string(), StorageAccessKind::Write), ("owner".to_string(), StorageAccessKind::Read)]);
+        assert_eq!(fns["leaked"], vec![("owner".to_string(), StorageAccessKind::Write)]);
+    }
+}
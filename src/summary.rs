@@ -0,0 +1,123 @@
+use crate::report::{Report, Severity};
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Summary {
+    pub files: usize,
+    pub lines: usize,
+    pub items: usize,
+    pub severity_findings: BTreeMap<Severity, usize>,
+    pub detector_findings: BTreeMap<String, usize>,
+    pub timings: Option<Vec<DetectorTiming>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DetectorTiming {
+    pub detector: String,
+    pub milliseconds: f64,
+}
+
+impl Summary {
+    /// Counts the findings in the supplied `report` by severity and by detector.
+    pub fn add_report_findings(&mut self, report: &Report) {
+        for severity in [Severity::High, Severity::Medium, Severity::Low] {
+            self.severity_findings.insert(severity, 0);
+        }
+
+        for (_, entries) in report.entries.iter() {
+            for entry in entries.iter() {
+                *self.severity_findings.entry(entry.severity).or_default() += 1;
+                *self.detector_findings.entry(entry.detector.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    /// Adds the time spent in each detector, sorted from slowest to fastest.
+    pub fn add_timings(&mut self, detector_names: &[String], timings: &[Duration]) {
+        let mut result = detector_names.iter()
+            .zip(timings.iter())
+            .map(|(detector, timing)| DetectorTiming {
+                detector: detector.clone(),
+                milliseconds: timing.as_secs_f64() * 1000.0,
+            })
+            .collect::<Vec<_>>();
+
+        result.sort_by(|a, b| b.milliseconds.total_cmp(&a.milliseconds));
+
+        self.timings = Some(result);
+    }
+
+    pub fn finding_count(&self) -> usize {
+        self.severity_findings.values().sum()
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Summary:")?;
+        writeln!(f, "\tFiles: {}", self.files)?;
+        writeln!(f, "\tLines: {}", self.lines)?;
+        writeln!(f, "\tItems: {}", self.items)?;
+
+        write!(f, "\tFindings: {}", self.finding_count())?;
+
+        if !self.severity_findings.is_empty() {
+            let severity_findings = self.severity_findings.iter()
+                .map(|(severity, count)| format!("{severity}: {count}"))
+                .collect::<Vec<_>>();
+
+            write!(f, " ({})", severity_findings.join(", "))?;
+        }
+
+        writeln!(f)?;
+
+        for (detector, count) in self.detector_findings.iter() {
+            writeln!(f, "\t\t{detector}: {count}")?;
+        }
+
+        if let Some(timings) = self.timings.as_ref() {
+            writeln!(f)?;
+            writeln!(f, "Timings:")?;
+
+            for timing in timings.iter() {
+                writeln!(f, "\t{}: {:.3} ms", timing.detector, timing.milliseconds)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{project::Project, report::Severity, Options};
+
+    #[test]
+    fn test_summary() {
+        let options = Options {
+            directory: Some("test/boolean_comparison/".into()),
+            detectors: vec!["boolean_comparison".to_string(), "magic_number".to_string()],
+            timings: true,
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        let summary = project.summary();
+
+        assert_eq!(summary.files, 1);
+        assert_eq!(summary.lines, 77);
+        assert_eq!(summary.items, 7);
+        assert_eq!(summary.finding_count(), 13);
+        assert_eq!(summary.severity_findings[&Severity::High], 0);
+        assert_eq!(summary.severity_findings[&Severity::Low], 13);
+        assert_eq!(summary.detector_findings["boolean_comparison"], 10);
+        assert_eq!(summary.detector_findings["magic_number"], 3);
+
+        let timings = summary.timings.unwrap();
+        assert_eq!(timings.len(), 2);
+        assert!(timings.iter().any(|x| x.detector == "boolean_comparison"));
+    }
+}
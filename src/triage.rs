@@ -0,0 +1,210 @@
+use crate::{error::Error, project::Project, report::Entry};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+/// The default path of the triage database, relative to the project directory.
+pub const DEFAULT_TRIAGE_PATH: &str = ".sway-analyzer/triage.json";
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TriageStatus {
+    Confirmed,
+    FalsePositive,
+    WontFix,
+}
+
+impl TriageStatus {
+    /// Checks if entries with the triage status should be hidden from the report by default.
+    pub fn is_hidden(&self) -> bool {
+        matches!(self, TriageStatus::FalsePositive | TriageStatus::WontFix)
+    }
+}
+
+impl Display for TriageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriageStatus::Confirmed => write!(f, "confirmed"),
+            TriageStatus::FalsePositive => write!(f, "false-positive"),
+            TriageStatus::WontFix => write!(f, "wont-fix"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TriageEntry {
+    pub status: TriageStatus,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+}
+
+impl Display for TriageEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.note.is_empty() {
+            write!(f, "{}", self.status)
+        } else {
+            write!(f, "{}: {}", self.status, self.note)
+        }
+    }
+}
+
+/// A database of triaged report entries, keyed by entry fingerprint.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TriageDatabase {
+    pub entries: BTreeMap<String, TriageEntry>,
+}
+
+impl TriageDatabase {
+    /// Attempts to load the triage database from the supplied `path`, or creates an empty database if it does not exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let source = std::fs::read_to_string(path).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        serde_json::from_str(&source).map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    /// Attempts to save the triage database to the supplied `path`, creating its parent directory if necessary.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent().filter(|x| !x.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        }
+
+        let source = serde_json::to_string_pretty(self).map_err(|e| Error::Wrapped(Box::new(e)))?;
+        std::fs::write(path, format!("{source}\n")).map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    pub fn get(&self, fingerprint: &str) -> Option<&TriageEntry> {
+        self.entries.get(fingerprint)
+    }
+
+    pub fn insert<S: Into<String>>(&mut self, fingerprint: S, entry: TriageEntry) {
+        self.entries.insert(fingerprint.into(), entry);
+    }
+}
+
+/// Walks through each untriaged entry in the report of the supplied `project`, prompting for a triage status and note.
+///
+/// The triage database is saved to `path` after each decision, so progress is kept if the session is interrupted.
+pub fn triage_interactive<R: BufRead, W: Write>(
+    project: &Project,
+    database: &mut TriageDatabase,
+    path: &Path,
+    input: &mut R,
+    output: &mut W,
+) -> Result<(), Error> {
+    let untriaged: Vec<(PathBuf, Entry)> = project.report.borrow().entries.iter()
+        .flat_map(|(path, entries)| entries.iter().map(move |entry| (path.clone(), entry.clone())))
+        .filter(|(_, entry)| database.get(&entry.fingerprint).is_none())
+        .collect();
+
+    let io_error = |e: std::io::Error| Error::Wrapped(Box::new(e));
+
+    if untriaged.is_empty() {
+        writeln!(output, "No untriaged entries.").map_err(io_error)?;
+        return Ok(());
+    }
+
+    for (i, (entry_path, entry)) in untriaged.iter().enumerate() {
+        writeln!(output).map_err(io_error)?;
+        writeln!(output, "[{}/{}] {} ({}, {})", i + 1, untriaged.len(), entry_path.to_string_lossy(), entry.detector, entry.severity).map_err(io_error)?;
+        writeln!(output, "{entry}").map_err(io_error)?;
+
+        if let Some(source_line) = entry.line.and_then(|line| project.get_source_line(entry_path, line)) {
+            writeln!(output, "\t{}", source_line.trim()).map_err(io_error)?;
+        }
+
+        let status = loop {
+            write!(output, "[c]onfirmed, [f]alse positive, [w]on't fix, [s]kip, [q]uit: ").map_err(io_error)?;
+            output.flush().map_err(io_error)?;
+
+            let mut line = String::new();
+
+            // Treat the end of the input as quitting
+            if input.read_line(&mut line).map_err(io_error)? == 0 {
+                return Ok(());
+            }
+
+            match line.trim().to_ascii_lowercase().as_str() {
+                "c" => break Some(TriageStatus::Confirmed),
+                "f" => break Some(TriageStatus::FalsePositive),
+                "w" => break Some(TriageStatus::WontFix),
+                "s" => break None,
+                "q" => return Ok(()),
+                _ => continue,
+            }
+        };
+
+        let Some(status) = status else { continue };
+
+        write!(output, "Note (optional): ").map_err(io_error)?;
+        output.flush().map_err(io_error)?;
+
+        let mut note = String::new();
+        input.read_line(&mut note).map_err(io_error)?;
+
+        database.insert(entry.fingerprint.clone(), TriageEntry {
+            status,
+            note: note.trim().to_string(),
+        });
+
+        database.save(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Options;
+
+    #[test]
+    fn test_triage() {
+        let triage_path = std::env::temp_dir().join(format!("sway-analyzer-triage-{}.json", std::process::id()));
+
+        let options = Options {
+            directory: Some("test/boolean_comparison/".into()),
+            detectors: vec!["boolean_comparison".to_string()],
+            triage_file: Some(triage_path.clone()),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        // Mark the first entry as a false positive, skip the second and confirm the third
+        let mut database = TriageDatabase::default();
+        let mut input = "f\nnot reachable\ns\nx\nc\n\nq\n".as_bytes();
+        let mut output = vec![];
+
+        triage_interactive(&project, &mut database, &triage_path, &mut input, &mut output).unwrap();
+
+        assert_eq!(database.entries.len(), 2);
+
+        let database = TriageDatabase::load(&triage_path).unwrap();
+        let statuses = database.entries.values().map(|x| (x.status, x.note.as_str())).collect::<Vec<_>>();
+        assert!(statuses.contains(&(TriageStatus::FalsePositive, "not reachable")));
+        assert!(statuses.contains(&(TriageStatus::Confirmed, "")));
+
+        // Reload the project, which should hide the false positive and annotate the confirmed entry
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        let report = project.report.borrow();
+        let entries = &report.entries[0].1;
+        assert_eq!(entries.len(), 9);
+        assert_eq!(entries.iter().filter(|x| x.triage.is_some()).count(), 1);
+
+        std::fs::remove_file(&triage_path).unwrap();
+    }
+}
@@ -0,0 +1,1211 @@
+use crate::utils::{expr_key, source_key, ExprKey};
+use std::{collections::HashMap, fmt::Display};
+use sway_ast::{
+    expr::{asm::AsmBlock, LoopControlFlow},
+    literal::LitIntType,
+    ty::TyTupleDescriptor,
+    CodeBlockContents, Expr, ExprArrayDescriptor, ExprTupleDescriptor, FnArgs, GenericParams,
+    IfCondition, IfExpr, ItemFn, ItemImplItem, ItemKind, ItemTraitItem, Literal, MatchBranchKind,
+    Module, PathExpr, PathExprSegment, Pattern, PatternStructField, Statement, Ty,
+};
+use sway_types::Spanned;
+
+/// The return types of std functions and associated functions, matched against the trailing segments of a call path.
+///
+/// Single uppercase letters are type variables, bound from the argument types.
+const STD_FUNCTIONS: &[(&str, &[&str], &str)] = &[
+    ("Some", &["T"], "Option<T>"),
+    ("Ok", &["T"], "Result<T, E>"),
+    ("Err", &["E"], "Result<T, E>"),
+    ("msg_sender", &[], "Result<Identity, AuthError>"),
+    ("msg_amount", &[], "u64"),
+    ("msg_asset_id", &[], "AssetId"),
+    ("this_balance", &[], "u64"),
+    ("balance_of", &[], "u64"),
+    ("timestamp", &[], "u64"),
+    ("height", &[], "u32"),
+    ("block_header_hash", &[], "Result<b256, BlockHashError>"),
+    ("contract_id", &[], "ContractId"),
+    ("caller_contract_id", &[], "ContractId"),
+    ("tx_id", &[], "b256"),
+    ("keccak256", &[], "b256"),
+    ("sha256", &[], "b256"),
+    ("ec_recover", &[], "Result<B512, EcRecoverError>"),
+    ("ec_recover_address", &[], "Result<Address, EcRecoverError>"),
+    ("Identity::Address", &[], "Identity"),
+    ("Identity::ContractId", &[], "Identity"),
+    ("Address::from", &[], "Address"),
+    ("ContractId::from", &[], "ContractId"),
+    ("AssetId::new", &[], "AssetId"),
+    ("AssetId::from", &[], "AssetId"),
+    ("AssetId::default", &[], "AssetId"),
+    ("Vec::new", &[], "Vec<T>"),
+    ("Vec::with_capacity", &[], "Vec<T>"),
+    ("Bytes::new", &[], "Bytes"),
+    ("b256::min", &[], "b256"),
+    ("b256::max", &[], "b256"),
+    ("u64::min", &[], "u64"),
+    ("u64::max", &[], "u64"),
+];
+
+/// The return types of std methods, as `(receiver, method, parameters, return type)`.
+///
+/// Single uppercase letters are type variables, bound from the receiver and argument types.
+const STD_METHODS: &[(&str, &str, &[&str], &str)] = &[
+    ("StorageKey<T>", "read", &[], "T"),
+    ("StorageKey<T>", "try_read", &[], "Option<T>"),
+    ("StorageKey<T>", "write", &["T"], "()"),
+    ("StorageKey<T>", "clear", &[], "bool"),
+    ("StorageKey<StorageMap<K, V>>", "get", &["K"], "StorageKey<V>"),
+    ("StorageKey<StorageMap<K, V>>", "insert", &["K", "V"], "()"),
+    ("StorageKey<StorageMap<K, V>>", "try_insert", &["K", "V"], "Result<V, StorageMapError<V>>"),
+    ("StorageKey<StorageMap<K, V>>", "remove", &["K"], "bool"),
+    ("StorageKey<StorageVec<V>>", "get", &["u64"], "Option<StorageKey<V>>"),
+    ("StorageKey<StorageVec<V>>", "first", &[], "Option<StorageKey<V>>"),
+    ("StorageKey<StorageVec<V>>", "last", &[], "Option<StorageKey<V>>"),
+    ("StorageKey<StorageVec<V>>", "push", &["V"], "()"),
+    ("StorageKey<StorageVec<V>>", "pop", &[], "Option<V>"),
+    ("StorageKey<StorageVec<V>>", "remove", &["u64"], "V"),
+    ("StorageKey<StorageVec<V>>", "len", &[], "u64"),
+    ("StorageKey<StorageVec<V>>", "is_empty", &[], "bool"),
+    ("Option<T>", "unwrap", &[], "T"),
+    ("Option<T>", "unwrap_or", &["T"], "T"),
+    ("Option<T>", "expect", &[], "T"),
+    ("Option<T>", "is_some", &[], "bool"),
+    ("Option<T>", "is_none", &[], "bool"),
+    ("Result<T, E>", "unwrap", &[], "T"),
+    ("Result<T, E>", "unwrap_or", &["T"], "T"),
+    ("Result<T, E>", "expect", &[], "T"),
+    ("Result<T, E>", "is_ok", &[], "bool"),
+    ("Result<T, E>", "is_err", &[], "bool"),
+    ("Identity", "as_address", &[], "Option<Address>"),
+    ("Identity", "as_contract_id", &[], "Option<ContractId>"),
+    ("Identity", "is_address", &[], "bool"),
+    ("Identity", "is_contract_id", &[], "bool"),
+    ("Address", "bits", &[], "b256"),
+    ("ContractId", "bits", &[], "b256"),
+    ("AssetId", "bits", &[], "b256"),
+    ("Vec<T>", "get", &["u64"], "Option<T>"),
+    ("Vec<T>", "push", &["T"], "()"),
+    ("Vec<T>", "pop", &[], "Option<T>"),
+    ("Vec<T>", "remove", &["u64"], "T"),
+    ("Vec<T>", "len", &[], "u64"),
+    ("Vec<T>", "is_empty", &[], "bool"),
+    ("Bytes", "len", &[], "u64"),
+    ("Bytes", "is_empty", &[], "bool"),
+    ("u64", "pow", &["u32"], "u64"),
+    ("u64", "as_u256", &[], "u256"),
+    ("u32", "as_u64", &[], "u64"),
+    ("u16", "as_u64", &[], "u64"),
+    ("u8", "as_u64", &[], "u64"),
+];
+
+/// A type inferred for an expression.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Type {
+    /// A type that could not be inferred.
+    #[default]
+    Unknown,
+
+    /// A named type with its generic arguments, i.e: `u64`, `Identity` or `StorageMap<Address, u64>`.
+    Named(String, Vec<Type>),
+
+    /// A tuple type, where the unit type is the empty tuple.
+    Tuple(Vec<Type>),
+
+    /// An array type with its element type.
+    Array(Box<Type>),
+}
+
+impl Type {
+    pub fn unit() -> Self {
+        Type::Tuple(vec![])
+    }
+
+    pub fn named<S: Into<String>>(name: S) -> Self {
+        Type::Named(name.into(), vec![])
+    }
+
+    /// Gets the name of the type if it is a named type.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Type::Named(name, _) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Gets the width in bits of the type if it is an unsigned integer type.
+    pub fn int_bits(&self) -> Option<u16> {
+        match self.name()? {
+            "u8" => Some(8),
+            "u16" => Some(16),
+            "u32" => Some(32),
+            "u64" => Some(64),
+            "u256" => Some(256),
+            _ => None,
+        }
+    }
+
+    /// Gets the generic arguments of the type if it is a named type.
+    pub fn generic_args(&self) -> &[Type] {
+        match self {
+            Type::Named(_, args) => args.as_slice(),
+            _ => &[],
+        }
+    }
+
+    /// Checks if the type is a named type called `name`.
+    pub fn is(&self, name: &str) -> bool {
+        self.name() == Some(name)
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown)
+    }
+
+    /// Checks if the type is an unsigned integer type.
+    pub fn is_integer(&self) -> bool {
+        matches!(self.name(), Some("u8" | "u16" | "u32" | "u64" | "u256"))
+    }
+
+    /// Converts the supplied syntactic `ty` to a type, using the last segment of path types.
+    pub fn from_ty(ty: &Ty) -> Self {
+        match ty {
+            Ty::Path(path_type) => {
+                let segment = path_type.last_segment();
+
+                let args = segment.generics_opt.as_ref()
+                    .map(|(_, generics)| (&generics.parameters.inner).into_iter().map(Type::from_ty).collect())
+                    .unwrap_or_default();
+
+                Type::Named(segment.name.as_str().to_string(), args)
+            }
+
+            Ty::Tuple(tuple) => match &tuple.inner {
+                TyTupleDescriptor::Nil => Type::unit(),
+                TyTupleDescriptor::Cons { head, tail, .. } => {
+                    Type::Tuple(std::iter::once(head.as_ref()).chain(tail).map(Type::from_ty).collect())
+                }
+            },
+
+            Ty::Array(array) => Type::Array(Box::new(Type::from_ty(&array.inner.ty))),
+            Ty::StringSlice(_) | Ty::StringArray { .. } => Type::named("str"),
+            Ty::Infer { .. } => Type::Unknown,
+            Ty::Ptr { ty, .. } => Type::Named("__ptr".into(), vec![Type::from_ty(&ty.inner)]),
+            Ty::Slice { ty, .. } => Type::Named("__slice".into(), vec![Type::from_ty(&ty.inner)]),
+        }
+    }
+
+    /// Parses the supplied type `text`, i.e: `StorageMap<Address, (u64, bool)>`.
+    pub fn parse(text: &str) -> Self {
+        fn parse_list(text: &str) -> Vec<Type> {
+            let mut types = vec![];
+            let mut depth = 0;
+            let mut start = 0;
+
+            for (i, c) in text.char_indices() {
+                match c {
+                    '<' | '(' | '[' => depth += 1,
+                    '>' | ')' | ']' => depth -= 1,
+                    ',' if depth == 0 => {
+                        types.push(Type::parse(&text[start..i]));
+                        start = i + 1;
+                    }
+                    _ => {}
+                }
+            }
+
+            if !text[start..].trim().is_empty() {
+                types.push(Type::parse(&text[start..]));
+            }
+
+            types
+        }
+
+        let text = text.trim();
+
+        if let Some(inner) = text.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+            return Type::Tuple(parse_list(inner));
+        }
+
+        if let Some(inner) = text.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+            let element = inner.split_once(';').map(|(element, _)| element).unwrap_or(inner);
+            return Type::Array(Box::new(Type::parse(element)));
+        }
+
+        match text.split_once('<') {
+            Some((name, args)) => Type::Named(name.trim().to_string(), parse_list(args.strip_suffix('>').unwrap_or(args))),
+            None if text.is_empty() || text == "_" => Type::Unknown,
+            None => Type::named(text),
+        }
+    }
+
+    /// Replaces the type variables in the type with their types in the supplied `bindings`.
+    fn substitute(&self, bindings: &HashMap<String, Type>) -> Type {
+        match self {
+            Type::Named(name, args) if args.is_empty() && bindings.contains_key(name) => bindings[name].clone(),
+            Type::Named(name, args) => Type::Named(name.clone(), args.iter().map(|x| x.substitute(bindings)).collect()),
+            Type::Tuple(types) => Type::Tuple(types.iter().map(|x| x.substitute(bindings)).collect()),
+            Type::Array(element) => Type::Array(Box::new(element.substitute(bindings))),
+            Type::Unknown => Type::Unknown,
+        }
+    }
+
+    /// Attempts to match the type against the supplied `other` type, binding the type variables named in `vars`.
+    ///
+    /// Unknown types match anything without binding.
+    fn unify(&self, other: &Type, vars: &[String], bindings: &mut HashMap<String, Type>) -> bool {
+        match (self, other) {
+            (Type::Named(name, args), _) if args.is_empty() && vars.contains(name) => {
+                if !other.is_unknown() {
+                    bindings.entry(name.clone()).or_insert_with(|| other.clone());
+                }
+
+                true
+            }
+
+            (Type::Unknown, _) | (_, Type::Unknown) => true,
+
+            (Type::Named(name, args), Type::Named(other_name, other_args)) => {
+                name == other_name
+                    && (other_args.is_empty() || args.len() == other_args.len())
+                    && args.iter().zip(other_args).all(|(a, b)| a.unify(b, vars, bindings))
+            }
+
+            (Type::Tuple(types), Type::Tuple(other_types)) => {
+                types.len() == other_types.len() && types.iter().zip(other_types).all(|(a, b)| a.unify(b, vars, bindings))
+            }
+
+            (Type::Array(element), Type::Array(other_element)) => element.unify(other_element, vars, bindings),
+
+            _ => false,
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |types: &[Type]| types.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+        match self {
+            Type::Unknown => write!(f, "_"),
+            Type::Named(name, args) if args.is_empty() => write!(f, "{name}"),
+            Type::Named(name, args) => write!(f, "{name}<{}>", join(args)),
+            Type::Tuple(types) if types.len() == 1 => write!(f, "({},)", types[0]),
+            Type::Tuple(types) => write!(f, "({})", join(types)),
+            Type::Array(element) => write!(f, "[{element}]"),
+        }
+    }
+}
+
+/// The signature of a function or method, with the names of its type variables.
+#[derive(Clone, Debug, Default)]
+struct Signature {
+    vars: Vec<String>,
+    receiver: Option<Type>,
+    params: Vec<Type>,
+    ret: Type,
+}
+
+impl Signature {
+    /// Creates a signature from types written in the std signature tables.
+    fn from_std(receiver: Option<&str>, params: &[&str], ret: &str) -> Self {
+        let receiver = receiver.map(Type::parse);
+        let params = params.iter().map(|x| Type::parse(x)).collect::<Vec<_>>();
+        let ret = Type::parse(ret);
+
+        let mut vars = vec![];
+
+        fn collect_vars(ty: &Type, vars: &mut Vec<String>) {
+            match ty {
+                Type::Named(name, args) if args.is_empty() && name.len() == 1 && name.chars().all(|c| c.is_ascii_uppercase()) => {
+                    if !vars.contains(name) {
+                        vars.push(name.clone());
+                    }
+                }
+                Type::Named(_, types) | Type::Tuple(types) => types.iter().for_each(|x| collect_vars(x, vars)),
+                Type::Array(element) => collect_vars(element, vars),
+                Type::Unknown => {}
+            }
+        }
+
+        for ty in receiver.iter().chain(params.iter()).chain(std::iter::once(&ret)) {
+            collect_vars(ty, &mut vars);
+        }
+
+        Self { vars, receiver, params, ret }
+    }
+
+    /// Gets the return type of a call with the supplied `receiver` and `args` types, or `None` if the receiver does not match.
+    fn apply(&self, receiver: Option<&Type>, args: &[Type]) -> Option<Type> {
+        let mut bindings = HashMap::new();
+
+        if let (Some(pattern), Some(receiver)) = (self.receiver.as_ref(), receiver) {
+            if !pattern.unify(receiver, &self.vars, &mut bindings) {
+                return None;
+            }
+        }
+
+        for (param, arg) in self.params.iter().zip(args) {
+            param.unify(arg, &self.vars, &mut bindings);
+        }
+
+        // Leave unbound type variables unknown
+        for var in self.vars.iter() {
+            bindings.entry(var.clone()).or_insert(Type::Unknown);
+        }
+
+        Some(self.ret.substitute(&bindings))
+    }
+}
+
+/// A struct or enum definition, with the types of its fields or variants.
+#[derive(Clone, Debug, Default)]
+struct TypeDefinition {
+    generic_params: Vec<String>,
+    fields: HashMap<String, Type>,
+}
+
+impl TypeDefinition {
+    /// Gets the type of the field or variant called `name` in an instance of the supplied `ty`.
+    fn field_type(&self, ty: &Type, name: &str) -> Option<Type> {
+        let bindings = self.generic_params.iter().cloned().zip(ty.generic_args().iter().cloned()).collect();
+        self.fields.get(name).map(|field| field.substitute(&bindings))
+    }
+}
+
+fn last_segment(path: &PathExpr) -> &PathExprSegment {
+    path.suffix.last().map(|(_, segment)| segment).unwrap_or(&path.prefix)
+}
+
+fn generic_param_names(generics: Option<&GenericParams>) -> Vec<String> {
+    generics.map(|generics| (&generics.parameters.inner).into_iter().map(|x| x.as_str().to_string()).collect()).unwrap_or_default()
+}
+
+/// A lightweight type inference pass over a set of modules.
+///
+/// Types are inferred for local variables, function and method returns, struct and enum fields, storage fields and std functions, and are `Type::Unknown` wherever they cannot be determined.
+///
+/// Functions, methods, constants and storage fields are recorded along with the module declaring them, so declarations of the same name in different packages do not collide.
+#[derive(Default)]
+pub struct TypeInference {
+    type_aliases: HashMap<String, Type>,
+    structs: HashMap<String, TypeDefinition>,
+    enums: HashMap<String, TypeDefinition>,
+    functions: HashMap<String, Vec<(usize, Signature)>>,
+    methods: HashMap<(String, String), Vec<(usize, Signature)>>,
+    constants: HashMap<(usize, String), Type>,
+    storage_fields: HashMap<(usize, String), Type>,
+    expr_types: HashMap<ExprKey, Type>,
+    /// The source key of the module currently being processed.
+    module: usize,
+}
+
+impl TypeInference {
+    /// Infers the types of every expression in the supplied `modules`.
+    pub fn new<'a, I: IntoIterator<Item = &'a Module>>(modules: I) -> Self {
+        let modules = modules.into_iter().collect::<Vec<_>>();
+        let mut result = Self::default();
+
+        for module in modules.iter() {
+            for item in module.items.iter() {
+                if let ItemKind::TypeAlias(item_type_alias) = &item.value {
+                    result.type_aliases.insert(item_type_alias.name.as_str().to_string(), Type::from_ty(&item_type_alias.ty));
+                }
+            }
+        }
+
+        for module in modules.iter() {
+            result.module = source_key(&module.span());
+
+            for item in module.items.iter() {
+                result.add_definitions(&item.value);
+            }
+        }
+
+        for module in modules.iter() {
+            result.module = source_key(&module.span());
+
+            for item in module.items.iter() {
+                result.infer_item(&item.value);
+            }
+        }
+
+        result
+    }
+
+    /// Gets the inferred type of the supplied `expr`.
+    pub fn type_of(&self, expr: &Expr) -> Type {
+        self.expr_types.get(&expr_key(expr)).cloned().unwrap_or_default()
+    }
+
+    /// Converts the supplied syntactic `ty` to a type, resolving type aliases.
+    fn resolve_ty(&self, ty: &Ty) -> Type {
+        fn resolve(ty: Type, type_aliases: &HashMap<String, Type>) -> Type {
+            match ty {
+                Type::Named(name, args) if args.is_empty() && type_aliases.contains_key(&name) => type_aliases[&name].clone(),
+                Type::Named(name, args) => Type::Named(name, args.into_iter().map(|x| resolve(x, type_aliases)).collect()),
+                Type::Tuple(types) => Type::Tuple(types.into_iter().map(|x| resolve(x, type_aliases)).collect()),
+                Type::Array(element) => Type::Array(Box::new(resolve(*element, type_aliases))),
+                Type::Unknown => Type::Unknown,
+            }
+        }
+
+        resolve(Type::from_ty(ty), &self.type_aliases)
+    }
+
+    fn fn_signature(&self, fn_signature: &sway_ast::FnSignature, receiver: Option<Type>, mut vars: Vec<String>) -> Signature {
+        vars.extend(generic_param_names(fn_signature.generics.as_ref()));
+
+        let params = match &fn_signature.arguments.inner {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.iter().flat_map(|(_, args)| args.into_iter()).collect(),
+        };
+
+        Signature {
+            vars,
+            receiver,
+            params: params.into_iter().map(|arg| self.resolve_ty(&arg.ty)).collect(),
+            ret: fn_signature.return_type_opt.as_ref().map(|(_, ty)| self.resolve_ty(ty)).unwrap_or_else(Type::unit),
+        }
+    }
+
+    fn add_definitions(&mut self, item: &ItemKind) {
+        match item {
+            ItemKind::Struct(item_struct) => {
+                let definition = TypeDefinition {
+                    generic_params: generic_param_names(item_struct.generics.as_ref()),
+                    fields: (&item_struct.fields.inner).into_iter().map(|field| (field.value.name.as_str().to_string(), self.resolve_ty(&field.value.ty))).collect(),
+                };
+
+                self.structs.insert(item_struct.name.as_str().to_string(), definition);
+            }
+
+            ItemKind::Enum(item_enum) => {
+                let definition = TypeDefinition {
+                    generic_params: generic_param_names(item_enum.generics.as_ref()),
+                    fields: (&item_enum.fields.inner).into_iter().map(|field| (field.value.name.as_str().to_string(), self.resolve_ty(&field.value.ty))).collect(),
+                };
+
+                self.enums.insert(item_enum.name.as_str().to_string(), definition);
+            }
+
+            ItemKind::Fn(item_fn) => {
+                let signature = self.fn_signature(&item_fn.fn_signature, None, vec![]);
+                self.functions.entry(item_fn.fn_signature.name.as_str().to_string()).or_default().push((self.module, signature));
+            }
+
+            ItemKind::Impl(item_impl) => {
+                let self_type = self.resolve_ty(&item_impl.ty);
+                let Some(self_name) = self_type.name().map(str::to_string) else { return };
+                let generic_params = generic_param_names(item_impl.generic_params_opt.as_ref());
+
+                for item in item_impl.contents.inner.iter() {
+                    let ItemImplItem::Fn(item_fn) = &item.value else { continue };
+                    let signature = self.fn_signature(&item_fn.fn_signature, Some(self_type.clone()), generic_params.clone());
+                    self.methods.entry((self_name.clone(), item_fn.fn_signature.name.as_str().to_string())).or_default().push((self.module, signature));
+                }
+            }
+
+            ItemKind::Abi(item_abi) => {
+                let abi_type = Type::named(item_abi.name.as_str());
+
+                let fn_signatures = item_abi.abi_items.inner.iter()
+                    .filter_map(|item| match &item.value {
+                        ItemTraitItem::Fn(fn_signature, _) => Some(fn_signature),
+                        _ => None,
+                    })
+                    .chain(item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter().map(|item| &item.value.fn_signature)));
+
+                for fn_signature in fn_signatures {
+                    let signature = self.fn_signature(fn_signature, Some(abi_type.clone()), vec![]);
+                    self.methods.entry((item_abi.name.as_str().to_string(), fn_signature.name.as_str().to_string())).or_default().push((self.module, signature));
+                }
+            }
+
+            ItemKind::Const(item_const) => {
+                let ty = item_const.ty_opt.as_ref().map(|(_, ty)| self.resolve_ty(ty)).unwrap_or_default();
+                self.constants.insert((self.module, item_const.name.as_str().to_string()), ty);
+            }
+
+            ItemKind::Configurable(item_configurable) => {
+                for field in (&item_configurable.fields.inner).into_iter() {
+                    self.constants.insert((self.module, field.value.name.as_str().to_string()), self.resolve_ty(&field.value.ty));
+                }
+            }
+
+            ItemKind::Storage(item_storage) => {
+                for field in (&item_storage.fields.inner).into_iter() {
+                    self.storage_fields.insert((self.module, field.value.name.as_str().to_string()), self.resolve_ty(&field.value.ty));
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn infer_item(&mut self, item: &ItemKind) {
+        match item {
+            ItemKind::Fn(item_fn) => self.infer_fn(item_fn, None),
+
+            ItemKind::Impl(item_impl) => {
+                let self_type = self.resolve_ty(&item_impl.ty);
+
+                for item in item_impl.contents.inner.iter() {
+                    match &item.value {
+                        ItemImplItem::Fn(item_fn) => self.infer_fn(item_fn, Some(self_type.clone())),
+                        ItemImplItem::Const(item_const) => self.infer_item(&ItemKind::Const(item_const.clone())),
+                        ItemImplItem::Type(_) => {}
+                    }
+                }
+            }
+
+            ItemKind::Abi(item_abi) => {
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.infer_fn(&item.value, None);
+                }
+            }
+
+            ItemKind::Trait(item_trait) => {
+                for item in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.inner.iter()) {
+                    self.infer_fn(&item.value, None);
+                }
+            }
+
+            ItemKind::Const(item_const) => {
+                if let Some(expr) = item_const.expr_opt.as_ref() {
+                    self.infer_expr(expr, &mut vec![]);
+                }
+            }
+
+            ItemKind::Configurable(item_configurable) => {
+                for field in (&item_configurable.fields.inner).into_iter() {
+                    self.infer_expr(&field.value.initializer, &mut vec![]);
+                }
+            }
+
+            ItemKind::Storage(item_storage) => {
+                for field in (&item_storage.fields.inner).into_iter() {
+                    self.infer_expr(&field.value.initializer, &mut vec![]);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn infer_fn(&mut self, item_fn: &ItemFn, self_type: Option<Type>) {
+        let mut scope = HashMap::new();
+
+        let args = match &item_fn.fn_signature.arguments.inner {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+
+            FnArgs::NonStatic { args_opt, .. } => {
+                scope.insert("self".to_string(), self_type.unwrap_or_default());
+                args_opt.iter().flat_map(|(_, args)| args.into_iter()).collect()
+            }
+        };
+
+        for arg in args {
+            let ty = self.resolve_ty(&arg.ty);
+            self.bind_pattern(&arg.pattern, ty, &mut scope);
+        }
+
+        self.infer_block(&item_fn.body.inner, &mut vec![scope]);
+    }
+
+    /// Binds the variables in the supplied `pattern` matched against a value of type `ty` in the supplied `scope`.
+    fn bind_pattern(&self, pattern: &Pattern, ty: Type, scope: &mut HashMap<String, Type>) {
+        match pattern {
+            Pattern::Var { name, .. } | Pattern::AmbiguousSingleIdent(name) => {
+                scope.insert(name.as_str().to_string(), ty);
+            }
+
+            Pattern::Or { lhs, rhs, .. } => {
+                self.bind_pattern(lhs, ty.clone(), scope);
+                self.bind_pattern(rhs, ty, scope);
+            }
+
+            Pattern::Tuple(patterns) => {
+                let types = match ty {
+                    Type::Tuple(types) => types,
+                    _ => vec![],
+                };
+
+                for (i, pattern) in (&patterns.inner).into_iter().enumerate() {
+                    self.bind_pattern(pattern, types.get(i).cloned().unwrap_or_default(), scope);
+                }
+            }
+
+            Pattern::Constructor { path, args } => {
+                let variant = last_segment(path).name.as_str();
+
+                let field_type = match (variant, ty.name()) {
+                    ("Some", Some("Option")) | ("Ok", Some("Result")) => ty.generic_args().first().cloned(),
+                    ("Err", Some("Result")) => ty.generic_args().get(1).cloned(),
+                    (_, Some(name)) => self.enums.get(name).and_then(|definition| definition.field_type(&ty, variant)),
+                    _ => None,
+                };
+
+                let args = (&args.inner).into_iter().collect::<Vec<_>>();
+
+                match (field_type, args.as_slice()) {
+                    (Some(field_type), [arg]) => self.bind_pattern(arg, field_type, scope),
+                    (Some(Type::Tuple(types)), args) => {
+                        for (i, arg) in args.iter().enumerate() {
+                            self.bind_pattern(arg, types.get(i).cloned().unwrap_or_default(), scope);
+                        }
+                    }
+                    (_, args) => {
+                        for arg in args {
+                            self.bind_pattern(arg, Type::Unknown, scope);
+                        }
+                    }
+                }
+            }
+
+            Pattern::Struct { path, fields } => {
+                let name = last_segment(path).name.as_str();
+                let struct_type = if ty.is(name) { ty.clone() } else { Type::named(name) };
+
+                for field in (&fields.inner).into_iter() {
+                    let PatternStructField::Field { field_name, pattern_opt } = field else { continue };
+
+                    let field_type = self.structs.get(name)
+                        .and_then(|definition| definition.field_type(&struct_type, field_name.as_str()))
+                        .unwrap_or_default();
+
+                    match pattern_opt {
+                        Some((_, pattern)) => self.bind_pattern(pattern.as_ref(), field_type, scope),
+                        None => { scope.insert(field_name.as_str().to_string(), field_type); }
+                    }
+                }
+            }
+
+            Pattern::Wildcard { .. } | Pattern::Literal(_) | Pattern::Constant(_) | Pattern::Error(..) => {}
+        }
+    }
+
+    fn infer_block(&mut self, block: &CodeBlockContents, scopes: &mut Vec<HashMap<String, Type>>) -> Type {
+        scopes.push(HashMap::new());
+
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Let(statement_let) => {
+                    let expr_type = self.infer_expr(&statement_let.expr, scopes);
+                    let ty = statement_let.ty_opt.as_ref().map(|(_, ty)| self.resolve_ty(ty)).unwrap_or(expr_type);
+                    self.bind_pattern(&statement_let.pattern, ty, scopes.last_mut().unwrap());
+                }
+
+                Statement::Expr { expr, .. } => {
+                    self.infer_expr(expr, scopes);
+                }
+
+                Statement::Item(_) | Statement::Error(..) => {}
+            }
+        }
+
+        let result = block.final_expr_opt.as_ref().map(|expr| self.infer_expr(expr, scopes)).unwrap_or_else(Type::unit);
+
+        scopes.pop();
+
+        result
+    }
+
+    fn infer_if(&mut self, if_expr: &IfExpr, scopes: &mut Vec<HashMap<String, Type>>) -> Type {
+        let mut scope = HashMap::new();
+
+        match &if_expr.condition {
+            IfCondition::Expr(expr) => {
+                self.infer_expr(expr, scopes);
+            }
+
+            IfCondition::Let { lhs, rhs, .. } => {
+                let ty = self.infer_expr(rhs, scopes);
+                self.bind_pattern(lhs, ty, &mut scope);
+            }
+        }
+
+        scopes.push(scope);
+        let then_type = self.infer_block(&if_expr.then_block.inner, scopes);
+        scopes.pop();
+
+        let else_type = match if_expr.else_opt.as_ref() {
+            Some((_, LoopControlFlow::Continue(if_expr))) => self.infer_if(if_expr, scopes),
+            Some((_, LoopControlFlow::Break(block))) => self.infer_block(&block.inner, scopes),
+            None => return Type::unit(),
+        };
+
+        if then_type.is_unknown() { else_type } else { then_type }
+    }
+
+    fn infer_asm_block(&mut self, asm: &AsmBlock, scopes: &mut Vec<HashMap<String, Type>>) -> Type {
+        for register in (&asm.registers.inner).into_iter() {
+            if let Some((_, value)) = register.value_opt.as_ref() {
+                self.infer_expr(value, scopes);
+            }
+        }
+
+        match asm.contents.inner.final_expr_opt.as_ref() {
+            Some(final_expr) => final_expr.ty_opt.as_ref().map(|(_, ty)| self.resolve_ty(ty)).unwrap_or_else(|| Type::named("u64")),
+            None => Type::unit(),
+        }
+    }
+
+    /// Gets the signatures from the supplied `candidates` declared in the module being inferred.
+    ///
+    /// Falls back to signatures declared in another module if they all belong to the same module, since the candidates are otherwise ambiguous.
+    fn visible_signatures<'s>(&self, candidates: Option<&'s Vec<(usize, Signature)>>) -> Vec<&'s Signature> {
+        let candidates = candidates.map(Vec::as_slice).unwrap_or_default();
+
+        let local = candidates.iter()
+            .filter(|(module, _)| *module == self.module)
+            .map(|(_, signature)| signature)
+            .collect::<Vec<_>>();
+
+        if !local.is_empty() {
+            return local;
+        }
+
+        match candidates.first() {
+            Some((module, _)) if candidates.iter().all(|(x, _)| x == module) => candidates.iter().map(|(_, signature)| signature).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Gets the return type of a call to the supplied function `path` with arguments of the supplied `args` types.
+    fn infer_call(&self, path: &PathExpr, args: &[Type]) -> Type {
+        let segments = std::iter::once(&path.prefix)
+            .chain(path.suffix.iter().map(|(_, segment)| segment))
+            .map(|segment| segment.name.as_str())
+            .collect::<Vec<_>>();
+
+        let name = segments[segments.len() - 1];
+        let parent = segments.len().checked_sub(2).map(|i| segments[i]);
+
+        if let Some(parent) = parent {
+            // Enum variant constructors
+            if let Some(definition) = self.enums.get(parent) {
+                let vars = definition.generic_params.clone();
+                let enum_type = Type::Named(parent.into(), vars.iter().map(|x| Type::named(x.as_str())).collect());
+
+                let signature = Signature {
+                    vars,
+                    receiver: None,
+                    params: definition.fields.get(name).map(|field| vec![field.clone()]).unwrap_or_default(),
+                    ret: enum_type,
+                };
+
+                return signature.apply(None, args).unwrap_or_default();
+            }
+
+            // Associated functions
+            let signatures = self.visible_signatures(self.methods.get(&(parent.to_string(), name.to_string())));
+
+            if !signatures.is_empty() {
+                return signatures.iter().find_map(|signature| signature.apply(None, args)).unwrap_or_default();
+            }
+        } else {
+            let signatures = self.visible_signatures(self.functions.get(name));
+
+            if !signatures.is_empty() {
+                return signatures.iter().find_map(|signature| signature.apply(None, args)).unwrap_or_default();
+            }
+        }
+
+        let path = parent.map(|parent| format!("{parent}::{name}")).unwrap_or_else(|| name.to_string());
+
+        STD_FUNCTIONS.iter()
+            .find(|(std_path, _, _)| *std_path == path || *std_path == name)
+            .and_then(|(_, params, ret)| Signature::from_std(None, params, ret).apply(None, args))
+            .unwrap_or_default()
+    }
+
+    /// Gets the return type of a call to the method called `name` on a receiver of the supplied `receiver` type.
+    fn infer_method_call(&self, receiver: &Type, name: &str, args: &[Type]) -> Type {
+        let signatures = self.visible_signatures(receiver.name().and_then(|receiver_name| self.methods.get(&(receiver_name.to_string(), name.to_string()))));
+
+        // Impls for different generic arguments of the same type are distinguished by their receivers
+        if !signatures.is_empty() {
+            return signatures.iter().find_map(|signature| signature.apply(Some(receiver), args)).unwrap_or_default();
+        }
+
+        STD_METHODS.iter()
+            .filter(|(_, method, _, _)| *method == name)
+            .find_map(|(std_receiver, _, params, ret)| Signature::from_std(Some(std_receiver), params, ret).apply(Some(receiver), args))
+            .unwrap_or_default()
+    }
+
+    fn infer_exprs<'e, I: IntoIterator<Item = &'e Expr>>(&mut self, exprs: I, scopes: &mut Vec<HashMap<String, Type>>) -> Vec<Type> {
+        exprs.into_iter().map(|expr| self.infer_expr(expr, scopes)).collect()
+    }
+
+    fn infer_expr(&mut self, expr: &Expr, scopes: &mut Vec<HashMap<String, Type>>) -> Type {
+        let result = match expr {
+            Expr::Error(..) => Type::Unknown,
+
+            Expr::Path(path) => {
+                let name = path.prefix.name.as_str();
+
+                if path.root_opt.is_some() {
+                    Type::Unknown
+                } else if path.suffix.is_empty() {
+                    scopes.iter().rev()
+                        .find_map(|scope| scope.get(name).cloned())
+                        .or_else(|| self.constants.get(&(self.module, name.to_string())).cloned())
+                        .or_else(|| (name == "None").then(|| Type::Named("Option".into(), vec![Type::Unknown])))
+                        .unwrap_or_default()
+                } else {
+                    // Unit enum variants
+                    self.infer_call(path, &[])
+                }
+            }
+
+            Expr::Literal(literal) => match literal {
+                Literal::String(_) => Type::named("str"),
+                Literal::Char(_) => Type::named("char"),
+                Literal::Bool(_) => Type::named("bool"),
+                Literal::Int(lit_int) => Type::named(match lit_int.ty_opt.as_ref().map(|(ty, _)| ty) {
+                    Some(LitIntType::U8) => "u8",
+                    Some(LitIntType::U16) => "u16",
+                    Some(LitIntType::U32) => "u32",
+                    Some(LitIntType::U256) => "u256",
+                    Some(LitIntType::I8) => "i8",
+                    Some(LitIntType::I16) => "i16",
+                    Some(LitIntType::I32) => "i32",
+                    Some(LitIntType::I64) => "i64",
+                    Some(LitIntType::U64) | None => "u64",
+                }),
+            },
+
+            Expr::AbiCast { args, .. } => {
+                self.infer_expr(&args.inner.address, scopes);
+                Type::named(args.inner.name.last_segment().name.as_str())
+            }
+
+            Expr::Struct { path, fields } => {
+                let name = last_segment(path).name.as_str().to_string();
+                let mut bindings = HashMap::new();
+
+                for field in (&fields.inner).into_iter() {
+                    let field_type = match field.expr_opt.as_ref() {
+                        Some((_, expr)) => self.infer_expr(expr, scopes),
+                        None => scopes.iter().rev().find_map(|scope| scope.get(field.field_name.as_str()).cloned()).unwrap_or_default(),
+                    };
+
+                    if let Some(definition) = self.structs.get(&name) {
+                        if let Some(declared_type) = definition.fields.get(field.field_name.as_str()) {
+                            declared_type.unify(&field_type, &definition.generic_params, &mut bindings);
+                        }
+                    }
+                }
+
+                let generic_params = self.structs.get(&name).map(|definition| definition.generic_params.clone()).unwrap_or_default();
+                Type::Named(name, generic_params.iter().map(|x| bindings.get(x).cloned().unwrap_or_default()).collect())
+            }
+
+            Expr::Tuple(tuple) => match &tuple.inner {
+                ExprTupleDescriptor::Nil => Type::unit(),
+                ExprTupleDescriptor::Cons { head, tail, .. } => {
+                    Type::Tuple(self.infer_exprs(std::iter::once(head.as_ref()).chain(tail), scopes))
+                }
+            },
+
+            Expr::Parens(expr) => self.infer_expr(&expr.inner, scopes),
+
+            Expr::Block(block) => self.infer_block(&block.inner, scopes),
+
+            Expr::Array(array) => match &array.inner {
+                ExprArrayDescriptor::Sequence(exprs) => {
+                    let types = self.infer_exprs(exprs, scopes);
+                    Type::Array(Box::new(types.into_iter().find(|x| !x.is_unknown()).unwrap_or_default()))
+                }
+
+                ExprArrayDescriptor::Repeat { value, length, .. } => {
+                    self.infer_expr(length, scopes);
+                    Type::Array(Box::new(self.infer_expr(value, scopes)))
+                }
+            },
+
+            Expr::Asm(asm) => self.infer_asm_block(asm, scopes),
+
+            Expr::Return { expr_opt, .. } => {
+                if let Some(expr) = expr_opt.as_ref() {
+                    self.infer_expr(expr, scopes);
+                }
+
+                Type::unit()
+            }
+
+            Expr::If(if_expr) => self.infer_if(if_expr, scopes),
+
+            Expr::Match { value, branches, .. } => {
+                let value_type = self.infer_expr(value, scopes);
+                let mut result = Type::Unknown;
+
+                for branch in branches.inner.iter() {
+                    let mut scope = HashMap::new();
+                    self.bind_pattern(&branch.pattern, value_type.clone(), &mut scope);
+                    scopes.push(scope);
+
+                    let branch_type = match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => self.infer_block(&block.inner, scopes),
+                        MatchBranchKind::Expr { expr, .. } => self.infer_expr(expr, scopes),
+                    };
+
+                    scopes.pop();
+
+                    if result.is_unknown() {
+                        result = branch_type;
+                    }
+                }
+
+                result
+            }
+
+            Expr::While { condition, block, .. } => {
+                self.infer_expr(condition, scopes);
+                self.infer_block(&block.inner, scopes);
+                Type::unit()
+            }
+
+            Expr::FuncApp { func, args } => {
+                self.infer_expr(func, scopes);
+                let arg_types = self.infer_exprs(&args.inner, scopes);
+
+                match func.as_ref() {
+                    Expr::Path(path) => self.infer_call(path, &arg_types),
+                    _ => Type::Unknown,
+                }
+            }
+
+            Expr::Index { target, arg } => {
+                let target_type = self.infer_expr(target, scopes);
+                self.infer_expr(&arg.inner, scopes);
+
+                match target_type {
+                    Type::Array(element) => *element,
+                    _ => Type::Unknown,
+                }
+            }
+
+            Expr::MethodCall { target, path_seg, contract_args_opt, args, .. } => {
+                let target_type = self.infer_expr(target, scopes);
+
+                if let Some(contract_args) = contract_args_opt.as_ref() {
+                    for field in (&contract_args.inner).into_iter() {
+                        if let Some((_, expr)) = field.expr_opt.as_ref() {
+                            self.infer_expr(expr, scopes);
+                        }
+                    }
+                }
+
+                let arg_types = self.infer_exprs(&args.inner, scopes);
+
+                self.infer_method_call(&target_type, path_seg.name.as_str(), &arg_types)
+            }
+
+            Expr::FieldProjection { target, name, .. } => {
+                let target_type = self.infer_expr(target, scopes);
+
+                match target.as_ref() {
+                    // Storage fields are accessed through storage keys
+                    Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() && path.prefix.name.as_str() == "storage" => {
+                        self.storage_fields.get(&(self.module, name.to_string()))
+                            .map(|ty| Type::Named("StorageKey".into(), vec![ty.clone()]))
+                            .unwrap_or_default()
+                    }
+
+                    _ => target_type.name()
+                        .and_then(|struct_name| self.structs.get(struct_name))
+                        .and_then(|definition| definition.field_type(&target_type, name.as_str()))
+                        .unwrap_or_default(),
+                }
+            }
+
+            Expr::TupleFieldProjection { target, field, .. } => match self.infer_expr(target, scopes) {
+                Type::Tuple(types) => usize::try_from(field).ok().and_then(|i| types.get(i).cloned()).unwrap_or_default(),
+                _ => Type::Unknown,
+            },
+
+            Expr::Ref { expr, .. } | Expr::Deref { expr, .. } | Expr::Not { expr, .. } => self.infer_expr(expr, scopes),
+
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. } => {
+                let lhs_type = self.infer_expr(lhs, scopes);
+                let rhs_type = self.infer_expr(rhs, scopes);
+                if lhs_type.is_unknown() { rhs_type } else { lhs_type }
+            }
+
+            Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. }
+            | Expr::LogicalAnd { lhs, rhs, .. }
+            | Expr::LogicalOr { lhs, rhs, .. } => {
+                self.infer_expr(lhs, scopes);
+                self.infer_expr(rhs, scopes);
+                Type::named("bool")
+            }
+
+            Expr::Reassignment { expr, .. } => {
+                self.infer_expr(expr, scopes);
+                Type::unit()
+            }
+
+            Expr::Break { .. } | Expr::Continue { .. } => Type::unit(),
+        };
+
+        self.expr_types.insert(expr_key(expr), result.clone());
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_type_of() {
+        let source = r#"contract;
+
+use std::storage::storage_map::*;
+
+type Balance = u64;
+
+struct Pair<T> {
+    first: T,
+    second: bool,
+}
+
+enum Action {
+    Deposit: Balance,
+    Withdraw: (Identity, u64),
+}
+
+storage {
+    total: Balance = 0,
+    balances: StorageMap<Identity, u64> = StorageMap {},
+}
+
+fn pair(x: u64) -> Pair<u64> {
+    Pair { first: x, second: true }
+}
+
+impl Pair<u64> {
+    fn sum(self) -> u64 {
+        self.first
+    }
+}
+
+#[storage(read)]
+fn f(action: Action) -> u64 {
+    let sender = msg_sender().unwrap();
+    let total = storage.total.read();
+    let balance = storage.balances.get(sender).read();
+    let p = pair(total);
+    let (a, b) = (p.first, p.second);
+    let amount = match action {
+        Action::Deposit(amount) => amount,
+        Action::Withdraw((to, amount)) => amount,
+    };
+    let q = Pair { first: sender, second: false };
+    let identity = q.first;
+    let sum = p.sum() + 1u8;
+    if let Some(address) = sender.as_address() {
+        address.bits();
+    }
+    let values = [a, 1];
+    values[0]
+}
+"#;
+
+        let types = crate::tests::collect_expr_results(source, |context, project| project.type_of(context.expr).to_string());
+
+        for (expr, expected) in [
+            ("msg_sender()", "Result<Identity, AuthError>"),
+            ("msg_sender().unwrap()", "Identity"),
+            ("storage.total", "StorageKey<u64>"),
+            ("storage.total.read()", "u64"),
+            ("storage.balances.get(sender)", "StorageKey<u64>"),
+            ("storage.balances.get(sender).read()", "u64"),
+            ("pair(total)", "Pair<u64>"),
+            ("(p.first, p.second)", "(u64, bool)"),
+            ("Pair { first: x, second: true }", "Pair<u64>"),
+            ("Pair { first: sender, second: false }", "Pair<Identity>"),
+            ("q.first", "Identity"),
+            ("p.sum()", "u64"),
+            ("p.sum() + 1u8", "u64"),
+            ("sender.as_address()", "Option<Address>"),
+            ("address.bits()", "b256"),
+            ("[a, 1]", "[u64]"),
+            ("values[0]", "u64"),
+            ("self.first", "u64"),
+            ("amount", "u64"),
+            ("action", "Action"),
+        ] {
+            assert_eq!(types.get(expr).map(String::as_str), Some(expected), "Unexpected type for `{expr}`");
+        }
+
+        assert_eq!(types["match action {\n        Action::Deposit(amount) => amount,\n        Action::Withdraw((to, amount)) => amount,\n    }"], "u64");
+    }
+
+    #[test]
+    fn test_type_of_per_module() {
+        let a = r#"library;
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+impl Wrapper<u64> {
+    fn get(self) -> u64 {
+        self.inner
+    }
+}
+
+impl Wrapper<bool> {
+    fn get(self) -> bool {
+        self.inner
+    }
+}
+
+fn value() -> u64 {
+    1
+}
+
+fn a(w: Wrapper<bool>) {
+    let x = value();
+    let y = w.get();
+}
+"#;
+
+        let b = r#"library;
+
+fn value() -> bool {
+    true
+}
+
+fn b() {
+    let x = value( );
+}
+"#;
+
+        let mut project = crate::tests::parse_modules(&[("a.sw", a), ("b.sw", b)]);
+        let types = crate::tests::collect_project_expr_results(&mut project, |context, project| project.type_of(context.expr).to_string());
+
+        assert_eq!(types["value()"], "u64");
+        assert_eq!(types["value( )"], "bool");
+        assert_eq!(types["w.get()"], "bool");
+    }
+}
@@ -1320,6 +1320,82 @@ pub fn use_tree_to_name(mut use_tree: &UseTree, path: &str) -> Option<String> {
     }
 }
 
+/// Folds the supplied `use_tree` into a list of `(local_name, full_path)` pairs for each imported symbol, prefixed with `path`.
+pub fn fold_use_tree_imports(use_tree: &UseTree, path: &str) -> Vec<(String, String)> {
+    let join = |name: &str| if path.is_empty() { name.to_string() } else { format!("{path}::{name}") };
+
+    let mut result = vec![];
+
+    match use_tree {
+        UseTree::Group { imports } => {
+            for import in fold_punctuated(&imports.inner) {
+                result.extend(fold_use_tree_imports(import, path));
+            }
+        }
+
+        UseTree::Name { name } if name.as_str() == "self" => {
+            if let Some(local_name) = path.rsplit("::").next() {
+                result.push((local_name.to_string(), path.to_string()));
+            }
+        }
+
+        UseTree::Name { name } => {
+            result.push((name.as_str().to_string(), join(name.as_str())));
+        }
+
+        UseTree::Rename { name, alias, .. } => {
+            result.push((alias.as_str().to_string(), join(name.as_str())));
+        }
+
+        UseTree::Path { prefix, suffix, .. } => {
+            result.extend(fold_use_tree_imports(suffix.as_ref(), &join(prefix.as_str())));
+        }
+
+        UseTree::Glob { .. } | UseTree::Error { .. } => {}
+    }
+
+    result
+}
+
+/// Resolves the supplied `path` expression to a full path string using the supplied `imports` from `fold_use_tree_imports`.
+pub fn resolve_path_expr(path: &PathExpr, imports: &[(String, String)]) -> String {
+    let idents = fold_path_idents(path);
+    let mut segments = idents.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+
+    let mut result = String::new();
+
+    if let Some((_, full_path)) = imports.iter().find(|(local_name, _)| local_name == segments[0]) {
+        result.push_str(full_path);
+        segments.remove(0);
+    }
+
+    for segment in segments {
+        if !result.is_empty() {
+            result.push_str("::");
+        }
+
+        result.push_str(segment);
+    }
+
+    result
+}
+
+pub fn is_storage_write_fn_name(s: &str) -> bool {
+    is_storage_bytes_write_fn(s)
+        || is_storage_key_write_fn(s)
+        || is_storage_map_write_fn(s)
+        || is_storage_string_write_fn(s)
+        || is_storage_vec_write_fn(s)
+}
+
+/// Checks if the supplied function name transfers, mints or burns native assets.
+pub fn is_asset_fn_name(s: &str) -> bool {
+    matches!(
+        s,
+        "transfer" | "transfer_to_address" | "force_transfer_to_contract" | "mint" | "mint_to" | "mint_to_address" | "mint_to_contract" | "burn"
+    )
+}
+
 pub fn expr_binary_operands(expr: &Expr) -> Option<(&Expr, &Expr)> {
     match expr {
         Expr::Mul { lhs, rhs, .. } => Some((lhs.as_ref(), rhs.as_ref())),
@@ -1352,3 +1428,25 @@ pub fn expr_negation_result(expr: &Expr) -> bool {
         _ => true,
     }
 }
+
+/// A key identifying an expression by its source text and position, since spans only compare positions.
+pub type ExprKey = (usize, usize, usize);
+
+pub fn expr_key(expr: &Expr) -> ExprKey {
+    span_key(&expr.span())
+}
+
+pub fn span_key(span: &Span) -> ExprKey {
+    (source_key(span), span.start(), span.end())
+}
+
+/// Gets the name of the supplied type without its path or generic arguments, i.e: `Vec` for `std::vec::Vec<u64>`.
+pub fn base_type_name(name: &str) -> &str {
+    let name = name.split('<').next().unwrap_or_default().trim();
+    name.rsplit("::").next().unwrap_or_default()
+}
+
+/// Gets a key identifying the source file containing the supplied `span`, which distinguishes declarations of the same name in different modules.
+pub fn source_key(span: &Span) -> usize {
+    std::sync::Arc::as_ptr(span.src()) as *const u8 as usize
+}
@@ -1,7 +1,132 @@
 use crate::{error::Error, project::Project};
-use std::path::Path;
-use sway_ast::{expr::asm::AsmFinalExpr, *};
-use sway_types::{Span, Spanned};
+use std::{
+    path::Path,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+use sway_ast::{attribute::{Annotated, Attribute}, expr::asm::AsmFinalExpr, ty::TyTupleDescriptor, *};
+use sway_types::{Ident, Span, Spanned};
+
+/// The version of the context structs and the `AstVisitor` trait, used to reject plugins built against a different layout.
+///
+/// Must be incremented whenever a context struct, the `AstVisitor` trait or the `Report` API changes.
+pub const CONTEXT_VERSION: u32 = 5;
+
+/// A reference to a node enclosing the node being visited.
+#[derive(Clone, Copy)]
+pub enum Ancestor<'a> {
+    Item(&'a ItemKind),
+    Fn(&'a ItemFn),
+    Statement(&'a Statement),
+    Expr(&'a Expr),
+    /// An `else if` expression, which is not wrapped in an `Expr`.
+    IfExpr(&'a IfExpr),
+    MatchBranch(&'a MatchBranch),
+    FnArg(&'a FnArg),
+    Pattern(&'a Pattern),
+    Ty(&'a Ty),
+}
+
+impl Spanned for Ancestor<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Ancestor::Item(item) => item.span(),
+            Ancestor::Fn(item_fn) => item_fn.span(),
+            Ancestor::Statement(statement) => statement.span(),
+            Ancestor::Expr(expr) => expr.span(),
+            Ancestor::IfExpr(if_expr) => if_expr.span(),
+            Ancestor::MatchBranch(branch) => branch.span(),
+            Ancestor::FnArg(fn_arg) => fn_arg.span(),
+            Ancestor::Pattern(pattern) => pattern.span(),
+            Ancestor::Ty(ty) => ty.span(),
+        }
+    }
+}
+
+/// The chain of nodes enclosing the node being visited, where each link borrows the chain of the enclosing context.
+#[derive(Clone, Copy, Default)]
+pub struct Ancestors<'a> {
+    innermost: Option<Ancestor<'a>>,
+    outer: Option<&'a Ancestors<'a>>,
+}
+
+impl<'a> Ancestors<'a> {
+    /// Creates a chain linked to this one with `ancestor` as the innermost node.
+    pub fn with<'b>(&'b self, ancestor: Ancestor<'b>) -> Ancestors<'b> where 'a: 'b {
+        Ancestors {
+            innermost: Some(ancestor),
+            outer: Some(self),
+        }
+    }
+
+    /// Iterates over the enclosing nodes, starting from the innermost node.
+    pub fn iter(&self) -> impl Iterator<Item = &Ancestor<'a>> {
+        std::iter::successors(Some(self), |ancestors| ancestors.outer).filter_map(|ancestors| ancestors.innermost.as_ref())
+    }
+
+    /// Gets the innermost enclosing node.
+    pub fn parent(&self) -> Option<&Ancestor<'a>> {
+        self.innermost.as_ref()
+    }
+
+    /// Gets the innermost enclosing expression.
+    pub fn parent_expr(&self) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| match ancestor {
+            Ancestor::Expr(expr) => Some(*expr),
+            _ => None,
+        })
+    }
+
+    /// Gets the innermost enclosing `while` expression.
+    pub fn enclosing_loop(&self) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| match ancestor {
+            Ancestor::Expr(expr @ Expr::While { .. }) => Some(*expr),
+            _ => None,
+        })
+    }
+
+    /// Gets the innermost enclosing function application or method call.
+    pub fn enclosing_call(&self) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| match ancestor {
+            Ancestor::Expr(expr @ (Expr::FuncApp { .. } | Expr::MethodCall { .. })) => Some(*expr),
+            _ => None,
+        })
+    }
+
+    /// Gets the innermost enclosing `while` expression whose condition contains the supplied `span`.
+    pub fn enclosing_loop_condition(&self, span: &Span) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| match ancestor {
+            Ancestor::Expr(expr @ Expr::While { condition, .. }) if contains_span(&condition.span(), span) => Some(*expr),
+            _ => None,
+        })
+    }
+
+    /// Gets the innermost enclosing `match` expression whose scrutinee contains the supplied `span`.
+    pub fn enclosing_match_scrutinee(&self, span: &Span) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| match ancestor {
+            Ancestor::Expr(expr @ Expr::Match { value, .. }) if contains_span(&value.span(), span) => Some(*expr),
+            _ => None,
+        })
+    }
+
+    /// Gets the innermost enclosing function application or method call with an argument containing the supplied `span`.
+    pub fn enclosing_call_argument(&self, span: &Span) -> Option<&'a Expr> {
+        self.iter().find_map(|ancestor| {
+            let Ancestor::Expr(expr) = ancestor else { return None };
+
+            let args = match expr {
+                Expr::FuncApp { args, .. } | Expr::MethodCall { args, .. } => args,
+                _ => return None,
+            };
+
+            (&args.inner).into_iter().any(|arg| contains_span(&arg.span(), span)).then_some(*expr)
+        })
+    }
+}
+
+fn contains_span(outer: &Span, inner: &Span) -> bool {
+    outer.start() <= inner.start() && inner.end() <= outer.end()
+}
 
 #[derive(Clone)]
 pub struct ModuleContext<'a> {
@@ -13,6 +138,7 @@ pub struct ModuleContext<'a> {
 pub struct ItemContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub attributes: &'a [AttributeDecl],
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
@@ -27,6 +153,7 @@ pub struct ItemContext<'a> {
 pub struct SubmoduleContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub submodule: &'a Submodule,
@@ -36,6 +163,7 @@ pub struct SubmoduleContext<'a> {
 pub struct UseContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_use: &'a ItemUse,
@@ -45,6 +173,7 @@ pub struct UseContext<'a> {
 pub struct StructContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub struct_attributes: &'a [AttributeDecl],
     pub item_struct: &'a ItemStruct,
@@ -54,6 +183,7 @@ pub struct StructContext<'a> {
 pub struct StructFieldContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub struct_attributes: &'a [AttributeDecl],
     pub item_struct: &'a ItemStruct,
@@ -65,6 +195,7 @@ pub struct StructFieldContext<'a> {
 pub struct EnumContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_enum: &'a ItemEnum,
@@ -74,6 +205,7 @@ pub struct EnumContext<'a> {
 pub struct EnumFieldContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub enum_attributes: &'a [AttributeDecl],
     pub item_enum: &'a ItemEnum,
@@ -85,6 +217,7 @@ pub struct EnumFieldContext<'a> {
 pub struct FnContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -96,6 +229,7 @@ pub struct FnContext<'a> {
 pub struct StatementContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -109,6 +243,7 @@ pub struct StatementContext<'a> {
 pub struct StatementLetContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -123,6 +258,7 @@ pub struct StatementLetContext<'a> {
 pub struct ExprContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -137,6 +273,7 @@ pub struct ExprContext<'a> {
 pub struct BlockContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -152,6 +289,7 @@ pub struct BlockContext<'a> {
 pub struct AsmBlockContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -167,6 +305,7 @@ pub struct AsmBlockContext<'a> {
 pub struct AsmInstructionContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -183,6 +322,7 @@ pub struct AsmInstructionContext<'a> {
 pub struct AsmFinalExprContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -199,6 +339,7 @@ pub struct AsmFinalExprContext<'a> {
 pub struct IfExprContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -214,6 +355,7 @@ pub struct IfExprContext<'a> {
 pub struct MatchExprContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -230,6 +372,7 @@ pub struct MatchExprContext<'a> {
 pub struct MatchBranchContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -246,6 +389,7 @@ pub struct MatchBranchContext<'a> {
 pub struct WhileExprContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -262,6 +406,7 @@ pub struct WhileExprContext<'a> {
 pub struct TraitContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_trait: &'a ItemTrait,
@@ -271,6 +416,7 @@ pub struct TraitContext<'a> {
 pub struct ImplContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_impl: &'a ItemImpl,
@@ -282,6 +428,7 @@ pub struct ImplContext<'a> {
 pub struct AbiContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_abi: &'a ItemAbi,
@@ -291,6 +438,7 @@ pub struct AbiContext<'a> {
 pub struct ConstContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub impl_attributes: Option<&'a [AttributeDecl]>,
     pub item_impl: Option<&'a ItemImpl>,
@@ -306,6 +454,7 @@ pub struct ConstContext<'a> {
 pub struct StorageContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_storage: &'a ItemStorage,
@@ -315,6 +464,7 @@ pub struct StorageContext<'a> {
 pub struct StorageFieldContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub storage_attributes: &'a [AttributeDecl],
     pub item_storage: &'a ItemStorage,
@@ -326,6 +476,7 @@ pub struct StorageFieldContext<'a> {
 pub struct ConfigurableContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_configurable: &'a ItemConfigurable,
@@ -335,6 +486,7 @@ pub struct ConfigurableContext<'a> {
 pub struct ConfigurableFieldContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub configurable_attributes: &'a [AttributeDecl],
     pub item_configurable: &'a ItemConfigurable,
@@ -346,6 +498,7 @@ pub struct ConfigurableFieldContext<'a> {
 pub struct TypeAliasContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_type_alias: &'a ItemTypeAlias,
@@ -355,11 +508,62 @@ pub struct TypeAliasContext<'a> {
 pub struct TraitTypeContext<'a> {
     pub path: &'a Path,
     pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
     pub item: &'a ItemKind,
     pub attributes: &'a [AttributeDecl],
     pub item_type: &'a TraitType,
 }
 
+#[derive(Clone)]
+pub struct AttributeContext<'a> {
+    pub path: &'a Path,
+    pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
+    pub item: &'a ItemKind,
+    pub attribute_decl: &'a AttributeDecl,
+    pub attribute: &'a Attribute,
+}
+
+#[derive(Clone)]
+pub struct FnArgContext<'a> {
+    pub path: &'a Path,
+    pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
+    pub item: &'a ItemKind,
+    pub fn_signature: &'a FnSignature,
+    pub fn_arg: &'a FnArg,
+}
+
+#[derive(Clone)]
+pub struct PatternContext<'a> {
+    pub path: &'a Path,
+    pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
+    pub item: &'a ItemKind,
+    pub pattern: &'a Pattern,
+}
+
+#[derive(Clone)]
+pub struct TypeContext<'a> {
+    pub path: &'a Path,
+    pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
+    pub item: &'a ItemKind,
+    pub ty: &'a Ty,
+}
+
+#[derive(Clone)]
+pub struct GenericParamContext<'a> {
+    pub path: &'a Path,
+    pub module: &'a Module,
+    pub ancestors: Ancestors<'a>,
+    pub item: &'a ItemKind,
+    pub generic_params: &'a GenericParams,
+    pub name: &'a Ident,
+    /// The bounds of the `where` clause constraining the generic parameter.
+    pub where_bounds: Vec<&'a WhereBound>,
+}
+
 #[allow(unused_variables)]
 pub trait AstVisitor {
     fn visit_module(&mut self, context: &ModuleContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
@@ -451,78 +655,411 @@ pub trait AstVisitor {
 
     fn visit_trait_type(&mut self, context: &TraitTypeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
     fn leave_trait_type(&mut self, context: &TraitTypeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+
+    fn visit_attribute(&mut self, context: &AttributeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+    fn leave_attribute(&mut self, context: &AttributeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+
+    fn visit_fn_arg(&mut self, context: &FnArgContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+    fn leave_fn_arg(&mut self, context: &FnArgContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+
+    fn visit_pattern(&mut self, context: &PatternContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+    fn leave_pattern(&mut self, context: &PatternContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+
+    fn visit_type(&mut self, context: &TypeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+    fn leave_type(&mut self, context: &TypeContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+
+    fn visit_generic_param(&mut self, context: &GenericParamContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
+    fn leave_generic_param(&mut self, context: &GenericParamContext, project: &mut Project) -> Result<(), Error> { Ok(()) }
 }
 
+/// The location shared by the contexts of nodes which are visited from several kinds of parent nodes.
+#[derive(Clone)]
+struct NodeLocation<'a> {
+    path: &'a Path,
+    module: &'a Module,
+    ancestors: Ancestors<'a>,
+    item: &'a ItemKind,
+}
+
+/// A closure called by `AstVisitorRecursive` when visiting or leaving a node, alongside its visitors.
+pub type ModuleHook<'a> = Box<dyn FnMut(&ModuleContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ItemHook<'a> = Box<dyn FnMut(&ItemContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type SubmoduleHook<'a> = Box<dyn FnMut(&SubmoduleContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type UseHook<'a> = Box<dyn FnMut(&UseContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StructHook<'a> = Box<dyn FnMut(&StructContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StructFieldHook<'a> = Box<dyn FnMut(&StructFieldContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type EnumHook<'a> = Box<dyn FnMut(&EnumContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type EnumFieldHook<'a> = Box<dyn FnMut(&EnumFieldContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type FnHook<'a> = Box<dyn FnMut(&FnContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StatementHook<'a> = Box<dyn FnMut(&StatementContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StatementLetHook<'a> = Box<dyn FnMut(&StatementLetContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ExprHook<'a> = Box<dyn FnMut(&ExprContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type BlockHook<'a> = Box<dyn FnMut(&BlockContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type AsmBlockHook<'a> = Box<dyn FnMut(&AsmBlockContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type AsmInstructionHook<'a> = Box<dyn FnMut(&AsmInstructionContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type AsmFinalExprHook<'a> = Box<dyn FnMut(&AsmFinalExprContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type IfExprHook<'a> = Box<dyn FnMut(&IfExprContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type MatchExprHook<'a> = Box<dyn FnMut(&MatchExprContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type MatchBranchHook<'a> = Box<dyn FnMut(&MatchBranchContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type WhileExprHook<'a> = Box<dyn FnMut(&WhileExprContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type TraitHook<'a> = Box<dyn FnMut(&TraitContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ImplHook<'a> = Box<dyn FnMut(&ImplContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type AbiHook<'a> = Box<dyn FnMut(&AbiContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ConstHook<'a> = Box<dyn FnMut(&ConstContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StorageHook<'a> = Box<dyn FnMut(&StorageContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type StorageFieldHook<'a> = Box<dyn FnMut(&StorageFieldContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ConfigurableHook<'a> = Box<dyn FnMut(&ConfigurableContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type ConfigurableFieldHook<'a> = Box<dyn FnMut(&ConfigurableFieldContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type TypeAliasHook<'a> = Box<dyn FnMut(&TypeAliasContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type TraitTypeHook<'a> = Box<dyn FnMut(&TraitTypeContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type AttributeHook<'a> = Box<dyn FnMut(&AttributeContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type FnArgHook<'a> = Box<dyn FnMut(&FnArgContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type PatternHook<'a> = Box<dyn FnMut(&PatternContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type TypeHook<'a> = Box<dyn FnMut(&TypeContext, &mut Project) -> Result<(), Error> + 'a>;
+pub type GenericParamHook<'a> = Box<dyn FnMut(&GenericParamContext, &mut Project) -> Result<(), Error> + 'a>;
+
 #[derive(Default)]
 pub struct AstVisitorRecursive<'a> {
-    pub visitors: Vec<Box<dyn AstVisitor>>,
-    pub visit_module_hooks: Vec<Box<dyn FnMut(&ModuleContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_module_hooks: Vec<Box<dyn FnMut(&ModuleContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_module_item_hooks: Vec<Box<dyn FnMut(&ItemContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_module_item_hooks: Vec<Box<dyn FnMut(&ItemContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_submodule_hooks: Vec<Box<dyn FnMut(&SubmoduleContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_submodule_hooks: Vec<Box<dyn FnMut(&SubmoduleContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_use_hooks: Vec<Box<dyn FnMut(&UseContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_use_hooks: Vec<Box<dyn FnMut(&UseContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_struct_hooks: Vec<Box<dyn FnMut(&StructContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_struct_hooks: Vec<Box<dyn FnMut(&StructContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_struct_field_hooks: Vec<Box<dyn FnMut(&StructFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_struct_field_hooks: Vec<Box<dyn FnMut(&StructFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_enum_hooks: Vec<Box<dyn FnMut(&EnumContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_enum_hooks: Vec<Box<dyn FnMut(&EnumContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_enum_field_hooks: Vec<Box<dyn FnMut(&EnumFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_enum_field_hooks: Vec<Box<dyn FnMut(&EnumFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_fn_hooks: Vec<Box<dyn FnMut(&FnContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_fn_hooks: Vec<Box<dyn FnMut(&FnContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_statement_hooks: Vec<Box<dyn FnMut(&StatementContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_statement_hooks: Vec<Box<dyn FnMut(&StatementContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_statement_let_hooks: Vec<Box<dyn FnMut(&StatementLetContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_statement_let_hooks: Vec<Box<dyn FnMut(&StatementLetContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_expr_hooks: Vec<Box<dyn FnMut(&ExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_expr_hooks: Vec<Box<dyn FnMut(&ExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_block_hooks: Vec<Box<dyn FnMut(&BlockContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_block_hooks: Vec<Box<dyn FnMut(&BlockContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_asm_block_hooks: Vec<Box<dyn FnMut(&AsmBlockContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_asm_block_hooks: Vec<Box<dyn FnMut(&AsmBlockContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_asm_instruction_hooks: Vec<Box<dyn FnMut(&AsmInstructionContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_asm_instruction_hooks: Vec<Box<dyn FnMut(&AsmInstructionContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_asm_final_expr_hooks: Vec<Box<dyn FnMut(&AsmFinalExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_asm_final_expr_hooks: Vec<Box<dyn FnMut(&AsmFinalExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_if_expr_hooks: Vec<Box<dyn FnMut(&IfExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_if_expr_hooks: Vec<Box<dyn FnMut(&IfExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_match_expr_hooks: Vec<Box<dyn FnMut(&MatchExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_match_expr_hooks: Vec<Box<dyn FnMut(&MatchExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_match_branch_hooks: Vec<Box<dyn FnMut(&MatchBranchContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_match_branch_hooks: Vec<Box<dyn FnMut(&MatchBranchContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_while_expr_hooks: Vec<Box<dyn FnMut(&WhileExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_while_expr_hooks: Vec<Box<dyn FnMut(&WhileExprContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_trait_hooks: Vec<Box<dyn FnMut(&TraitContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_trait_hooks: Vec<Box<dyn FnMut(&TraitContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_impl_hooks: Vec<Box<dyn FnMut(&ImplContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_impl_hooks: Vec<Box<dyn FnMut(&ImplContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_abi_hooks: Vec<Box<dyn FnMut(&AbiContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_abi_hooks: Vec<Box<dyn FnMut(&AbiContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_const_hooks: Vec<Box<dyn FnMut(&ConstContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_const_hooks: Vec<Box<dyn FnMut(&ConstContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_storage_hooks: Vec<Box<dyn FnMut(&StorageContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_storage_hooks: Vec<Box<dyn FnMut(&StorageContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_storage_field_hooks: Vec<Box<dyn FnMut(&StorageFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_storage_field_hooks: Vec<Box<dyn FnMut(&StorageFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_configurable_hooks: Vec<Box<dyn FnMut(&ConfigurableContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_configurable_hooks: Vec<Box<dyn FnMut(&ConfigurableContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_configurable_field_hooks: Vec<Box<dyn FnMut(&ConfigurableFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_configurable_field_hooks: Vec<Box<dyn FnMut(&ConfigurableFieldContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_type_alias_hooks: Vec<Box<dyn FnMut(&TypeAliasContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_type_alias_hooks: Vec<Box<dyn FnMut(&TypeAliasContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub visit_trait_type_hooks: Vec<Box<dyn FnMut(&TraitTypeContext, &mut Project) -> Result<(), Error> + 'a>>,
-    pub leave_trait_type_hooks: Vec<Box<dyn FnMut(&TraitTypeContext, &mut Project) -> Result<(), Error> + 'a>>,
+    /// The visitors to dispatch to, along with the detector name their report entries are attributed to.
+    pub visitors: Vec<(Rc<str>, Box<dyn AstVisitor>)>,
+    pub timings: Option<Vec<Duration>>,
+    pub visit_module_hooks: Vec<ModuleHook<'a>>,
+    pub leave_module_hooks: Vec<ModuleHook<'a>>,
+    pub visit_module_item_hooks: Vec<ItemHook<'a>>,
+    pub leave_module_item_hooks: Vec<ItemHook<'a>>,
+    pub visit_submodule_hooks: Vec<SubmoduleHook<'a>>,
+    pub leave_submodule_hooks: Vec<SubmoduleHook<'a>>,
+    pub visit_use_hooks: Vec<UseHook<'a>>,
+    pub leave_use_hooks: Vec<UseHook<'a>>,
+    pub visit_struct_hooks: Vec<StructHook<'a>>,
+    pub leave_struct_hooks: Vec<StructHook<'a>>,
+    pub visit_struct_field_hooks: Vec<StructFieldHook<'a>>,
+    pub leave_struct_field_hooks: Vec<StructFieldHook<'a>>,
+    pub visit_enum_hooks: Vec<EnumHook<'a>>,
+    pub leave_enum_hooks: Vec<EnumHook<'a>>,
+    pub visit_enum_field_hooks: Vec<EnumFieldHook<'a>>,
+    pub leave_enum_field_hooks: Vec<EnumFieldHook<'a>>,
+    pub visit_fn_hooks: Vec<FnHook<'a>>,
+    pub leave_fn_hooks: Vec<FnHook<'a>>,
+    pub visit_statement_hooks: Vec<StatementHook<'a>>,
+    pub leave_statement_hooks: Vec<StatementHook<'a>>,
+    pub visit_statement_let_hooks: Vec<StatementLetHook<'a>>,
+    pub leave_statement_let_hooks: Vec<StatementLetHook<'a>>,
+    pub visit_expr_hooks: Vec<ExprHook<'a>>,
+    pub leave_expr_hooks: Vec<ExprHook<'a>>,
+    pub visit_block_hooks: Vec<BlockHook<'a>>,
+    pub leave_block_hooks: Vec<BlockHook<'a>>,
+    pub visit_asm_block_hooks: Vec<AsmBlockHook<'a>>,
+    pub leave_asm_block_hooks: Vec<AsmBlockHook<'a>>,
+    pub visit_asm_instruction_hooks: Vec<AsmInstructionHook<'a>>,
+    pub leave_asm_instruction_hooks: Vec<AsmInstructionHook<'a>>,
+    pub visit_asm_final_expr_hooks: Vec<AsmFinalExprHook<'a>>,
+    pub leave_asm_final_expr_hooks: Vec<AsmFinalExprHook<'a>>,
+    pub visit_if_expr_hooks: Vec<IfExprHook<'a>>,
+    pub leave_if_expr_hooks: Vec<IfExprHook<'a>>,
+    pub visit_match_expr_hooks: Vec<MatchExprHook<'a>>,
+    pub leave_match_expr_hooks: Vec<MatchExprHook<'a>>,
+    pub visit_match_branch_hooks: Vec<MatchBranchHook<'a>>,
+    pub leave_match_branch_hooks: Vec<MatchBranchHook<'a>>,
+    pub visit_while_expr_hooks: Vec<WhileExprHook<'a>>,
+    pub leave_while_expr_hooks: Vec<WhileExprHook<'a>>,
+    pub visit_trait_hooks: Vec<TraitHook<'a>>,
+    pub leave_trait_hooks: Vec<TraitHook<'a>>,
+    pub visit_impl_hooks: Vec<ImplHook<'a>>,
+    pub leave_impl_hooks: Vec<ImplHook<'a>>,
+    pub visit_abi_hooks: Vec<AbiHook<'a>>,
+    pub leave_abi_hooks: Vec<AbiHook<'a>>,
+    pub visit_const_hooks: Vec<ConstHook<'a>>,
+    pub leave_const_hooks: Vec<ConstHook<'a>>,
+    pub visit_storage_hooks: Vec<StorageHook<'a>>,
+    pub leave_storage_hooks: Vec<StorageHook<'a>>,
+    pub visit_storage_field_hooks: Vec<StorageFieldHook<'a>>,
+    pub leave_storage_field_hooks: Vec<StorageFieldHook<'a>>,
+    pub visit_configurable_hooks: Vec<ConfigurableHook<'a>>,
+    pub leave_configurable_hooks: Vec<ConfigurableHook<'a>>,
+    pub visit_configurable_field_hooks: Vec<ConfigurableFieldHook<'a>>,
+    pub leave_configurable_field_hooks: Vec<ConfigurableFieldHook<'a>>,
+    pub visit_type_alias_hooks: Vec<TypeAliasHook<'a>>,
+    pub leave_type_alias_hooks: Vec<TypeAliasHook<'a>>,
+    pub visit_trait_type_hooks: Vec<TraitTypeHook<'a>>,
+    pub leave_trait_type_hooks: Vec<TraitTypeHook<'a>>,
+    pub visit_attribute_hooks: Vec<AttributeHook<'a>>,
+    pub leave_attribute_hooks: Vec<AttributeHook<'a>>,
+    pub visit_fn_arg_hooks: Vec<FnArgHook<'a>>,
+    pub leave_fn_arg_hooks: Vec<FnArgHook<'a>>,
+    pub visit_pattern_hooks: Vec<PatternHook<'a>>,
+    pub leave_pattern_hooks: Vec<PatternHook<'a>>,
+    pub visit_type_hooks: Vec<TypeHook<'a>>,
+    pub leave_type_hooks: Vec<TypeHook<'a>>,
+    pub visit_generic_param_hooks: Vec<GenericParamHook<'a>>,
+    pub leave_generic_param_hooks: Vec<GenericParamHook<'a>>,
+}
+
+impl AstVisitorRecursive<'_> {
+    /// Calls `f` on each visitor, attributing its report entries to its name and measuring the time spent in each visitor if `timings` is enabled.
+    fn dispatch<F: FnMut(&mut dyn AstVisitor, &mut Project) -> Result<(), Error>>(&mut self, project: &mut Project, mut f: F) -> Result<(), Error> {
+        if let Some(timings) = self.timings.as_mut() {
+            timings.resize(self.visitors.len(), Duration::ZERO);
+        }
+
+        for (i, (name, visitor)) in self.visitors.iter_mut().enumerate() {
+            project.report.borrow_mut().detector = Some(name.clone());
+
+            let start = self.timings.is_some().then(Instant::now);
+            let result = f(visitor.as_mut(), project);
+
+            if let (Some(timings), Some(start)) = (self.timings.as_mut(), start) {
+                timings[i] += start.elapsed();
+            }
+
+            project.report.borrow_mut().detector = None;
+            result?;
+        }
+
+        Ok(())
+    }
+
+    /// Visits the expressions nested in a block, `if`, `match`, `while` or `asm` expression outside of a function (i.e. in a constant or storage initializer), where the function-specific contexts are unavailable.
+    fn visit_nested_exprs_outside_fn(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
+        fn push_block_exprs<'a>(block: &'a CodeBlockContents, exprs: &mut Vec<&'a Expr>) {
+            for statement in block.statements.iter() {
+                match statement {
+                    Statement::Let(statement_let) => exprs.push(&statement_let.expr),
+                    Statement::Expr { expr, .. } => exprs.push(expr),
+                    Statement::Item(_) | Statement::Error(_, _) => {}
+                }
+            }
+
+            if let Some(expr) = block.final_expr_opt.as_ref() {
+                exprs.push(expr);
+            }
+        }
+
+        fn push_if_exprs<'a>(if_expr: &'a IfExpr, exprs: &mut Vec<&'a Expr>) {
+            match &if_expr.condition {
+                IfCondition::Expr(expr) => exprs.push(expr),
+                IfCondition::Let { rhs, .. } => exprs.push(rhs),
+            }
+
+            push_block_exprs(&if_expr.then_block.inner, exprs);
+
+            match if_expr.else_opt.as_ref() {
+                Some((_, expr::LoopControlFlow::Break(block))) => push_block_exprs(&block.inner, exprs),
+                Some((_, expr::LoopControlFlow::Continue(if_expr))) => push_if_exprs(if_expr, exprs),
+                None => {}
+            }
+        }
+
+        let mut exprs = vec![];
+
+        match context.expr {
+            Expr::Block(block) => push_block_exprs(&block.inner, &mut exprs),
+
+            Expr::If(if_expr) => push_if_exprs(if_expr, &mut exprs),
+
+            Expr::Match { value, branches, .. } => {
+                exprs.push(value.as_ref());
+
+                for branch in branches.inner.iter() {
+                    match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => push_block_exprs(&block.inner, &mut exprs),
+                        MatchBranchKind::Expr { expr, .. } => exprs.push(expr),
+                    }
+                }
+            }
+
+            Expr::While { condition, block, .. } => {
+                exprs.push(condition.as_ref());
+                push_block_exprs(&block.inner, &mut exprs);
+            }
+
+            Expr::Asm(asm) => {
+                for register in &asm.registers.inner {
+                    if let Some((_, expr)) = register.value_opt.as_ref() {
+                        exprs.push(expr);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        for expr in exprs {
+            let context = ExprContext {
+                path: context.path,
+                module: context.module,
+                ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
+                item: context.item,
+                impl_attributes: context.impl_attributes,
+                item_impl: context.item_impl,
+                fn_attributes: None,
+                item_fn: None,
+                blocks: context.blocks.clone(),
+                statement: context.statement,
+                expr,
+            };
+
+            self.visit_expr(&context, project)?;
+            self.leave_expr(&context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_attribute_decls<'a>(&mut self, location: &NodeLocation<'a>, attribute_decls: &'a [AttributeDecl], project: &mut Project) -> Result<(), Error> {
+        for attribute_decl in attribute_decls {
+            for attribute in &attribute_decl.attribute.inner {
+                let context = AttributeContext {
+                    path: location.path,
+                    module: location.module,
+                    ancestors: location.ancestors,
+                    item: location.item,
+                    attribute_decl,
+                    attribute,
+                };
+
+                self.visit_attribute(&context, project)?;
+                self.leave_attribute(&context, project)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_generic_params<'a>(&mut self, location: &NodeLocation<'a>, generic_params: Option<&'a GenericParams>, where_clause: Option<&'a WhereClause>, project: &mut Project) -> Result<(), Error> {
+        let Some(generic_params) = generic_params else { return Ok(()) };
+
+        for name in &generic_params.parameters.inner {
+            let where_bounds = where_clause.into_iter()
+                .flat_map(|where_clause| &where_clause.bounds)
+                .filter(|where_bound| where_bound.ty_name == *name)
+                .collect();
+
+            let context = GenericParamContext {
+                path: location.path,
+                module: location.module,
+                ancestors: location.ancestors,
+                item: location.item,
+                generic_params,
+                name,
+                where_bounds,
+            };
+
+            self.visit_generic_param(&context, project)?;
+            self.leave_generic_param(&context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn_signature<'a>(&mut self, location: &NodeLocation<'a>, fn_signature: &'a FnSignature, project: &mut Project) -> Result<(), Error> {
+        self.visit_generic_params(location, fn_signature.generics.as_ref(), fn_signature.where_clause_opt.as_ref(), project)?;
+
+        let fn_args = match &fn_signature.arguments.inner {
+            FnArgs::Static(fn_args) => Some(fn_args),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_, fn_args)| fn_args),
+        };
+
+        for fn_arg in fn_args.into_iter().flatten() {
+            let context = FnArgContext {
+                path: location.path,
+                module: location.module,
+                ancestors: location.ancestors,
+                item: location.item,
+                fn_signature,
+                fn_arg,
+            };
+
+            self.visit_fn_arg(&context, project)?;
+            self.leave_fn_arg(&context, project)?;
+        }
+
+        if let Some((_, ty)) = fn_signature.return_type_opt.as_ref() {
+            self.visit_type_at(location, ty, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_trait_items<'a>(&mut self, location: &NodeLocation<'a>, items: &'a [Annotated<ItemTraitItem>], defs: Option<&'a [Annotated<ItemFn>]>, project: &mut Project) -> Result<(), Error> {
+        for item in items {
+            self.visit_attribute_decls(location, &item.attribute_list, project)?;
+
+            match &item.value {
+                ItemTraitItem::Fn(fn_signature, _) => self.visit_fn_signature(location, fn_signature, project)?,
+                ItemTraitItem::Const(item_const, _) => {
+                    if let Some((_, ty)) = item_const.ty_opt.as_ref() {
+                        self.visit_type_at(location, ty, project)?;
+                    }
+                }
+                ItemTraitItem::Type(trait_type, _) => {
+                    if let Some(ty) = trait_type.ty_opt.as_ref() {
+                        self.visit_type_at(location, ty, project)?;
+                    }
+                }
+                ItemTraitItem::Error(_, _) => {}
+            }
+        }
+
+        for item in defs.unwrap_or_default() {
+            self.visit_attribute_decls(location, &item.attribute_list, project)?;
+            self.visit_fn_signature(location, &item.value.fn_signature, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_type_at<'a>(&mut self, location: &NodeLocation<'a>, ty: &'a Ty, project: &mut Project) -> Result<(), Error> {
+        let context = TypeContext {
+            path: location.path,
+            module: location.module,
+            ancestors: location.ancestors,
+            item: location.item,
+            ty,
+        };
+
+        self.visit_type(&context, project)?;
+        self.leave_type(&context, project)
+    }
+
+    fn visit_pattern_at<'a>(&mut self, location: &NodeLocation<'a>, pattern: &'a Pattern, project: &mut Project) -> Result<(), Error> {
+        let context = PatternContext {
+            path: location.path,
+            module: location.module,
+            ancestors: location.ancestors,
+            item: location.item,
+            pattern,
+        };
+
+        self.visit_pattern(&context, project)?;
+        self.leave_pattern(&context, project)
+    }
+
+    /// Visits the types supplied as generic arguments in the segments of a path expression.
+    fn visit_path_expr_types<'a>(&mut self, location: &NodeLocation<'a>, path_expr: &'a PathExpr, project: &mut Project) -> Result<(), Error> {
+        let segments = std::iter::once(&path_expr.prefix).chain(path_expr.suffix.iter().map(|(_, segment)| segment));
+
+        for segment in segments {
+            self.visit_path_expr_segment_types(location, segment, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_path_expr_segment_types<'a>(&mut self, location: &NodeLocation<'a>, segment: &'a PathExprSegment, project: &mut Project) -> Result<(), Error> {
+        let Some((_, generic_args)) = segment.generics_opt.as_ref() else { return Ok(()) };
+
+        for ty in &generic_args.parameters.inner {
+            self.visit_type_at(location, ty, project)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl AstVisitor for AstVisitorRecursive<'_> {
     fn visit_module(&mut self, context: &ModuleContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_module(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_module(context, project))?;
 
         for hook in self.visit_module_hooks.iter_mut() {
             hook(context, project)?;
@@ -532,6 +1069,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = ItemContext {
                 path: context.path,
                 module: context.module,
+                ancestors: Ancestors::default(),
                 attributes: item.attribute_list.as_slice(),
                 item: &item.value,
                 impl_attributes: None,
@@ -550,9 +1088,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_module(&mut self, context: &ModuleContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_module(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_module(context, project))?;
 
         for hook in self.leave_module_hooks.iter_mut() {
             hook(context, project)?;
@@ -562,19 +1098,27 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_module_item(&mut self, context: &ItemContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_module_item(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_module_item(context, project))?;
 
         for hook in self.visit_module_item_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Item(context.item)),
+            item: context.item,
+        };
+
+        self.visit_attribute_decls(&location, context.attributes, project)?;
+
         match &context.item {
             ItemKind::Submodule(submodule) => {
                 let context = SubmoduleContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     submodule,
@@ -588,6 +1132,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = UseContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_use,
@@ -601,6 +1146,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = StructContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     struct_attributes: context.attributes,
                     item_struct,
@@ -614,6 +1160,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = EnumContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_enum,
@@ -627,6 +1174,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = FnContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     impl_attributes: None,
                     item_impl: None,
@@ -642,6 +1190,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = TraitContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_trait,
@@ -655,6 +1204,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ImplContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_impl,
@@ -670,6 +1220,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = AbiContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_abi,
@@ -683,6 +1234,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ConstContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     impl_attributes: None,
                     item_impl: None,
@@ -702,6 +1254,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = StorageContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_storage,
@@ -715,6 +1268,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ConfigurableContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_configurable,
@@ -728,6 +1282,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = TypeAliasContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Item(context.item)),
                     item: context.item,
                     attributes: context.attributes,
                     item_type_alias,
@@ -744,9 +1299,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_module_item(&mut self, context: &ItemContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_module_item(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_module_item(context, project))?;
 
         for hook in self.leave_module_item_hooks.iter_mut() {
             hook(context, project)?;
@@ -756,9 +1309,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_submodule(&mut self, context: &SubmoduleContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_submodule(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_submodule(context, project))?;
 
         for hook in self.visit_submodule_hooks.iter_mut() {
             hook(context, project)?;
@@ -768,9 +1319,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_submodule(&mut self, context: &SubmoduleContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_submodule(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_submodule(context, project))?;
         
         for hook in self.leave_submodule_hooks.iter_mut() {
             hook(context, project)?;
@@ -780,9 +1329,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_use(&mut self, context: &UseContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_use(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_use(context, project))?;
         
         for hook in self.visit_use_hooks.iter_mut() {
             hook(context, project)?;
@@ -792,9 +1339,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_use(&mut self, context: &UseContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_use(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_use(context, project))?;
         
         for hook in self.leave_use_hooks.iter_mut() {
             hook(context, project)?;
@@ -804,18 +1349,26 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_struct(&mut self, context: &StructContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_struct(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_struct(context, project))?;
         
         for hook in self.visit_struct_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_generic_params(&location, context.item_struct.generics.as_ref(), context.item_struct.where_clause_opt.as_ref(), project)?;
+
         for field in &context.item_struct.fields.inner {
             let context = StructFieldContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors,
                 item: context.item,
                 struct_attributes: context.struct_attributes,
                 item_struct: context.item_struct,
@@ -831,9 +1384,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_struct(&mut self, context: &StructContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_struct(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_struct(context, project))?;
         
         for hook in self.leave_struct_hooks.iter_mut() {
             hook(context, project)?;
@@ -843,21 +1394,27 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_struct_field(&mut self, context: &StructFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_struct_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_struct_field(context, project))?;
 
         for hook in self.visit_struct_field_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_attribute_decls(&location, context.field_attributes, project)?;
+        self.visit_type_at(&location, &context.field.ty, project)?;
+
         Ok(())
     }
 
     fn leave_struct_field(&mut self, context: &StructFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_struct_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_struct_field(context, project))?;
 
         for hook in self.leave_struct_field_hooks.iter_mut() {
             hook(context, project)?;
@@ -867,18 +1424,26 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_enum(&mut self, context: &EnumContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_enum(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_enum(context, project))?;
         
         for hook in self.visit_enum_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_generic_params(&location, context.item_enum.generics.as_ref(), context.item_enum.where_clause_opt.as_ref(), project)?;
+
         for field in &context.item_enum.fields.inner {
             let context = EnumFieldContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors,
                 item: context.item,
                 enum_attributes: context.attributes,
                 item_enum: context.item_enum,
@@ -894,9 +1459,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_enum(&mut self, context: &EnumContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_enum(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_enum(context, project))?;
         
         for hook in self.leave_enum_hooks.iter_mut() {
             hook(context, project)?;
@@ -906,21 +1469,27 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_enum_field(&mut self, context: &EnumFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_enum_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_enum_field(context, project))?;
         
         for hook in self.visit_enum_field_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_attribute_decls(&location, context.field_attributes, project)?;
+        self.visit_type_at(&location, &context.field.ty, project)?;
+
         Ok(())
     }
 
     fn leave_enum_field(&mut self, context: &EnumFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_enum_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_enum_field(context, project))?;
         
         for hook in self.leave_enum_field_hooks.iter_mut() {
             hook(context, project)?;
@@ -930,17 +1499,25 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_fn(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_fn(context, project))?;
 
         for hook in self.visit_fn_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Fn(context.item_fn)),
+            item: context.item,
+        };
+
+        self.visit_fn_signature(&location, &context.item_fn.fn_signature, project)?;
+
         let body_context = BlockContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Fn(context.item_fn)),
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -959,9 +1536,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_fn(&mut self, context: &FnContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_fn(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_fn(context, project))?;
         
         for hook in self.leave_fn_hooks.iter_mut() {
             hook(context, project)?;
@@ -971,9 +1546,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_statement(&mut self, context: &StatementContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_statement(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_statement(context, project))?;
         
         for hook in self.visit_statement_hooks.iter_mut() {
             hook(context, project)?;
@@ -984,6 +1557,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = StatementLetContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Statement(context.statement)),
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1002,6 +1576,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ItemContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Statement(context.statement)),
                     attributes: item.attribute_list.as_slice(),
                     item: &item.value,
                     impl_attributes: context.impl_attributes,
@@ -1020,6 +1595,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::Statement(context.statement)),
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1041,9 +1617,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_statement(&mut self, context: &StatementContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_statement(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_statement(context, project))?;
         
         for hook in self.leave_statement_hooks.iter_mut() {
             hook(context, project)?;
@@ -1053,17 +1627,29 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_statement_let(&mut self, context: &StatementLetContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_statement_let(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_statement_let(context, project))?;
         
         for hook in self.visit_statement_let_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_pattern_at(&location, &context.statement_let.pattern, project)?;
+
+        if let Some((_, ty)) = context.statement_let.ty_opt.as_ref() {
+            self.visit_type_at(&location, ty, project)?;
+        }
+
         let context = ExprContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors,
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -1081,9 +1667,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_statement_let(&mut self, context: &StatementLetContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_statement_let(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_statement_let(context, project))?;
         
         for hook in self.leave_statement_let_hooks.iter_mut() {
             hook(context, project)?;
@@ -1093,23 +1677,37 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_expr(context, project))?;
 
         for hook in self.visit_expr_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        if context.item_fn.is_none() && matches!(context.expr, Expr::Block(_) | Expr::If(_) | Expr::Match { .. } | Expr::While { .. } | Expr::Asm(_)) {
+            return self.visit_nested_exprs_outside_fn(context, project);
+        }
+
+        let ancestors = context.ancestors.with(Ancestor::Expr(context.expr));
+
         match context.expr {
             Expr::Error(_, _) => {}
-            Expr::Path(_) => {}
+            Expr::Path(path) => {
+                let location = NodeLocation {
+                    path: context.path,
+                    module: context.module,
+                    ancestors,
+                    item: context.item,
+                };
+
+                self.visit_path_expr_types(&location, path, project)?;
+            }
             Expr::Literal(_) => {}
 
             Expr::AbiCast { args, .. } => {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1124,12 +1722,22 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 self.leave_expr(&context, project)?;
             }
 
-            Expr::Struct { fields, .. } => {
+            Expr::Struct { path, fields } => {
+                let location = NodeLocation {
+                    path: context.path,
+                    module: context.module,
+                    ancestors,
+                    item: context.item,
+                };
+
+                self.visit_path_expr_types(&location, path, project)?;
+
                 for field in &fields.inner {
                     if let Some(field) = field.expr_opt.as_ref() {
                         let context = ExprContext {
                             path: context.path,
                             module: context.module,
+                            ancestors,
                             item: context.item,
                             impl_attributes: context.impl_attributes,
                             item_impl: context.item_impl,
@@ -1154,6 +1762,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                         let context = ExprContext {
                             path: context.path,
                             module: context.module,
+                            ancestors,
                             item: context.item,
                             impl_attributes: context.impl_attributes,
                             item_impl: context.item_impl,
@@ -1171,6 +1780,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                             let context = ExprContext {
                                 path: context.path,
                                 module: context.module,
+                                ancestors,
                                 item: context.item,
                                 impl_attributes: context.impl_attributes,
                                 item_impl: context.item_impl,
@@ -1191,6 +1801,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1209,6 +1820,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = BlockContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1231,6 +1843,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                             let context = ExprContext {
                                 path: context.path,
                                 module: context.module,
+                                ancestors,
                                 item: context.item,
                                 impl_attributes: context.impl_attributes,
                                 item_impl: context.item_impl,
@@ -1250,6 +1863,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                         let context = ExprContext {
                             path: context.path,
                             module: context.module,
+                            ancestors,
                             item: context.item,
                             impl_attributes: context.impl_attributes,
                             item_impl: context.item_impl,
@@ -1266,6 +1880,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                         let context = ExprContext {
                             path: context.path,
                             module: context.module,
+                            ancestors,
                             item: context.item,
                             impl_attributes: context.impl_attributes,
                             item_impl: context.item_impl,
@@ -1286,6 +1901,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = AsmBlockContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1306,6 +1922,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = ExprContext {
                         path: context.path,
                         module: context.module,
+                        ancestors,
                         item: context.item,
                         impl_attributes: context.impl_attributes,
                         item_impl: context.item_impl,
@@ -1325,6 +1942,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = IfExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1344,6 +1962,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = MatchExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1364,6 +1983,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = WhileExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1384,6 +2004,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1401,6 +2022,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = ExprContext {
                         path: context.path,
                         module: context.module,
+                        ancestors,
                         item: context.item,
                         impl_attributes: context.impl_attributes,
                         item_impl: context.item_impl,
@@ -1420,6 +2042,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1436,6 +2059,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1450,10 +2074,20 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 self.leave_expr(&context, project)?;
             }
 
-            Expr::MethodCall { target, contract_args_opt, args, .. } => {
+            Expr::MethodCall { target, path_seg, contract_args_opt, args, .. } => {
+                let location = NodeLocation {
+                    path: context.path,
+                    module: context.module,
+                    ancestors,
+                    item: context.item,
+                };
+
+                self.visit_path_expr_segment_types(&location, path_seg, project)?;
+
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1473,6 +2107,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                             let context = ExprContext {
                                 path: context.path,
                                 module: context.module,
+                                ancestors,
                                 item: context.item,
                                 impl_attributes: context.impl_attributes,
                                 item_impl: context.item_impl,
@@ -1493,6 +2128,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = ExprContext {
                         path: context.path,
                         module: context.module,
+                        ancestors,
                         item: context.item,
                         impl_attributes: context.impl_attributes,
                         item_impl: context.item_impl,
@@ -1512,6 +2148,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1530,6 +2167,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1548,6 +2186,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1566,6 +2205,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1584,6 +2224,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1602,6 +2243,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1618,6 +2260,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1636,6 +2279,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1652,6 +2296,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1670,6 +2315,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1686,6 +2332,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1704,6 +2351,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1720,6 +2368,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1738,6 +2387,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1754,6 +2404,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1772,6 +2423,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1788,6 +2440,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1806,6 +2459,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1822,6 +2476,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1840,6 +2495,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1856,6 +2512,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1874,6 +2531,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1890,6 +2548,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1908,6 +2567,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1924,6 +2584,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1942,6 +2603,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1958,6 +2620,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1976,6 +2639,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -1992,6 +2656,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2010,6 +2675,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2026,6 +2692,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2044,6 +2711,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2060,6 +2728,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2078,6 +2747,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2094,6 +2764,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2112,6 +2783,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2128,6 +2800,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2146,6 +2819,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2162,6 +2836,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2180,6 +2855,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2196,6 +2872,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2214,6 +2891,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2230,6 +2908,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2248,6 +2927,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2270,9 +2950,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_expr(&mut self, context: &ExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_expr(context, project))?;
         
         for hook in self.leave_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2282,14 +2960,18 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_block(&mut self, context: &BlockContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_block(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_block(context, project))?;
 
         for hook in self.visit_block_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        // Blocks of `if`, `match` and `while` expressions are visited from those expressions, which are already in the ancestors
+        let ancestors = match context.expr {
+            Some(expr @ Expr::Block(block)) if std::ptr::eq(block, context.block) => context.ancestors.with(Ancestor::Expr(expr)),
+            _ => context.ancestors,
+        };
+
         let mut blocks = context.blocks.clone();
         blocks.push(context.block.span());
 
@@ -2297,6 +2979,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = StatementContext {
                 path: context.path,
                 module: context.module,
+                ancestors,
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2314,6 +2997,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = ExprContext {
                 path: context.path,
                 module: context.module,
+                ancestors,
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2332,9 +3016,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_block(&mut self, context: &BlockContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_block(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_block(context, project))?;
         
         for hook in self.leave_block_hooks.iter_mut() {
             hook(context, project)?;
@@ -2344,18 +3026,38 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_asm_block(&mut self, context: &AsmBlockContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_asm_block(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_asm_block(context, project))?;
 
         for hook in self.visit_asm_block_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        for register in &context.asm.registers.inner {
+            let Some((_, expr)) = register.value_opt.as_ref() else { continue };
+
+            let context = ExprContext {
+                path: context.path,
+                module: context.module,
+                ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
+                item: context.item,
+                impl_attributes: context.impl_attributes,
+                item_impl: context.item_impl,
+                fn_attributes: Some(context.fn_attributes),
+                item_fn: Some(context.item_fn),
+                blocks: context.blocks.clone(),
+                statement: context.statement,
+                expr,
+            };
+
+            self.visit_expr(&context, project)?;
+            self.leave_expr(&context, project)?;
+        }
+
         for instruction in context.asm.contents.inner.instructions.iter() {
             let context = AsmInstructionContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2376,6 +3078,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = AsmFinalExprContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2396,9 +3099,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_asm_block(&mut self, context: &AsmBlockContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_asm_block(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_asm_block(context, project))?;
         
         for hook in self.leave_asm_block_hooks.iter_mut() {
             hook(context, project)?;
@@ -2408,9 +3109,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_asm_instruction(&mut self, context: &AsmInstructionContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_asm_instruction(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_asm_instruction(context, project))?;
         
         for hook in self.visit_asm_instruction_hooks.iter_mut() {
             hook(context, project)?;
@@ -2420,9 +3119,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_asm_instruction(&mut self, context: &AsmInstructionContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_asm_instruction(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_asm_instruction(context, project))?;
         
         for hook in self.leave_asm_instruction_hooks.iter_mut() {
             hook(context, project)?;
@@ -2432,9 +3129,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_asm_final_expr(&mut self, context: &AsmFinalExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_asm_final_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_asm_final_expr(context, project))?;
         
         for hook in self.visit_asm_final_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2444,9 +3139,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_asm_final_expr(&mut self, context: &AsmFinalExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_asm_final_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_asm_final_expr(context, project))?;
         
         for hook in self.leave_asm_final_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2456,19 +3149,24 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_if_expr(&mut self, context: &IfExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_if_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_if_expr(context, project))?;
 
         for hook in self.visit_if_expr_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        // `else if` expressions are not wrapped in an `Expr`, so they are added to the ancestors separately
+        let ancestors = match context.expr {
+            Expr::If(if_expr) if std::ptr::eq(if_expr, context.if_expr) => context.ancestors.with(Ancestor::Expr(context.expr)),
+            _ => context.ancestors.with(Ancestor::IfExpr(context.if_expr)),
+        };
+
         match &context.if_expr.condition {
             IfCondition::Expr(expr) => {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2483,14 +3181,20 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 self.leave_expr(&context, project)?;
             }
             
-            IfCondition::Let { rhs, .. } => {
-                //
-                // NOTE: `lhs` pattern can be handled by overriding `visit_if_expr`
-                //
+            IfCondition::Let { lhs, rhs, .. } => {
+                let location = NodeLocation {
+                    path: context.path,
+                    module: context.module,
+                    ancestors,
+                    item: context.item,
+                };
+
+                self.visit_pattern_at(&location, lhs, project)?;
 
                 let rhs_context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors,
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2509,6 +3213,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
         let then_block_context = BlockContext {
             path: context.path,
             module: context.module,
+            ancestors,
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -2529,6 +3234,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = IfExprContext {
                         path: context.path,
                         module: context.module,
+                        ancestors,
                         item: context.item,
                         impl_attributes: context.impl_attributes,
                         item_impl: context.item_impl,
@@ -2548,6 +3254,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let else_block_context = BlockContext {
                         path: context.path,
                         module: context.module,
+                        ancestors,
                         item: context.item,
                         impl_attributes: context.impl_attributes,
                         item_impl: context.item_impl,
@@ -2569,9 +3276,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_if_expr(&mut self, context: &IfExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_if_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_if_expr(context, project))?;
         
         for hook in self.leave_if_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2581,9 +3286,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_match_expr(&mut self, context: &MatchExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_match_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_match_expr(context, project))?;
 
         for hook in self.visit_match_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2592,6 +3295,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
         let value_context = ExprContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -2609,6 +3313,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = MatchBranchContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2629,9 +3334,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_match_expr(&mut self, context: &MatchExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_match_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_match_expr(context, project))?;
         
         for hook in self.leave_match_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2641,23 +3344,27 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_match_branch(&mut self, context: &MatchBranchContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_match_branch(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_match_branch(context, project))?;
 
         for hook in self.visit_match_branch_hooks.iter_mut() {
             hook(context, project)?;
         }
 
-        //
-        // NOTE: `context.branch.pattern` pattern can be handled by overriding `visit_match_branch`
-        //
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::MatchBranch(context.branch)),
+            item: context.item,
+        };
+
+        self.visit_pattern_at(&location, &context.branch.pattern, project)?;
 
         match &context.branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let context = BlockContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::MatchBranch(context.branch)),
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2677,6 +3384,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 let context = ExprContext {
                     path: context.path,
                     module: context.module,
+                    ancestors: context.ancestors.with(Ancestor::MatchBranch(context.branch)),
                     item: context.item,
                     impl_attributes: context.impl_attributes,
                     item_impl: context.item_impl,
@@ -2696,9 +3404,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_match_branch(&mut self, context: &MatchBranchContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_match_branch(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_match_branch(context, project))?;
         
         for hook in self.leave_match_branch_hooks.iter_mut() {
             hook(context, project)?;
@@ -2708,9 +3414,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_while_expr(&mut self, context: &WhileExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_while_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_while_expr(context, project))?;
 
         for hook in self.visit_while_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2719,6 +3423,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
         let condition_context = ExprContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -2735,6 +3440,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
         let body_context = BlockContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Expr(context.expr)),
             item: context.item,
             impl_attributes: context.impl_attributes,
             item_impl: context.item_impl,
@@ -2753,9 +3459,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_while_expr(&mut self, context: &WhileExprContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_while_expr(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_while_expr(context, project))?;
         
         for hook in self.leave_while_expr_hooks.iter_mut() {
             hook(context, project)?;
@@ -2765,21 +3469,29 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_trait(&mut self, context: &TraitContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_trait(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_trait(context, project))?;
 
         for hook in self.visit_trait_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_generic_params(&location, context.item_trait.generics.as_ref(), context.item_trait.where_clause_opt.as_ref(), project)?;
+
+        let trait_defs = context.item_trait.trait_defs_opt.as_ref().map(|trait_defs| trait_defs.inner.as_slice());
+        self.visit_trait_items(&location, &context.item_trait.trait_items.inner, trait_defs, project)?;
+
         Ok(())
     }
 
     fn leave_trait(&mut self, context: &TraitContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_trait(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_trait(context, project))?;
         
         for hook in self.leave_trait_hooks.iter_mut() {
             hook(context, project)?;
@@ -2789,20 +3501,31 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_impl(&mut self, context: &ImplContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_impl(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_impl(context, project))?;
 
         for hook in self.visit_impl_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_generic_params(&location, context.item_impl.generic_params_opt.as_ref(), context.item_impl.where_clause_opt.as_ref(), project)?;
+        self.visit_type_at(&location, &context.item_impl.ty, project)?;
+
         for item in context.item_impl.contents.inner.iter() {
+            self.visit_attribute_decls(&location, &item.attribute_list, project)?;
+
             match &item.value {
                 sway_ast::ItemImplItem::Fn(item_fn) => {
                     let context = FnContext {
                         path: context.path,
                         module: context.module,
+                        ancestors: context.ancestors,
                         item: &ItemKind::Fn(item_fn.clone()),
                         impl_attributes: Some(context.attributes),
                         item_impl: Some(context.item_impl),
@@ -2818,6 +3541,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = ConstContext {
                         path: context.path,
                         module: context.module,
+                        ancestors: context.ancestors,
                         item: &ItemKind::Const(item_const.clone()),
                         impl_attributes: Some(context.attributes),
                         item_impl: Some(context.item_impl),
@@ -2837,6 +3561,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                     let context = TraitTypeContext {
                         path: context.path,
                         module: context.module,
+                        ancestors: context.ancestors,
                         item: context.item,
                         attributes: context.attributes,
                         item_type,
@@ -2852,9 +3577,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_impl(&mut self, context: &ImplContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_impl(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_impl(context, project))?;
 
         for hook in self.leave_impl_hooks.iter_mut() {
             hook(context, project)?;
@@ -2864,21 +3587,27 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_abi(&mut self, context: &AbiContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_abi(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_abi(context, project))?;
         
         for hook in self.visit_abi_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        let abi_defs = context.item_abi.abi_defs_opt.as_ref().map(|abi_defs| abi_defs.inner.as_slice());
+        self.visit_trait_items(&location, &context.item_abi.abi_items.inner, abi_defs, project)?;
+
         Ok(())
     }
 
     fn leave_abi(&mut self, context: &AbiContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_abi(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_abi(context, project))?;
         
         for hook in self.leave_abi_hooks.iter_mut() {
             hook(context, project)?;
@@ -2888,18 +3617,28 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_const(&mut self, context: &ConstContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_const(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_const(context, project))?;
         
         for hook in self.visit_const_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        if let Some((_, ty)) = context.item_const.ty_opt.as_ref() {
+            self.visit_type_at(&location, ty, project)?;
+        }
+
         if let Some(expr) = context.item_const.expr_opt.as_ref() {
             let context = ExprContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors,
                 item: context.item,
                 impl_attributes: context.impl_attributes,
                 item_impl: context.item_impl,
@@ -2918,9 +3657,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_const(&mut self, context: &ConstContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_const(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_const(context, project))?;
         
         for hook in self.leave_const_hooks.iter_mut() {
             hook(context, project)?;
@@ -2930,9 +3667,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_storage(&mut self, context: &StorageContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_storage(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_storage(context, project))?;
 
         for hook in self.visit_storage_hooks.iter_mut() {
             hook(context, project)?;
@@ -2942,6 +3677,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = StorageFieldContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors,
                 item: context.item,
                 storage_attributes: context.attributes,
                 item_storage: context.item_storage,
@@ -2957,9 +3693,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_storage(&mut self, context: &StorageContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_storage(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_storage(context, project))?;
         
         for hook in self.leave_storage_hooks.iter_mut() {
             hook(context, project)?;
@@ -2969,17 +3703,26 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_storage_field(&mut self, context: &StorageFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_storage_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_storage_field(context, project))?;
 
         for hook in self.visit_storage_field_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_attribute_decls(&location, context.field_attributes, project)?;
+        self.visit_type_at(&location, &context.field.ty, project)?;
+
         let context = ExprContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors,
             item: context.item,
             impl_attributes: None,
             item_impl: None,
@@ -2997,9 +3740,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_storage_field(&mut self, context: &StorageFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_storage_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_storage_field(context, project))?;
         
         for hook in self.leave_storage_field_hooks.iter_mut() {
             hook(context, project)?;
@@ -3009,9 +3750,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
     
     fn visit_configurable(&mut self, context: &ConfigurableContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_configurable(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_configurable(context, project))?;
 
         for hook in self.visit_configurable_hooks.iter_mut() {
             hook(context, project)?;
@@ -3021,6 +3760,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             let context = ConfigurableFieldContext {
                 path: context.path,
                 module: context.module,
+                ancestors: context.ancestors,
                 item: context.item,
                 configurable_attributes: context.attributes,
                 item_configurable: context.item_configurable,
@@ -3036,9 +3776,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_configurable(&mut self, context: &ConfigurableContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_configurable(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_configurable(context, project))?;
         
         for hook in self.leave_configurable_hooks.iter_mut() {
             hook(context, project)?;
@@ -3048,17 +3786,26 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_configurable_field(&mut self, context: &ConfigurableFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_configurable_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_configurable_field(context, project))?;
 
         for hook in self.visit_configurable_field_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_attribute_decls(&location, context.field_attributes, project)?;
+        self.visit_type_at(&location, &context.field.ty, project)?;
+
         let context = ExprContext {
             path: context.path,
             module: context.module,
+            ancestors: context.ancestors,
             item: context.item,
             impl_attributes: None,
             item_impl: None,
@@ -3076,9 +3823,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn leave_configurable_field(&mut self, context: &ConfigurableFieldContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_configurable_field(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_configurable_field(context, project))?;
         
         for hook in self.leave_configurable_field_hooks.iter_mut() {
             hook(context, project)?;
@@ -3088,21 +3833,26 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_type_alias(&mut self, context: &TypeAliasContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_type_alias(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_type_alias(context, project))?;
         
         for hook in self.visit_type_alias_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        self.visit_type_at(&location, &context.item_type_alias.ty, project)?;
+
         Ok(())
     }
 
     fn leave_type_alias(&mut self, context: &TypeAliasContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_type_alias(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_type_alias(context, project))?;
         
         for hook in self.leave_type_alias_hooks.iter_mut() {
             hook(context, project)?;
@@ -3112,21 +3862,28 @@ impl AstVisitor for AstVisitorRecursive<'_> {
     }
 
     fn visit_trait_type(&mut self, context: &TraitTypeContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.visit_trait_type(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.visit_trait_type(context, project))?;
         
         for hook in self.visit_trait_type_hooks.iter_mut() {
             hook(context, project)?;
         }
 
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors,
+            item: context.item,
+        };
+
+        if let Some(ty) = context.item_type.ty_opt.as_ref() {
+            self.visit_type_at(&location, ty, project)?;
+        }
+
         Ok(())
     }
 
     fn leave_trait_type(&mut self, context: &TraitTypeContext, project: &mut Project) -> Result<(), Error> {
-        for visitor in self.visitors.iter_mut() {
-            visitor.leave_trait_type(context, project)?;
-        }
+        self.dispatch(project, |visitor, project| visitor.leave_trait_type(context, project))?;
         
         for hook in self.leave_trait_type_hooks.iter_mut() {
             hook(context, project)?;
@@ -3134,4 +3891,304 @@ impl AstVisitor for AstVisitorRecursive<'_> {
 
         Ok(())
     }
+
+    fn visit_attribute(&mut self, context: &AttributeContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.visit_attribute(context, project))?;
+
+        for hook in self.visit_attribute_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_attribute(&mut self, context: &AttributeContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.leave_attribute(context, project))?;
+
+        for hook in self.leave_attribute_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn_arg(&mut self, context: &FnArgContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.visit_fn_arg(context, project))?;
+
+        for hook in self.visit_fn_arg_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::FnArg(context.fn_arg)),
+            item: context.item,
+        };
+
+        self.visit_pattern_at(&location, &context.fn_arg.pattern, project)?;
+        self.visit_type_at(&location, &context.fn_arg.ty, project)?;
+
+        Ok(())
+    }
+
+    fn leave_fn_arg(&mut self, context: &FnArgContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.leave_fn_arg(context, project))?;
+
+        for hook in self.leave_fn_arg_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_pattern(&mut self, context: &PatternContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.visit_pattern(context, project))?;
+
+        for hook in self.visit_pattern_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Pattern(context.pattern)),
+            item: context.item,
+        };
+
+        match context.pattern {
+            Pattern::Or { lhs, rhs, .. } => {
+                self.visit_pattern_at(&location, lhs, project)?;
+                self.visit_pattern_at(&location, rhs, project)?;
+            }
+
+            Pattern::Constant(path) => self.visit_path_expr_types(&location, path, project)?,
+
+            Pattern::Constructor { path, args } => {
+                self.visit_path_expr_types(&location, path, project)?;
+
+                for pattern in &args.inner {
+                    self.visit_pattern_at(&location, pattern, project)?;
+                }
+            }
+
+            Pattern::Struct { path, fields } => {
+                self.visit_path_expr_types(&location, path, project)?;
+
+                for field in &fields.inner {
+                    if let PatternStructField::Field { pattern_opt: Some((_, pattern)), .. } = field {
+                        self.visit_pattern_at(&location, pattern, project)?;
+                    }
+                }
+            }
+
+            Pattern::Tuple(patterns) => {
+                for pattern in &patterns.inner {
+                    self.visit_pattern_at(&location, pattern, project)?;
+                }
+            }
+
+            Pattern::Wildcard { .. }
+            | Pattern::AmbiguousSingleIdent(_)
+            | Pattern::Var { .. }
+            | Pattern::Literal(_)
+            | Pattern::Error(_, _) => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_pattern(&mut self, context: &PatternContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.leave_pattern(context, project))?;
+
+        for hook in self.leave_pattern_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_type(&mut self, context: &TypeContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.visit_type(context, project))?;
+
+        for hook in self.visit_type_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        let location = NodeLocation {
+            path: context.path,
+            module: context.module,
+            ancestors: context.ancestors.with(Ancestor::Ty(context.ty)),
+            item: context.item,
+        };
+
+        match context.ty {
+            Ty::Path(path_type) => {
+                if let Some((Some(root), _)) = path_type.root_opt.as_ref() {
+                    self.visit_type_at(&location, &root.inner.ty, project)?;
+                }
+
+                let segments = std::iter::once(&path_type.prefix).chain(path_type.suffix.iter().map(|(_, segment)| segment));
+
+                for (_, generic_args) in segments.filter_map(|segment| segment.generics_opt.as_ref()) {
+                    for ty in &generic_args.parameters.inner {
+                        self.visit_type_at(&location, ty, project)?;
+                    }
+                }
+            }
+
+            Ty::Tuple(tuple) => {
+                if let TyTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                    self.visit_type_at(&location, head, project)?;
+
+                    for ty in tail {
+                        self.visit_type_at(&location, ty, project)?;
+                    }
+                }
+            }
+
+            Ty::Array(array) => self.visit_type_at(&location, &array.inner.ty, project)?,
+
+            Ty::Ptr { ty, .. } | Ty::Slice { ty, .. } => self.visit_type_at(&location, &ty.inner, project)?,
+
+            Ty::StringSlice(_) | Ty::StringArray { .. } | Ty::Infer { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_type(&mut self, context: &TypeContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.leave_type(context, project))?;
+
+        for hook in self.leave_type_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_generic_param(&mut self, context: &GenericParamContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.visit_generic_param(context, project))?;
+
+        for hook in self.visit_generic_param_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_generic_param(&mut self, context: &GenericParamContext, project: &mut Project) -> Result<(), Error> {
+        self.dispatch(project, |visitor, project| visitor.leave_generic_param(context, project))?;
+
+        for hook in self.leave_generic_param_hooks.iter_mut() {
+            hook(context, project)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_ancestors() {
+        let source = "contract;\n\nfn f(x: u64) -> u64 {\n    while g(x + 1) > 0 {\n        h(x);\n    }\n\n    match x * 2 {\n        _ => 0,\n    }\n}\n";
+
+        let mut project = Project::default();
+        project.parse_source("test.sw", source).unwrap();
+
+        let found = RefCell::new(vec![]);
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_expr_hooks.push(Box::new(|context, _project| {
+            let span = context.expr.span();
+
+            if span.as_str() == "x" {
+                found.borrow_mut().push((
+                    context.ancestors.enclosing_loop().is_some(),
+                    context.ancestors.enclosing_loop_condition(&span).is_some(),
+                    context.ancestors.enclosing_call_argument(&span).is_some(),
+                    context.ancestors.enclosing_match_scrutinee(&span).is_some(),
+                ));
+            }
+
+            Ok(())
+        }));
+
+        project.visit_modules(&mut visitor).unwrap();
+        drop(visitor);
+
+        assert_eq!(found.into_inner(), vec![
+            (true, true, true, false),
+            (true, false, true, false),
+            (false, false, false, true),
+        ]);
+    }
+
+    #[test]
+    fn test_type_and_pattern_hooks() {
+        let source = "contract;\n\nstruct S<T> where T: Eq {\n    a: (u64, T),\n}\n\n#[inline(never)]\nfn f(s: S<b256>, Some(x): Option<u64>) -> [u8; 2] {\n    let (a, _): (u64, bool) = (x, true);\n    [0, 1]\n}\n";
+
+        let mut project = Project::default();
+        project.parse_source("test.sw", source).unwrap();
+
+        let found = RefCell::new(vec![]);
+        let mut visitor = AstVisitorRecursive::default();
+
+        visitor.visit_attribute_hooks.push(Box::new(|context, _project| {
+            found.borrow_mut().push(format!("attribute {}", context.attribute.name.as_str()));
+            Ok(())
+        }));
+
+        visitor.visit_generic_param_hooks.push(Box::new(|context, _project| {
+            found.borrow_mut().push(format!("generic {} ({} bounds)", context.name.as_str(), context.where_bounds.len()));
+            Ok(())
+        }));
+
+        visitor.visit_fn_arg_hooks.push(Box::new(|context, _project| {
+            found.borrow_mut().push(format!("arg {}", context.fn_arg.span().as_str()));
+            Ok(())
+        }));
+
+        visitor.visit_pattern_hooks.push(Box::new(|context, _project| {
+            found.borrow_mut().push(format!("pattern {}", context.pattern.span().as_str()));
+            Ok(())
+        }));
+
+        visitor.visit_type_hooks.push(Box::new(|context, _project| {
+            found.borrow_mut().push(format!("type {}", context.ty.span().as_str()));
+            Ok(())
+        }));
+
+        project.visit_modules(&mut visitor).unwrap();
+        drop(visitor);
+
+        assert_eq!(found.into_inner(), vec![
+            "generic T (1 bounds)",
+            "type (u64, T)",
+            "type u64",
+            "type T",
+            "attribute inline",
+            "arg s: S<b256>",
+            "pattern s",
+            "type S<b256>",
+            "type b256",
+            "arg Some(x): Option<u64>",
+            "pattern Some(x)",
+            "pattern x",
+            "type Option<u64>",
+            "type u64",
+            "type [u8; 2]",
+            "type u8",
+            "pattern (a, _)",
+            "pattern a",
+            "pattern _",
+            "type (u64, bool)",
+            "type u64",
+            "type bool",
+        ]);
+    }
 }
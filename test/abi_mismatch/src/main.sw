@@ -0,0 +1,49 @@
+contract;
+
+abi TestAbiMismatch {
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_matching(value: u64);
+
+    #[storage(read)]
+    fn test_storage_attributes();
+
+    #[payable]
+    fn test_payable_attribute();
+
+    fn test_signature(amount: u64) -> u64;
+
+    // Report entry should be created:
+    // L18: The `TestAbiMismatch::test_missing_implementation` ABI method is not implemented for `Contract`.
+    fn test_missing_implementation(); //~ abi_mismatch: The `TestAbiMismatch::test_missing_implementation` ABI method is not implemented for `Contract`.
+}
+
+abi TestExternal {
+    // Report entry should not be created
+    fn test_external();
+}
+
+impl TestAbiMismatch for Contract {
+    // Report entry should not be created
+    #[storage(write, read)]
+    fn test_matching(value: u64) {}
+
+    // Report entry should be created:
+    // L34: The `Contract::test_storage_attributes` function has `#[storage(read, write)]` but the `TestAbiMismatch` ABI declares `#[storage(read)]`.
+    #[storage(read, write)]
+    fn test_storage_attributes() {} //~ abi_mismatch: The `Contract::test_storage_attributes` function has `#[storage(read, write)]` but the `TestAbiMismatch` ABI declares `#[storage(read)]`.
+
+    // Report entry should be created:
+    // L38: The `Contract::test_payable_attribute` function is missing `#[payable]` declared in the `TestAbiMismatch` ABI.
+    fn test_payable_attribute() {} //~ abi_mismatch: The `Contract::test_payable_attribute` function is missing `#[payable]` declared in the `TestAbiMismatch` ABI.
+
+    // Report entry should be created:
+    // L42: The `Contract::test_signature` function has a signature which does not match its declaration in the `TestAbiMismatch` ABI: `fn test_signature(amount: u64) -> u64`.
+    fn test_signature(amount: u32) -> u64 { //~ abi_mismatch: The `Contract::test_signature` function has a signature which does not match its declaration in the `TestAbiMismatch` ABI: `fn test_signature(amount: u64) -> u64`.
+        amount.as_u64()
+    }
+
+    // Report entry should be created:
+    // L48: The `Contract::test_undeclared_method` function is not declared in the `TestAbiMismatch` ABI.
+    fn test_undeclared_method() {} //~ abi_mismatch: The `Contract::test_undeclared_method` function is not declared in the `TestAbiMismatch` ABI.
+}
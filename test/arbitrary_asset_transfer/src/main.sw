@@ -77,7 +77,7 @@ impl TestArbitraryAssetTransfer for Contract {
     fn arbitrary_transfer(to: Identity, asset_id: AssetId, amount: u64) {
         // Report entry should be created
         // L81: The `Contract::arbitrary_transfer` function contains an arbitrary native asset transfer: `transfer(to, asset_id, amount)`
-        transfer(to, asset_id, amount);
+        transfer(to, asset_id, amount); //~ arbitrary_asset_transfer: The `Contract::arbitrary_transfer` function contains an arbitrary native asset transfer: `transfer(to, asset_id, amount)`
     }
 
     fn arbitrary_transfer_with_require_and_revert(to: Identity, asset_id: AssetId, amount: u64) {
@@ -87,7 +87,7 @@ impl TestArbitraryAssetTransfer for Contract {
         }
         // Report entry should be created
         // L91: The `Contract::arbitrary_transfer_with_require_and_revert` function contains an arbitrary native asset transfer: `transfer(to, asset_id, amount)`
-        transfer(to, asset_id, amount);
+        transfer(to, asset_id, amount); //~ arbitrary_asset_transfer: The `Contract::arbitrary_transfer_with_require_and_revert` function contains an arbitrary native asset transfer: `transfer(to, asset_id, amount)`
     }
 
     #[storage(read)]
@@ -106,7 +106,7 @@ impl TestArbitraryAssetTransfer for Contract {
         };
         // Report entry should be created
         // L110: The `Contract::arbitraty_transfer_to_sender` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, 1)`
-        transfer_to_address(sender, BASE_ASSET_ID, 1);
+        transfer_to_address(sender, BASE_ASSET_ID, 1); //~ arbitrary_asset_transfer: The `Contract::arbitraty_transfer_to_sender` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, 1)`
     }
 
      #[storage(read)]
@@ -130,7 +130,7 @@ impl TestArbitraryAssetTransfer for Contract {
         };
         // Report entry should be created
         // L134: The `Contract::transfer_to_msg_sender` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, 1)`
-        transfer_to_address(sender, BASE_ASSET_ID, 1);
+        transfer_to_address(sender, BASE_ASSET_ID, 1); //~ arbitrary_asset_transfer: The `Contract::transfer_to_msg_sender` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, 1)`
     }
 
     fn transfer_to_msg_sender_msg_value() {
@@ -141,7 +141,7 @@ impl TestArbitraryAssetTransfer for Contract {
         };
         // Report entry should be created
         // L145: The `Contract::transfer_to_msg_sender_msg_value` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, msg_amount())`
-        transfer_to_address(sender, BASE_ASSET_ID, msg_amount());
+        transfer_to_address(sender, BASE_ASSET_ID, msg_amount()); //~ arbitrary_asset_transfer: The `Contract::transfer_to_msg_sender_msg_value` function contains an arbitrary native asset transfer: `transfer_to_address(sender, BASE_ASSET_ID, msg_amount())`
     }
 
     fn arbitrary_asset_transfer(to_ident: Identity, to_address: Address, to_contract: ContractId, asset_id: AssetId, amount: u64, target: ContractId,
@@ -151,13 +151,13 @@ impl TestArbitraryAssetTransfer for Contract {
         single_value_type_arg: bool) {
         // Report entry should be created
         // L156: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `transfer(to_ident, asset_id, amount)`  
-        transfer(to_ident, asset_id, amount);
+        transfer(to_ident, asset_id, amount); //~ arbitrary_asset_transfer: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `transfer(to_ident, asset_id, amount)`
         // L158: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `transfer_to_address(to_address, asset_id, amount)`
-        transfer_to_address(to_address, asset_id, amount);
+        transfer_to_address(to_address, asset_id, amount); //~ arbitrary_asset_transfer: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `transfer_to_address(to_address, asset_id, amount)`
         // L160: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `force_transfer_to_contract(to_contract, asset_id, amount)`
-        force_transfer_to_contract(to_contract, asset_id, amount);
+        force_transfer_to_contract(to_contract, asset_id, amount); //~ arbitrary_asset_transfer: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `force_transfer_to_contract(to_contract, asset_id, amount)`
         // L162: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `call_with_function_selector(target, function_selector, calldata, single_value_type_arg, call_params)`
-        call_with_function_selector(target, function_selector, calldata, single_value_type_arg, call_params);
+        call_with_function_selector(target, function_selector, calldata, single_value_type_arg, call_params); //~ arbitrary_asset_transfer: The `Contract::arbitrary_asset_transfer` function contains an arbitrary native asset transfer: `call_with_function_selector(target, function_selector, calldata, single_value_type_arg, call_params)`
     }
 
     #[storage(read)]
@@ -186,7 +186,7 @@ impl TestArbitraryAssetTransfer for Contract {
             Identity::Address => {
                 // Report entry should be created
                 // L191: The `Contract::arbitrary_asset_transfer_from_sender` function contains an arbitrary native asset transfer: `transfer(sender, BASE_ASSET_ID, 1)`
-                transfer(sender, BASE_ASSET_ID, 1);
+                transfer(sender, BASE_ASSET_ID, 1); //~ arbitrary_asset_transfer: The `Contract::arbitrary_asset_transfer_from_sender` function contains an arbitrary native asset transfer: `transfer(sender, BASE_ASSET_ID, 1)`
             },
             _ => { revert(0)}
         }
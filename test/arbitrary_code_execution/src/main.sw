@@ -23,7 +23,7 @@ impl TestArbitraryCodeExecution for Contract {
         asm(r1: 0, r2: 0, r3: 0) {
             // Report entry should be created:
             // L26: The `Contract::test_ldc_unrestricted` function uses the `LDC` instruction without access restriction: `ldc r1 r2 r3`. Consider checking against `msg_sender()` in order to limit access.
-            ldc r1 r2 r3;
+            ldc r1 r2 r3; //~ arbitrary_code_execution: The `Contract::test_ldc_unrestricted` function uses the `LDC` instruction without access restriction: `ldc r1 r2 r3`
         };
     }
 
@@ -0,0 +1,95 @@
+contract;
+
+abi TestArithmeticRevert {
+    fn test_guaranteed_overflow();
+    fn test_division_by_zero(x: u64, y: u64);
+    fn test_guarded_arithmetic(x: u64) -> u64;
+}
+
+impl TestArithmeticRevert for Contract {
+    fn test_guaranteed_overflow() {
+        let small = 255u8;
+
+        // Report entry should be created:
+        // L15: The `Contract::test_guaranteed_overflow` function contains an arithmetic operation which always reverts: `small + 1`. Consider checking the range of its operands.
+        let overflow = small + 1; //~ arithmetic_revert: The `Contract::test_guaranteed_overflow` function contains an arithmetic operation which always reverts: `small + 1`. Consider checking the range of its operands.
+
+        let limit = 100;
+
+        // Report entry should be created:
+        // L21: The `Contract::test_guaranteed_overflow` function contains an arithmetic operation which always reverts: `limit - 200`. Consider checking the range of its operands.
+        let underflow = limit - 200; //~ arithmetic_revert: The `Contract::test_guaranteed_overflow` function contains an arithmetic operation which always reverts: `limit - 200`. Consider checking the range of its operands.
+
+        // Report entry should not be created
+        let fine = limit - 50;
+    }
+
+    fn test_division_by_zero(x: u64, y: u64) {
+        // Report entry should be created:
+        // L30: The `Contract::test_division_by_zero` function contains a division by a value which can be zero: `x / y`. Consider requiring a non-zero divisor.
+        let a = x / y; //~ arithmetic_revert: The `Contract::test_division_by_zero` function contains a division by a value which can be zero: `x / y`. Consider requiring a non-zero divisor.
+
+        let mut b = x;
+
+        // Report entry should be created:
+        // L36: The `Contract::test_division_by_zero` function contains a division by a value which can be zero: `b /= y`. Consider requiring a non-zero divisor.
+        b /= y; //~ arithmetic_revert: The `Contract::test_division_by_zero` function contains a division by a value which can be zero: `b /= y`. Consider requiring a non-zero divisor.
+
+        // Report entry should be created:
+        // L40: The `Contract::test_division_by_zero` function contains an arithmetic operation which always reverts: `x % 0`. Consider checking the range of its operands.
+        let c = x % 0; //~ arithmetic_revert: The `Contract::test_division_by_zero` function contains an arithmetic operation which always reverts: `x % 0`. Consider checking the range of its operands.
+
+        // Report entries should not be created
+        require(y > 0, "Divisor is zero");
+        let d = x / y;
+        let e = x / 10;
+    }
+
+    fn test_guarded_arithmetic(x: u64) -> u64 {
+        let mut x = x;
+
+        if x < 10 {
+            return 0;
+        }
+
+        let f = x - 5;
+
+        while x < 20 {
+            // Report entry should be created:
+            // L60: The `Contract::test_guarded_arithmetic` function contains an arithmetic operation which always reverts: `x -= 30`. Consider checking the range of its operands.
+            x -= 30; //~ arithmetic_revert: The `Contract::test_guarded_arithmetic` function contains an arithmetic operation which always reverts: `x -= 30`. Consider checking the range of its operands.
+        }
+
+        f
+    }
+}
+
+fn test_configurable_divisor(n: u64) -> u64 {
+    // Report entry should be created:
+    // L70: The `test_configurable_divisor` function contains a division by a value which can be zero: `n / FEE`. Consider requiring a non-zero divisor.
+    n / FEE //~ arithmetic_revert: The `test_configurable_divisor` function contains a division by a value which can be zero: `n / FEE`. Consider requiring a non-zero divisor.
+}
+
+configurable {
+    FEE: u64 = 0,
+}
+
+fn bump(ref mut v: u64) {
+    v += 100;
+}
+
+fn test_ref_mut_argument() -> u64 {
+    let mut x = 0;
+    bump(x);
+    // Report entry should be created:
+    // L86: The `test_ref_mut_argument` function contains a division by a value which can be zero: `10 / x`. Consider requiring a non-zero divisor.
+    let y = 10 / x; //~ arithmetic_revert: The `test_ref_mut_argument` function contains a division by a value which can be zero: `10 / x`. Consider requiring a non-zero divisor.
+    y
+}
+
+fn test_mutable_reference() -> u64 {
+    let mut x = 0;
+    let r = ref x;
+    // Report entry should not be created
+    x - 1
+}
@@ -0,0 +1,50 @@
+contract;
+
+abi TestComplexEntryPoint {
+    fn test_complex_entry_point_1(a: u64) -> u64;
+    fn test_complex_entry_point_2(a: u64) -> u64;
+    fn test_complex_entry_point_3(a: u64) -> u64;
+}
+
+fn complex_helper(a: u64) -> u64 {
+    if a == 1 || a == 2 { return 1; }
+    if a == 3 || a == 4 { return 2; }
+    if a == 5 || a == 6 { return 3; }
+    if a == 7 || a == 8 { return 4; }
+    if a == 9 || a == 10 { return 5; }
+    0
+}
+
+impl TestComplexEntryPoint for Contract {
+    // Report entry should be created:
+    // L21: The `Contract::test_complex_entry_point_1` function is overly complex (complexity 11 > 10). Consider splitting it into smaller functions.
+    fn test_complex_entry_point_1(a: u64) -> u64 { //~ complex_entry_point: The `Contract::test_complex_entry_point_1` function is overly complex (complexity 11 > 10). Consider splitting it into smaller functions.
+        if a == 1 || a == 2 { return 1; }
+        if a == 3 || a == 4 { return 2; }
+        if a == 5 || a == 6 { return 3; }
+        if a == 7 || a == 8 { return 4; }
+        if a == 9 || a == 10 { return 5; }
+        0
+    }
+
+    // Report entry should be created:
+    // L32: The `Contract::test_complex_entry_point_2` function is overly complex (nesting 5 > 4). Consider splitting it into smaller functions.
+    fn test_complex_entry_point_2(a: u64) -> u64 { //~ complex_entry_point: The `Contract::test_complex_entry_point_2` function is overly complex (nesting 5 > 4). Consider splitting it into smaller functions.
+        if a > 1 {
+            if a > 2 {
+                if a > 3 {
+                    if a > 4 {
+                        if a > 5 {
+                            return 5;
+                        }
+                    }
+                }
+            }
+        }
+        0
+    }
+
+    fn test_complex_entry_point_3(a: u64) -> u64 {
+        complex_helper(a)
+    }
+}
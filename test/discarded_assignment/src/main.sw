@@ -25,7 +25,7 @@ impl TestDiscardedAssignment for Contract {
     fn test_discarded_assignment_1() {
         // Report entry should be created:
         // L28: The `Contract::test_discarded_assignment_1` function makes an assignment to `x` which is discarded.
-        let x = 1;
+        let x = 1; //~ discarded_assignment: The `Contract::test_discarded_assignment_1` function makes an assignment to `x` which is discarded
     }
 
     fn test_discarded_assignment_2() {
@@ -37,11 +37,11 @@ impl TestDiscardedAssignment for Contract {
     fn test_discarded_assignment_3() {
         // Report entry should be created:
         // L40: The `Contract::test_discarded_assignment_3` function makes an assignment to `x` which is discarded by the assignment made on L44.
-        let mut x = 2;
+        let mut x = 2; //~ discarded_assignment: The `Contract::test_discarded_assignment_3` function makes an assignment to `x` which is discarded by the assignment made on L44
         
         // Report entry should be created:
         // L44: The `Contract::test_discarded_assignment_3` function makes an assignment to `x` which is discarded.
-        x = 1;
+        x = 1; //~ discarded_assignment: The `Contract::test_discarded_assignment_3` function makes an assignment to `x` which is discarded
     }
 
     fn test_discarded_assignment_4() {
@@ -58,11 +58,11 @@ impl TestDiscardedAssignment for Contract {
     fn test_discarded_assignment_5() {
         // Report entry should be created:
         // L61: The `Contract::test_discarded_assignment_5` function makes an assignment to `counter` which is discarded.
-        let mut counter = storage.counter.read();
+        let mut counter = storage.counter.read(); //~ discarded_assignment: The `Contract::test_discarded_assignment_5` function makes an assignment to `counter` which is discarded
 
         // Report entry should be created:
         // L65: The `Contract::test_discarded_assignment_5` function makes an assignment to `counter.value` which is discarded.
-        counter.value += 1;
+        counter.value += 1; //~ discarded_assignment: The `Contract::test_discarded_assignment_5` function makes an assignment to `counter.value` which is discarded
     }
 
     #[storage(read, write)]
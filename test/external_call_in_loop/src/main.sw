@@ -30,7 +30,7 @@ impl TestExternalCallInLoop for Contract {
         while true {
             // Report entry should be created:
             // L33: The `Contract::test_external_call_in_loop_3` function performs an external call in a loop: `x.receive(true, 3)`
-            let _return_value = x.receive(true, 3);
+            let _return_value = x.receive(true, 3); //~ external_call_in_loop: The `Contract::test_external_call_in_loop_3` function performs an external call in a loop: `x.receive(true, 3)`
         }
     }
 
@@ -38,7 +38,7 @@ impl TestExternalCallInLoop for Contract {
         while true {
             // Report entry should be created:
             // L41: The `Contract::test_external_call_in_loop_4` function performs an external call in a loop: `abi(ContractA, CONTRACT_A_ID).receive(true, 3)`
-            let _return_value = abi(ContractA, CONTRACT_A_ID).receive(true, 3);
+            let _return_value = abi(ContractA, CONTRACT_A_ID).receive(true, 3); //~ external_call_in_loop: The `Contract::test_external_call_in_loop_4` function performs an external call in a loop: `abi(ContractA, CONTRACT_A_ID).receive(true, 3)`
         }
     }
 }
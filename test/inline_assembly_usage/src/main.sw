@@ -9,7 +9,7 @@ fn dummy_inline_assembly(a: u64, b: u64, c: u64) -> (u64, u64) {
     let empty_tuple = (0u64, 0u64);
     // Report entry should be created:
     // L12: The `dummy_inline_assembly` function contains inline assembly usage.
-    asm(output: empty_tuple, r1: a, r2: b, r3: c, r4, r5) {
+    asm(output: empty_tuple, r1: a, r2: b, r3: c, r4, r5) { //~ inline_assembly_usage: The `dummy_inline_assembly` function contains inline assembly usage
         add  r4 r1 r2; // add a & b and put the result in r4
         add  r5 r2 r3; // add b & c and put the result in r5
         sw   output r4 i0; // store the word in r4 in output + 0 words
@@ -23,7 +23,7 @@ impl TestInlineAssemblyUsage for Contract {
         let empty_tuple = (0u64, 0u64);
         // Report entry should be created:
         // L26: The `Contract::test_inline_assembly_usage_1` function contains inline assembly usage.
-        asm(output: empty_tuple, r1: a, r2: b, r3: c, r4, r5) {
+        asm(output: empty_tuple, r1: a, r2: b, r3: c, r4, r5) { //~ inline_assembly_usage: The `Contract::test_inline_assembly_usage_1` function contains inline assembly usage
             add  r4 r1 r2; // add a & b and put the result in r4
             add  r5 r2 r3; // add b & c and put the result in r5
             sw   output r4 i0; // store the word in r4 in output + 0 words
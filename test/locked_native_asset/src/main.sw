@@ -17,7 +17,7 @@ impl TestLockedNativeAsset for Contract {
     // Report entry should be created
     // L20: The `Contract::deposit` function will lock native assets. Consider adding a withdraw function.    
     #[payable, storage(read, write)]
-    fn deposit() {
+    fn deposit() { //~ locked_native_asset: The `Contract::deposit` function will lock native assets
         assert(msg_amount() > 0);
         storage.balance = storage.balance + msg_amount();
     }
@@ -25,7 +25,7 @@ impl TestLockedNativeAsset for Contract {
     // Report entry should be created
     // L28: The `Contract::deposit2` function will lock native assets. Consider adding a withdraw function.   
     #[payable, storage(read, write)]
-    fn deposit2() {
+    fn deposit2() { //~ locked_native_asset: The `Contract::deposit2` function will lock native assets
         assert(msg_amount() > 0);
         storage.balance = storage.balance + msg_amount();
     }
@@ -15,19 +15,19 @@ impl TestMagicNumber for Contract {
 
         // Report entry should be created:
         // L18: The `Contract::test_magic_numbers` function contains magic number usage: `3 * radius`. Consider introducing a constant value.
-        let _di = 3 * radius * radius;
+        let _di = 3 * radius * radius; //~ magic_number: The `Contract::test_magic_number` function contains magic number usage: `3 * radius`
 
         // Report entry should be created:
         // L22: The `Contract::test_magic_numbers` function contains magic number usage: `radius * 10 / 200`. Consider introducing a constant value.
-        let _xi = radius * 10 / 200;
+        let _xi = radius * 10 / 200; //~ magic_number: The `Contract::test_magic_number` function contains magic number usage: `radius * 10 / 200`
         
         // Report entry should be created:
         // L26: The `Contract::test_magic_numbers` function contains magic number usage: `radius * 10 * 200`. Consider introducing a constant value.
-        let _yi = radius * 10 * 200;
+        let _yi = radius * 10 * 200; //~ magic_number: The `Contract::test_magic_number` function contains magic number usage: `radius * 10 * 200`
 
         // Report entry should be created:
         // L30: The `Contract::test_magic_numbers` function contains magic number usage: `radius < 221`. Consider introducing a constant value.
-        if radius < 221 {
+        if radius < 221 { //~ magic_number: The `Contract::test_magic_number` function contains magic number usage: `radius < 221`
             return;
         }
 
@@ -38,7 +38,7 @@ impl TestMagicNumber for Contract {
 
         // Report entry should be created:
         // L41: The `Contract::test_magic_numbers` function contains magic number usage: `radius > 5`. Consider introducing a constant value.
-        while radius > 5 {
+        while radius > 5 { //~ magic_number: The `Contract::test_magic_number` function contains magic number usage: `radius > 5`
             radius = radius - 1;
         }
 
@@ -34,7 +34,7 @@ impl TestManipulatableBalanceUsage for Contract {
         
         // Report entry should be created:
         // L37: The `Contract::manipulatable_balance_usage_1` function contains manipulatable balance usage: `transfer(to, asset_id, out_amount)`
-        transfer(to, asset_id, out_amount);
+        transfer(to, asset_id, out_amount); //~ manipulatable_balance_usage: The `Contract::manipulatable_balance_usage_1` function contains manipulatable balance usage: `transfer(to, asset_id, out_amount)`
     }
 
     fn manipulatable_balance_usage_2(to: Identity, asset_id: AssetId, amount: u64) {
@@ -44,7 +44,7 @@ impl TestManipulatableBalanceUsage for Contract {
         
         // Report entry should be created:
         // L47: The `Contract::manipulatable_balance_usage_2` function contains manipulatable balance usage: `transfer(to, asset_id, amount_out)`
-        transfer(to, asset_id, amount_out);
+        transfer(to, asset_id, amount_out); //~ manipulatable_balance_usage: The `Contract::manipulatable_balance_usage_2` function contains manipulatable balance usage: `transfer(to, asset_id, amount_out)`
     }
     
     fn manipulatable_balance_usage_3(
@@ -65,15 +65,15 @@ impl TestManipulatableBalanceUsage for Contract {
         
         // Report entry should be created:
         // L68: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `transfer(to_ident, asset_id, amount)`
-        transfer(to_ident, asset_id, amount);
+        transfer(to_ident, asset_id, amount); //~ manipulatable_balance_usage: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `transfer(to_ident, asset_id, amount)`
         
         // Report entry should be created:
         // L72: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `transfer_to_address(to_address, asset_id, amount)`
-        transfer_to_address(to_address, asset_id, amount);
+        transfer_to_address(to_address, asset_id, amount); //~ manipulatable_balance_usage: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `transfer_to_address(to_address, asset_id, amount)`
         
         // Report entry should be created:
         // L76: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `force_transfer_to_contract(to_contract, asset_id, amount)`
-        force_transfer_to_contract(to_contract, asset_id, amount);
+        force_transfer_to_contract(to_contract, asset_id, amount); //~ manipulatable_balance_usage: The `Contract::manipulatable_balance_usage_3` function contains manipulatable balance usage: `force_transfer_to_contract(to_contract, asset_id, amount)`
 
         // Report entry should not be created
         call_with_function_selector(target, function_selector, calldata, single_value_type_arg, call_params);
@@ -34,7 +34,7 @@ impl TestMissingLogs for Contract {
     fn test_missing_logs_1(x: u64) {
         // Report entry should be created:
         // L37: The `Contract::test_missing_logs_1` function writes to `storage.value` without being logged.
-        storage.value.write(x);
+        storage.value.write(x); //~ missing_logs: The `Contract::test_missing_logs_1` function writes to `storage.value` without being logged
     }
 
     #[storage(read, write)]
@@ -62,7 +62,7 @@ impl TestMissingLogs for Contract {
     fn test_missing_logs_5(x: b256) {
         // Report entry should be created:
         // L65: The `Contract::test_missing_logs_5` function writes to `storage.admin` without being logged.
-        storage.admin.write(x);
+        storage.admin.write(x); //~ missing_logs: The `Contract::test_missing_logs_5` function writes to `storage.admin` without being logged
     }
 
     #[storage(read, write)]
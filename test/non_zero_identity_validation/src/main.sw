@@ -65,7 +65,7 @@ impl TestNonZeroIdentityValidation for Contract {
 
     // Report entry should be created:
     // L68: The `Contract::test_address_validated_5` function does not check its `input` parameter for a zero value.
-    fn test_address_validated_5(input: Address) {
+    fn test_address_validated_5(input: Address) { //~ non_zero_identity_validation: The `Contract::test_address_validated_5` function does not check its `input` parameter for a zero value
         log(input);
     }
 
@@ -99,7 +99,7 @@ impl TestNonZeroIdentityValidation for Contract {
 
     // Report entry should be created:
     // L102: The `Contract::test_contract_id_validated_5` function does not check its `input` parameter for a zero value.
-    fn test_contract_id_validated_5(input: ContractId) {
+    fn test_contract_id_validated_5(input: ContractId) { //~ non_zero_identity_validation: The `Contract::test_contract_id_validated_5` function does not check its `input` parameter for a zero value
         log(input);
     }
 
@@ -327,7 +327,7 @@ impl TestNonZeroIdentityValidation for Contract {
 
     // Report entry should be created:
     // L330: The `Contract::test_identity_validated_19` function does not check its `input` parameter for a zero value.
-    fn test_identity_validated_19(input: Identity) {
+    fn test_identity_validated_19(input: Identity) { //~ non_zero_identity_validation: The `Contract::test_identity_validated_19` function does not check its `input` parameter for a zero value
         log(input);
     }
 }
@@ -9,7 +9,7 @@ impl TestPotentialInfiniteLoops for Contract {
         let s = 1;
         let z = 10;
         // L12: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while s < z { ... }`. Consider adding a `break` statement.
-        while s < z {
+        while s < z { //~ potential_infinite_loop: The `Contract::test_potential_infinite_loop` function contains a potentially infinite loop: `while s < z { ... }`
             
         }
 
@@ -147,7 +147,7 @@ impl TestPotentialInfiniteLoops for Contract {
 
         // Report entry should be created:
         // L150: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while true { ... }`. Consider adding a `break` statement.
-        while true {
+        while true { //~ potential_infinite_loop: The `Contract::test_potential_infinite_loop` function contains a potentially infinite loop: `while true { ... }`
             let a = 1;
             let b = 2;
             let x = 10;
@@ -176,6 +176,20 @@ impl TestPotentialInfiniteLoops for Contract {
 
         // Report entry should be created:
         // L179: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while z { ... }`. Consider adding a `break` statement.
-        while z {}
+        while z {} //~ potential_infinite_loop: The `Contract::test_potential_infinite_loop` function contains a potentially infinite loop: `while z { ... }`
+
+        let limit = MAX * 2;
+        let mut i = 0;
+
+        // Report entry should be created:
+        // L186: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while limit > 10 { ... }`. Consider adding a `break` statement.
+        while limit > 10 { //~ potential_infinite_loop: The `Contract::test_potential_infinite_loop` function contains a potentially infinite loop: `while limit > 10 { ... }`
+            i += 1;
+        }
+
+        // Report entry should not be created
+        while limit < MAX {}
     }
-}
\ No newline at end of file
+}
+
+const MAX: u64 = 10;
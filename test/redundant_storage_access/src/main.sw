@@ -35,7 +35,7 @@ impl TestRedundantStorageAccess for Contract {
     #[storage(read)]
     fn test_storage_read_in_loop_condition() {
         let mut x = 0;
-        while x < storage.counter.read() {
+        while x < storage.counter.read() { //~ redundant_storage_access: The `Contract::test_storage_read_in_loop_condition` function contains a loop condition with redundant storage access: `storage.counter.read()`
             x += 1;
         }
     }
@@ -51,7 +51,7 @@ impl TestRedundantStorageAccess for Contract {
     #[storage(read)]
     fn test_redundant_storage_read_1() {
         let _ = storage.counter.read();
-        let _ = storage.counter.read();
+        let _ = storage.counter.read(); //~ redundant_storage_access: The `Contract::test_redundant_storage_read_1` function contains a redundant storage access: `storage.counter.read()`
     }
 
     // Report entry should be created:
@@ -60,7 +60,7 @@ impl TestRedundantStorageAccess for Contract {
     fn test_redundant_storage_read_2() {
         let _ = storage.counter.read();
         {
-            let _ = storage.counter.read();
+            let _ = storage.counter.read(); //~ redundant_storage_access: The `Contract::test_redundant_storage_read_2` function contains a redundant storage access: `storage.counter.read()`
         }
     }
 
@@ -75,7 +75,7 @@ impl TestRedundantStorageAccess for Contract {
     #[storage(write)]
     fn test_redundant_storage_write_1() {
         storage.counter.write(0);
-        storage.counter.write(0);
+        storage.counter.write(0); //~ redundant_storage_access: The `Contract::test_redundant_storage_write_1` function contains a redundant storage update: `storage.counter.write(0)`
     }
 
     // Report entry should be created:
@@ -84,7 +84,7 @@ impl TestRedundantStorageAccess for Contract {
     fn test_redundant_storage_write_2() {
         storage.counter.write(0);
         {
-            storage.counter.write(0);
+            storage.counter.write(0); //~ redundant_storage_access: The `Contract::test_redundant_storage_write_2` function contains a redundant storage update: `storage.counter.write(0)`
         }
     }
 }
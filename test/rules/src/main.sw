@@ -0,0 +1,59 @@
+contract;
+
+use std::{asset::mint_to, auth::msg_sender};
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    total_supply: u64 = 0,
+}
+
+abi TestRules {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64);
+    #[storage(read, write)]
+    fn airdrop(recipient: Identity, amount: u64);
+    #[storage(read, write)]
+    fn set_owner(owner: Identity);
+    #[storage(read, write)]
+    fn reset_supply();
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == storage.owner.read(), "not owner");
+}
+
+fn mint_helper(recipient: Identity, amount: u64) {
+    mint_to(recipient, b256::zero(), amount);
+}
+
+fn mint_qualified(recipient: Identity, amount: u64) {
+    std::asset::mint_to(recipient, b256::zero(), amount);
+}
+
+impl TestRules for Contract {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64) {
+        only_owner();
+        storage.total_supply.write(storage.total_supply.read() + amount);
+        mint_to(recipient, b256::zero(), amount);
+    }
+
+    #[storage(read, write)]
+    fn airdrop(recipient: Identity, amount: u64) {
+        only_owner();
+        storage.total_supply.write(storage.total_supply.read() + amount);
+        mint_helper(recipient, amount);
+    }
+
+    #[storage(read, write)]
+    fn set_owner(owner: Identity) {
+        only_owner();
+        storage.owner.write(owner);
+    }
+
+    #[storage(read, write)]
+    fn reset_supply() {
+        storage.total_supply.write(0);
+    }
+}
@@ -29,21 +29,21 @@ storage {
 
     // Report entry should be created:
     // L32: The `value2` storage field is never mutated. Consider refactoring it into a constant or a configurable field.
-    value2: u64 = 0,
+    value2: u64 = 0, //~ storage_field_mutability: The `value2` storage field is never mutated
 
     // Report entry should not be created
     map1: StorageMap<u64, u64> = StorageMap {},
 
     // Report entry should be created:
     // L39: The `map2` storage field is never mutated. Consider refactoring it into a constant or a configurable field.
-    map2: StorageMap<u64, u64> = StorageMap {},
+    map2: StorageMap<u64, u64> = StorageMap {}, //~ storage_field_mutability: The `map2` storage field is never mutated
 
     // Report entry should not be created
     map3: StorageMap<u64, StorageMap<u64, u64>> = StorageMap {},
 
     // Report entry should be created:
     // L46: The `map4` storage field is never mutated. Consider refactoring it into a constant or a configurable field.
-    map4: StorageMap<u64, StorageMap<u64, u64>> = StorageMap {},
+    map4: StorageMap<u64, StorageMap<u64, u64>> = StorageMap {}, //~ storage_field_mutability: The `map4` storage field is never mutated
 }
 
 impl TestStorageFieldMutability for Contract {
@@ -57,7 +57,7 @@ impl TestStorageNotUpdated for Contract {
     fn test_storage_u64_not_updated(amount: u64) {
         // Report entry should be created:
         // L60: The `Contract::test_storage_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.value`.
-        let mut value = storage.value.read();
+        let mut value = storage.value.read(); //~ storage_not_updated: The `Contract::test_storage_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.value`
         value += amount;
     }
 
@@ -73,7 +73,7 @@ impl TestStorageNotUpdated for Contract {
     fn test_storage_struct_not_updated(amount: u64) {
         // Report entry should be created:
         // L76: The `Contract::test_storage_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counter`.
-        let mut counter = storage.counter.read();
+        let mut counter = storage.counter.read(); //~ storage_not_updated: The `Contract::test_storage_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counter`
         counter.value += amount;
     }
     
@@ -89,7 +89,7 @@ impl TestStorageNotUpdated for Contract {
     fn test_storage_vec_u64_not_updated(amount: u64) {
         // Report entry should be created:
         // L92: The `Contract::test_storage_vec_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.values_vec`.
-        let mut value = storage.values_vec.get(0).unwrap().read();
+        let mut value = storage.values_vec.get(0).unwrap().read(); //~ storage_not_updated: The `Contract::test_storage_vec_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.values_vec`
         value += amount;
     }
     
@@ -105,7 +105,7 @@ impl TestStorageNotUpdated for Contract {
     fn test_storage_vec_struct_not_updated(amount: u64) {
         // Report entry should be created:
         // L108: The `Contract::test_storage_vec_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counters_vec`.
-        let mut counter = storage.counters_vec.get(0).unwrap().read();
+        let mut counter = storage.counters_vec.get(0).unwrap().read(); //~ storage_not_updated: The `Contract::test_storage_vec_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counters_vec`
         counter.value += amount;
     }
     
@@ -123,7 +123,7 @@ impl TestStorageNotUpdated for Contract {
         let sender = msg_sender().unwrap();
         // Report entry should be created:
         // L126: The `Contract::test_storage_map_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.values_map`.
-        let mut value = storage.values_map.get(sender).read();
+        let mut value = storage.values_map.get(sender).read(); //~ storage_not_updated: The `Contract::test_storage_map_u64_not_updated` function has storage bound to local variable `value` which is not written back to `storage.values_map`
         value += amount;
     }
     
@@ -141,7 +141,7 @@ impl TestStorageNotUpdated for Contract {
         let sender = msg_sender().unwrap();
         // Report entry should be created:
         // L144: The `Contract::test_storage_map_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counters_map`.
-        let mut counter = storage.counters_map.get(sender).read();
+        let mut counter = storage.counters_map.get(sender).read(); //~ storage_not_updated: The `Contract::test_storage_map_struct_not_updated` function has storage bound to local variable `counter` which is not written back to `storage.counters_map`
         counter.value += amount;
     }
 }
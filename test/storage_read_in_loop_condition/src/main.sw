@@ -20,7 +20,7 @@ impl TestStorageReadInLoopCondition for Contract {
 
         // Report entry should be created:
         // L23: The The `Contract::test_storage_read_in_loop_condition` function contains a loop with a condition that depends on a storage read: `storage.values.len()`. Consider storing the expression in a local variable in order to reduce gas costs.
-        while i < storage.values.len() {
+        while i < storage.values.len() { //~ storage_read_in_loop_condition: The The `Contract::test_storage_read_in_loop_condition_1` function contains a loop with a condition that depends on a storage read: `storage.values.len()`
             log(storage.values.get(i).unwrap());
             i += 1;
         }
@@ -34,7 +34,7 @@ impl TestStorageReadInLoopCondition for Contract {
         while j < 10 {
             // Report entry should be created:
             // L37: The The `Contract::test_storage_read_in_nested_loop_condition` function contains a loop with a condition that depends on a storage read: `storage.values.len()`. Consider storing the expression in a local variable in order to reduce gas costs.
-            while i < storage.values.len() {
+            while i < storage.values.len() { //~ storage_read_in_loop_condition: The The `Contract::test_storage_read_in_loop_condition_2` function contains a loop with a condition that depends on a storage read: `storage.values.len()`
                 log(storage.values.get(i).unwrap());
                 i += 1;
             }
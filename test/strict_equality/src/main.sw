@@ -17,7 +17,7 @@ impl TestStrictEquality for Contract {
     fn test_strict_equality_1() -> bool {
         // Report entry should be created:
         // L20: The `Contract::test_strict_equality_1` function contains a strict equality check: `storage.balance.read() == 100`. Don't use strict equality to determine if an account has enough balance.
-        return storage.balance.read() == 100;
+        return storage.balance.read() == 100; //~ strict_equality: The `Contract::test_strict_equality_1` function contains a strict equality check: `storage.balance.read() == 100`
     }
 
     #[storage(read)]
@@ -25,7 +25,7 @@ impl TestStrictEquality for Contract {
         let a = storage.balance.read();
         // Report entry should be created:
         // L28: The `Contract::test_strict_equality_2` function contains a strict equality check: `a == 100`. Don't use strict equality to determine if an account has enough balance.
-        return  a == 100;
+        return  a == 100; //~ strict_equality: The `Contract::test_strict_equality_2` function contains a strict equality check: `a == 100`
     }
 
     fn test_strict_equality_3() -> bool {
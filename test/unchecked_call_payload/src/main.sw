@@ -17,7 +17,7 @@ impl TestUncheckedCallPayload for Contract {
         asm(r1: payload, r2: call_params.coins, r3: call_params.asset_id, r4: call_params.gas) {
             // Report entry should be created:
             // L20: The `Contract::test_raw_ptr_payload` function uses the `payload: raw_ptr` parameter as the payload in a `CALL` instruction via register `r1`, which may revert if the data is incorrect: `call r1 r2 r3 r4`
-            call r1 r2 r3 r4;
+            call r1 r2 r3 r4; //~ unchecked_call_payload: The `Contract::test_raw_ptr_payload` function uses the `payload: raw_ptr` parameter as the payload in a `CALL` instruction via register `r1`, which may revert if the data is incorrect: `call r1 r2 r3 r4`
         };
     }
 
@@ -25,7 +25,7 @@ impl TestUncheckedCallPayload for Contract {
         asm(r1: payload.buf.ptr, r2: call_params.coins, r3: call_params.asset_id, r4: call_params.gas) {
             // Report entry should be created:
             // L28: The `Contract::test_unchecked_bytes_payload` function uses the `payload: Bytes` parameter as the payload in a `CALL` instruction via register `r1` without checking its length, which may revert if the data is incorrect: `call r1 r2 r3 r4`
-            call r1 r2 r3 r4;
+            call r1 r2 r3 r4; //~ unchecked_call_payload: The `Contract::test_unchecked_bytes_payload` function uses the `payload: Bytes` parameter as the payload in a `CALL` instruction via register `r1` without checking its length, which may revert if the data is incorrect: `call r1 r2 r3 r4`
         };
     }
     
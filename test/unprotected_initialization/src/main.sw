@@ -20,7 +20,7 @@ impl TestUnprotectedInitialization for Contract {
     // Report entry should be created:
     // L23: The `Contract::unsafe_init` function is an unprotected initializer function. Consider adding a requirement to prevent it from being called multiple times.
     #[storage(write)]
-    fn unsafe_init(value: u64) {
+    fn unsafe_init(value: u64) { //~ unprotected_initialization: The `Contract::unsafe_init` function is an unprotected initializer function
         storage.value.write(value);
     }
 
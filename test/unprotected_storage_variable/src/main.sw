@@ -60,7 +60,7 @@ fn only_owner() {
 // Report entry should be created:
 // L63: The `increment_value_unsafe` function writes to the `value` storage variable without access restriction. Consider checking against `msg_sender()` in order to limit access.
 #[storage(read, write)]
-fn increment_value_unsafe() {
+fn increment_value_unsafe() { //~ unprotected_storage_variable: The `increment_value_unsafe` function writes to the `value` storage variable without access restriction
     let mut value = storage.value.read();
     value += 1;
     storage.value.write(value);
@@ -70,7 +70,7 @@ impl TestUnprotectedStorageVariable for Contract {
     // Report entry should be created:
     // L73: The `Contract::test_unprotected_storage_variable_1` function writes to the `value` storage variable without access restriction. Consider checking against `msg_sender()` in order to limit access.
     #[storage(read, write)]
-    fn test_unprotected_storage_variable_1() {
+    fn test_unprotected_storage_variable_1() { //~ unprotected_storage_variable: The `Contract::test_unprotected_storage_variable_1` function writes to the `value` storage variable without access restriction
         let mut value = storage.value.read();
         value += 1;
         storage.value.write(value);
@@ -79,7 +79,7 @@ impl TestUnprotectedStorageVariable for Contract {
     // Report entry should be created:
     // L82: The `Contract::test_unprotected_storage_variable_2` function writes to the `value` storage variable without access restriction. Consider checking against `msg_sender()` in order to limit access.
     #[storage(read, write)]
-    fn test_unprotected_storage_variable_2() {
+    fn test_unprotected_storage_variable_2() { //~ unprotected_storage_variable: The `Contract::test_unprotected_storage_variable_2` function writes to the `value` storage variable without access restriction
         increment_value_unsafe();
     }
     
@@ -7,10 +7,10 @@ use std::token::transfer;
 
 // Report entry should be created:
 // L10: Found unused import: `msg_asset_id`. Consider removing any unused imports.
-use std::call_frames::msg_asset_id;
+use std::call_frames::msg_asset_id; //~ unused_import: Found unused import: `msg_asset_id`
 // Report entry should be created:
 // L13: Found unused import: `msg_amount`. Consider removing any unused imports.
-use std::context::msg_amount;
+use std::context::msg_amount; //~ unused_import: Found unused import: `msg_amount`
 
 /// TODO Importing prelude
 use std::storage::storage_vec::*;
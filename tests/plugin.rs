@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+use sway_analyzer::{plugin::Plugin, project::Project, Options};
+
+/// Gets the path of the example plugin, which `cargo test` builds into the `examples` directory next to the `deps` directory containing the test executable.
+fn example_plugin_path() -> PathBuf {
+    let path = std::env::current_exe().unwrap()
+        .parent().unwrap()
+        .parent().unwrap()
+        .join("examples")
+        .join(libloading::library_filename("example_plugin"));
+
+    assert!(path.exists(), "{} does not exist, run the full `cargo test` so the example plugin is built", path.display());
+
+    path
+}
+
+#[test]
+fn test_plugin() {
+    let options = Options {
+        directory: Some("test/boolean_comparison/".into()),
+        detectors: vec!["example_fn_count".to_string()],
+        plugins: vec![example_plugin_path()],
+        ..Default::default()
+    };
+
+    let mut project = Project::try_from(&options).unwrap();
+    project.analyze_modules().unwrap();
+
+    let report = project.report.borrow();
+    let entries = &report.entries[0].1;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].detector, "example_fn_count");
+    assert_eq!(entries[0].text, "The module contains 5 functions.");
+}
+
+#[test]
+fn test_plugin_invalid_library() {
+    assert!(Plugin::load("test/boolean_comparison/Forc.toml").is_err());
+}